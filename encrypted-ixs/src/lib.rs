@@ -1,5 +1,11 @@
  use arcis_imports::*;
 
+ #[cfg(test)]
+ mod reference;
+
+ #[cfg(test)]
+ mod tests;
+
  #[encrypted]
  mod circuits {
      use arcis_imports::*;
@@ -40,6 +46,208 @@
         faces[0].reveal()
     }
 
+    // Craps: two independent dice, each drawn like `roll_dice`
+    #[instruction]
+    pub fn roll_two_dice(_mxe: Mxe) -> (u8, u8) {
+        let mut first = [1u8,2,3,4,5,6];
+        let mut second = [1u8,2,3,4,5,6];
+        ArcisRNG::shuffle(&mut first);
+        ArcisRNG::shuffle(&mut second);
+        (first[0].reveal(), second[0].reveal())
+    }
+
+    // Sic Bo: three independent dice, each drawn like `roll_dice`
+    #[instruction]
+    pub fn roll_three_dice(_mxe: Mxe) -> (u8, u8, u8) {
+        let mut first = [1u8,2,3,4,5,6];
+        let mut second = [1u8,2,3,4,5,6];
+        let mut third = [1u8,2,3,4,5,6];
+        ArcisRNG::shuffle(&mut first);
+        ArcisRNG::shuffle(&mut second);
+        ArcisRNG::shuffle(&mut third);
+        (first[0].reveal(), second[0].reveal(), third[0].reveal())
+    }
+
+    // Aviator: multipliers are fixed-point hundredths (100 = 1.00x)
+    const AVIATOR_RANDOM_BITS: usize = 24;
+    const AVIATOR_MIN_CRASH: u64 = 100;
+    const AVIATOR_MAX_CRASH: u64 = 10000;
+
+    // Crash point for a uniform `random` in 0..2^24: P(crash >= m) = rtp / m, floored at 1.00x
+    // (an instant crash, the house edge) and capped at 100.00x
+    pub fn crash_point(random: u64, rtp_bps: u16) -> u64 {
+        let range = 1u64 << AVIATOR_RANDOM_BITS;
+        let crash = (rtp_bps as u64 * range) / (100 * (range - random));
+        if crash < AVIATOR_MIN_CRASH {
+            AVIATOR_MIN_CRASH
+        } else if crash > AVIATOR_MAX_CRASH {
+            AVIATOR_MAX_CRASH
+        } else {
+            crash
+        }
+    }
+
+    fn random_crash_point(rtp_bps: u16) -> u64 {
+        let mut random = 0u64;
+        let mut bit = 1u64;
+        for _ in 0..AVIATOR_RANDOM_BITS {
+            if ArcisRNG::bool() {
+                random += bit;
+            }
+            bit *= 2;
+        }
+        crash_point(random, rtp_bps)
+    }
+
+    #[instruction]
+    pub fn crash_aviator(_mxe: Mxe, rtp_bps: u16) -> u64 {
+        random_crash_point(rtp_bps).reveal()
+    }
+
+    // Multiplayer crash rounds: the crash point is drawn when the round opens and stays
+    // encrypted to the MXE until the live multiplier passes it
+    #[instruction]
+    pub fn start_crash_round(mxe: Mxe, rtp_bps: u16) -> Enc<Mxe, u64> {
+        mxe.from_arcis(random_crash_point(rtp_bps))
+    }
+
+    // Reveals the crash point once `multiplier` has reached it; 0 means the round is still flying
+    #[instruction]
+    pub fn check_crash_round(crash_ctxt: Enc<Mxe, u64>, multiplier: u64) -> u64 {
+        let crash = crash_ctxt.to_arcis();
+        let revealed = if crash <= multiplier { crash } else { 0 };
+        revealed.reveal()
+    }
+
+    // Memecoin simulator: prices are per-mille of the entry price (1000 = 1.000x)
+    const MEMECOIN_STEPS: usize = 16;
+    const MEMECOIN_START_PRICE: u64 = 1000;
+    const MEMECOIN_MAX_PRICE: u64 = 65535;
+    // Per-step price moves, per-mille. They average exactly 1000 so the path is a martingale
+    // and no selling strategy beats the configured RTP; move 0 is a rug pull.
+    const MEMECOIN_MOVES: [u64; 16] = [
+        0, 500, 700, 800, 850, 900, 950, 1000, 1000, 1050, 1100, 1150, 1200, 1300, 1500, 2000,
+    ];
+
+    // 16 sixteen-bit prices, eight per chunk
+    pub struct PricePath {
+        pub low: u128,
+        pub high: u128,
+    }
+
+    impl PricePath {
+        pub fn from_array(array: [u16; MEMECOIN_STEPS]) -> PricePath {
+            let mut low = 0u128;
+            let mut high = 0u128;
+            for i in 0..8 {
+                low += (array[i] as u128) << (16 * i);
+                high += (array[i + 8] as u128) << (16 * i);
+            }
+            PricePath { low, high }
+        }
+
+        pub fn to_array(&self) -> [u16; MEMECOIN_STEPS] {
+            let mut low = self.low;
+            let mut high = self.high;
+            let mut prices = [0u16; MEMECOIN_STEPS];
+            for i in 0..8 {
+                prices[i] = (low % 65536) as u16;
+                prices[i + 8] = (high % 65536) as u16;
+                low >>= 16;
+                high >>= 16;
+            }
+            prices
+        }
+    }
+
+    pub fn next_price(price: u64, step_move: u8) -> u64 {
+        let mut factor = 0u64;
+        for i in 0..16 {
+            if i as u8 == step_move {
+                factor = MEMECOIN_MOVES[i];
+            }
+        }
+        let next = price * factor / 1000;
+        if next > MEMECOIN_MAX_PRICE {
+            MEMECOIN_MAX_PRICE
+        } else {
+            next
+        }
+    }
+
+    #[instruction]
+    pub fn generate_price_path(mxe: Mxe) -> Enc<Mxe, PricePath> {
+        let mut prices = [0u16; MEMECOIN_STEPS];
+        let mut price = MEMECOIN_START_PRICE;
+        for i in 0..MEMECOIN_STEPS {
+            let mut step_move = 0u8;
+            let mut bit = 1u8;
+            for _ in 0..4 {
+                if ArcisRNG::bool() {
+                    step_move += bit;
+                }
+                bit *= 2;
+            }
+            price = next_price(price, step_move);
+            prices[i] = price as u16;
+        }
+        mxe.from_arcis(PricePath::from_array(prices))
+    }
+
+    // Reveals the price after `step` + 1 moves; earlier steps are already public
+    #[instruction]
+    pub fn reveal_price_step(path_ctxt: Enc<Mxe, PricePath>, step: u8) -> u16 {
+        let prices = path_ctxt.to_arcis().to_array();
+        prices[step as usize].reveal()
+    }
+
+    // Mines: the layout is a bitmask over up to 25 tiles, bit i set when tile i holds a mine
+    const MINES_MAX_TILES: usize = 25;
+
+    // Places `mine_count` mines uniformly among the first `tile_count` tiles: the first
+    // `mine_count` in-grid tiles of a shuffled order become mines
+    #[instruction]
+    pub fn generate_mines(mxe: Mxe, tile_count: u8, mine_count: u8) -> Enc<Mxe, u32> {
+        let mut order = [
+            0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        ];
+        ArcisRNG::shuffle(&mut order);
+        let mut layout = 0u32;
+        let mut placed = 0u8;
+        for i in 0..MINES_MAX_TILES {
+            let tile = order[i];
+            let place = tile < tile_count && placed < mine_count;
+            for j in 0..MINES_MAX_TILES {
+                if place && tile == j as u8 {
+                    layout += 1u32 << j;
+                }
+            }
+            if place {
+                placed += 1;
+            }
+        }
+        mxe.from_arcis(layout)
+    }
+
+    // Whether `tile` holds a mine; the full layout is revealed only when it does
+    #[instruction]
+    pub fn reveal_tile(layout_ctxt: Enc<Mxe, u32>, tile: u8) -> (bool, u32) {
+        let layout = layout_ctxt.to_arcis();
+        let mut hit = false;
+        for i in 0..MINES_MAX_TILES {
+            if i as u8 == tile {
+                hit = (layout >> i) & 1 == 1;
+            }
+        }
+        let revealed = if hit { layout } else { 0 };
+        (hit, revealed).reveal()
+    }
+
+    #[instruction]
+    pub fn reveal_mines(layout_ctxt: Enc<Mxe, u32>) -> u32 {
+        layout_ctxt.to_arcis().reveal()
+    }
+
     // Blackjack circuits
     const INITIAL_DECK: [u8; 52] = [
         0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
@@ -79,7 +287,7 @@
             Deck { card_one, card_two, card_three }
         }
 
-        fn to_array(&self) -> [u8; 52] {
+        pub fn to_array(&self) -> [u8; 52] {
             let mut card_one = self.card_one;
             let mut card_two = self.card_two;
             let mut card_three = self.card_three;
@@ -111,7 +319,7 @@
             Hand { cards }
         }
 
-        fn to_array(&self) -> [u8; 11] {
+        pub fn to_array(&self) -> [u8; 11] {
             let mut cards = self.cards;
             let mut bytes = [0u8; 11];
             for i in 0..11 {
@@ -122,26 +330,192 @@
         }
     }
 
-    fn calculate_hand_value(hand: &[u8; 11], hand_length: u8) -> u8 {
+    // Blackjack value of a single card: rank 0 is an ace, 1-9 are twos to tens, 10-12 are faces
+    pub fn card_value(card: u8) -> u8 {
+        let rank = card % 13;
+        if rank == 0 {
+            1
+        } else if rank >= 10 {
+            10
+        } else {
+            rank + 1
+        }
+    }
+
+    // Best total for the hand and whether an ace is still being counted as 11
+    pub fn hand_total(hand: &[u8; 11], hand_length: u8) -> (u8, bool) {
         let mut value = 0;
-        let mut has_ace = false;
+        let mut aces = 0;
         for i in 0..11 {
-            let rank = if i < hand_length as usize { (hand[i] % 13) } else { 0 };
             if i < hand_length as usize {
-                if rank == 0 {
-                    value += 11;
-                    has_ace = true;
-                } else if rank > 10 {
-                    value += 10;
-                } else {
-                    value += rank;
+                value += card_value(hand[i]);
+                if hand[i] % 13 == 0 {
+                    aces += 1;
                 }
             }
         }
-        if value > 21 && has_ace {
-            value -= 10;
+        // At most one ace can ever count as 11 without busting
+        let soft = aces > 0 && value + 10 <= 21;
+        if soft {
+            value += 10;
+        }
+        (value, soft)
+    }
+
+    pub fn calculate_hand_value(hand: &[u8; 11], hand_length: u8) -> u8 {
+        hand_total(hand, hand_length).0
+    }
+
+    pub fn dealer_should_hit(dealer: &[u8; 11], dealer_length: u8, hits_soft_17: bool) -> bool {
+        let (value, soft) = hand_total(dealer, dealer_length);
+        value < 17 || (hits_soft_17 && value == 17 && soft)
+    }
+
+    // Opening deal from a shuffled deck: player gets cards 0 and 2, dealer 1 and 3
+    pub fn deal_hands(deck: &[u8; 52]) -> ([u8; 11], [u8; 11]) {
+        let mut player_cards = [53; 11];
+        player_cards[0] = deck[0];
+        player_cards[1] = deck[2];
+        let mut dealer_cards = [53; 11];
+        dealer_cards[0] = deck[1];
+        dealer_cards[1] = deck[3];
+        (player_cards, dealer_cards)
+    }
+
+    // Adds `deck[next_card]` to the hand and reports whether the new hand is bust
+    pub fn draw_card(deck: &[u8; 52], hand: &[u8; 11], hand_size: u8, next_card: u8) -> ([u8; 11], bool) {
+        let mut new_hand = *hand;
+        new_hand[hand_size as usize] = deck[next_card as usize];
+        let is_bust = calculate_hand_value(&new_hand, hand_size + 1) > 21;
+        (new_hand, is_bust)
+    }
+
+    // Double down: one card if the current total is in the allowed range, otherwise no change
+    pub fn double_down_hand(
+        deck: &[u8; 52],
+        hand: &[u8; 11],
+        hand_size: u8,
+        next_card: u8,
+        double_min_total: u8,
+        double_max_total: u8,
+    ) -> ([u8; 11], bool, bool) {
+        let value = calculate_hand_value(hand, hand_size);
+        let allowed = value >= double_min_total && value <= double_max_total;
+        let (drawn, drawn_bust) = draw_card(deck, hand, hand_size, next_card);
+        let new_hand = if allowed { drawn } else { *hand };
+        (new_hand, allowed && drawn_bust, allowed)
+    }
+
+    // Dealer draws from `next_card` onwards until the house rules say stand
+    pub fn dealer_draw(
+        deck: &[u8; 52],
+        dealer: &[u8; 11],
+        dealer_hand_size: u8,
+        next_card: u8,
+        hits_soft_17: bool,
+    ) -> ([u8; 11], u8) {
+        let mut hand = *dealer;
+        let mut size = dealer_hand_size as usize;
+        let mut next = next_card as usize;
+        for _i in 0..7 {
+            if dealer_should_hit(&hand, size as u8, hits_soft_17) {
+                hand[size] = deck[next];
+                size += 1;
+                next += 1;
+            }
+        }
+        (hand, size as u8)
+    }
+
+    // Late surrender against the dealer's peek for a natural
+    pub fn surrender_result(player_hand: &[u8; 11], dealer_hand: &[u8; 11]) -> u8 {
+        let player_natural = calculate_hand_value(player_hand, 2) == 21;
+        let dealer_natural = calculate_hand_value(dealer_hand, 2) == 21;
+        if !dealer_natural {
+            6
+        } else if player_natural {
+            4
+        } else {
+            3
+        }
+    }
+
+    // Side bets. Suits are `card / 13`; hearts (1) and diamonds (2) are red.
+    fn is_red(card: u8) -> bool {
+        let suit = card / 13;
+        suit == 1 || suit == 2
+    }
+
+    // Perfect Pairs on the player's first two cards: 0 none, 1 mixed, 2 coloured, 3 perfect
+    pub fn perfect_pairs_result(first: u8, second: u8) -> u8 {
+        let pair = first % 13 == second % 13;
+        if !pair {
+            0
+        } else if first / 13 == second / 13 {
+            3
+        } else if is_red(first) == is_red(second) {
+            2
+        } else {
+            1
+        }
+    }
+
+    // 21+3 on the player's two cards and the dealer up card:
+    // 0 none, 1 flush, 2 straight, 3 three of a kind, 4 straight flush, 5 suited trips
+    pub fn twenty_one_plus_three_result(first: u8, second: u8, up_card: u8) -> u8 {
+        let a = first % 13;
+        let b = second % 13;
+        let c = up_card % 13;
+        let low = if a < b { if a < c { a } else { c } } else if b < c { b } else { c };
+        let high = if a > b { if a > c { a } else { c } } else if b > c { b } else { c };
+        let distinct = a != b && b != c && a != c;
+        // Aces play low (A-2-3) or high (Q-K-A)
+        let ace_high = distinct && low == 0 && a + b + c == 23 && high == 12;
+        let straight = distinct && (high - low == 2 || ace_high);
+        let flush = first / 13 == second / 13 && second / 13 == up_card / 13;
+        let trips = a == b && b == c;
+        if trips && flush {
+            5
+        } else if straight && flush {
+            4
+        } else if trips {
+            3
+        } else if straight {
+            2
+        } else if flush {
+            1
+        } else {
+            0
+        }
+    }
+
+    // Result code for one player hand against the dealer's final hand
+    pub fn settle_hand(
+        player_hand: &[u8; 11],
+        player_hand_length: u8,
+        dealer_hand: &[u8; 11],
+        dealer_hand_length: u8,
+    ) -> u8 {
+        let player_value = calculate_hand_value(player_hand, player_hand_length);
+        let dealer_value = calculate_hand_value(dealer_hand, dealer_hand_length);
+        // A natural is 21 on the first two cards; two naturals push.
+        let player_natural = player_hand_length == 2 && player_value == 21;
+        let dealer_natural = dealer_hand_length == 2 && dealer_value == 21;
+        if player_value > 21 {
+            0
+        } else if player_natural && !dealer_natural {
+            5
+        } else if dealer_natural && !player_natural {
+            3
+        } else if dealer_value > 21 {
+            1
+        } else if player_value > dealer_value {
+            2
+        } else if dealer_value > player_value {
+            3
+        } else {
+            4
         }
-        value
     }
 
     #[instruction]
@@ -150,24 +524,41 @@
         mxe_again: Mxe,
         client: Shared,
         client_again: Shared,
+        perfect_pairs_active: bool,
+        twenty_one_plus_three_active: bool,
     ) -> (
         Enc<Mxe, Deck>,
         Enc<Mxe, Hand>,
         Enc<Shared, Hand>,
         Enc<Shared, u8>,
+        u8,
+        u8,
     ) {
         let mut initial_deck = INITIAL_DECK;
         ArcisRNG::shuffle(&mut initial_deck);
         let deck = mxe.from_arcis(Deck::from_array(initial_deck));
-        let mut dealer_cards = [53; 11];
-        dealer_cards[0] = initial_deck[1];
-        dealer_cards[1] = initial_deck[3];
+        let (player_cards, dealer_cards) = deal_hands(&initial_deck);
+        // Side bets are settled on the opening cards; nothing is revealed for a bet not placed
+        let perfect_pairs = if perfect_pairs_active {
+            perfect_pairs_result(player_cards[0], player_cards[1])
+        } else {
+            0
+        };
+        let twenty_one_plus_three = if twenty_one_plus_three_active {
+            twenty_one_plus_three_result(player_cards[0], player_cards[1], dealer_cards[0])
+        } else {
+            0
+        };
         let dealer_hand = mxe_again.from_arcis(Hand::from_array(dealer_cards));
-        let mut player_cards = [53; 11];
-        player_cards[0] = initial_deck[0];
-        player_cards[1] = initial_deck[2];
         let player_hand = client.from_arcis(Hand::from_array(player_cards));
-        (deck, dealer_hand, player_hand, client_again.from_arcis(initial_deck[1]))
+        (
+            deck,
+            dealer_hand,
+            player_hand,
+            client_again.from_arcis(initial_deck[1]),
+            perfect_pairs.reveal(),
+            twenty_one_plus_three.reveal(),
+        )
     }
 
     #[instruction]
@@ -178,15 +569,8 @@
         dealer_hand_size: u8,
     ) -> (Enc<Shared, Hand>, bool) {
         let deck = deck_ctxt.to_arcis().to_array();
-        let mut player_hand = player_hand_ctxt.to_arcis().to_array();
-        let player_hand_value = calculate_hand_value(&player_hand, player_hand_size);
-        let is_bust = player_hand_value > 21;
-        let new_card = if !is_bust {
-            deck[(player_hand_size + dealer_hand_size) as usize]
-        } else {
-            53
-        };
-        player_hand[player_hand_size as usize] = new_card;
+        let player_hand = player_hand_ctxt.to_arcis().to_array();
+        let (player_hand, is_bust) = draw_card(&deck, &player_hand, player_hand_size, player_hand_size + dealer_hand_size);
         (player_hand_ctxt.owner.from_arcis(Hand::from_array(player_hand)), is_bust.reveal())
     }
 
@@ -203,18 +587,32 @@
         player_hand_ctxt: Enc<Shared, Hand>,
         player_hand_size: u8,
         dealer_hand_size: u8,
-    ) -> (Enc<Shared, Hand>, bool) {
-        let deck_array = deck_ctxt.to_arcis().to_array();
-        let mut player_hand = player_hand_ctxt.to_arcis().to_array();
-        let player_hand_value = calculate_hand_value(&player_hand, player_hand_size);
-        let is_bust = player_hand_value > 21;
-        let new_card = if !is_bust {
-            deck_array[(player_hand_size + dealer_hand_size) as usize]
-        } else {
-            53
-        };
-        player_hand[player_hand_size as usize] = new_card;
-        (player_hand_ctxt.owner.from_arcis(Hand::from_array(player_hand)), is_bust.reveal())
+        double_min_total: u8,
+        double_max_total: u8,
+    ) -> (Enc<Shared, Hand>, bool, bool) {
+        let deck = deck_ctxt.to_arcis().to_array();
+        let player_hand = player_hand_ctxt.to_arcis().to_array();
+        // Only whether the total is in the house's doubling range is revealed
+        let (player_hand, is_bust, allowed) = double_down_hand(
+            &deck,
+            &player_hand,
+            player_hand_size,
+            player_hand_size + dealer_hand_size,
+            double_min_total,
+            double_max_total,
+        );
+        (player_hand_ctxt.owner.from_arcis(Hand::from_array(player_hand)), is_bust.reveal(), allowed.reveal())
+    }
+
+    #[instruction]
+    pub fn player_surrender(
+        player_hand_ctxt: Enc<Shared, Hand>,
+        dealer_hand_ctxt: Enc<Mxe, Hand>,
+    ) -> u8 {
+        // Late surrender: the dealer peeks for a natural first, and only the outcome is revealed.
+        let player_hand = player_hand_ctxt.to_arcis().to_array();
+        let dealer_hand = dealer_hand_ctxt.to_arcis().to_array();
+        surrender_result(&player_hand, &dealer_hand).reveal()
     }
 
     #[instruction]
@@ -224,18 +622,12 @@
         client: Shared,
         player_hand_size: u8,
         dealer_hand_size: u8,
+        hits_soft_17: bool,
     ) -> (Enc<Mxe, Hand>, Enc<Shared, Hand>, u8) {
-        let deck_array = deck_ctxt.to_arcis().to_array();
-        let mut dealer = dealer_hand_ctxt.to_arcis().to_array();
-        let mut size = dealer_hand_size as usize;
-        for _i in 0..7 {
-            let val = calculate_hand_value(&dealer, size as u8);
-            if val < 17 {
-                dealer[size] = deck_array[(player_hand_size as usize + size)];
-                size += 1;
-            }
-        }
-        (dealer_hand_ctxt.owner.from_arcis(Hand::from_array(dealer)), client.from_arcis(Hand::from_array(dealer)), (size as u8).reveal())
+        let deck = deck_ctxt.to_arcis().to_array();
+        let dealer = dealer_hand_ctxt.to_arcis().to_array();
+        let (dealer, size) = dealer_draw(&deck, &dealer, dealer_hand_size, player_hand_size + dealer_hand_size, hits_soft_17);
+        (dealer_hand_ctxt.owner.from_arcis(Hand::from_array(dealer)), client.from_arcis(Hand::from_array(dealer)), size.reveal())
     }
 
     #[instruction]
@@ -247,20 +639,665 @@
     ) -> u8 {
         let player_hand = player_hand.to_arcis().to_array();
         let dealer_hand = dealer_hand.to_arcis().to_array();
-        let player_value = calculate_hand_value(&player_hand, player_hand_length);
-        let dealer_value = calculate_hand_value(&dealer_hand, dealer_hand_length);
-        let result = if player_value > 21 {
+        settle_hand(&player_hand, player_hand_length, &dealer_hand, dealer_hand_length).reveal()
+    }
+
+    // Post-game audit: the packed deck and the dealer's hole card, revealed once the hand is resolved
+    #[instruction]
+    pub fn reveal_deck(deck_ctxt: Enc<Mxe, Deck>, dealer_hand: Enc<Mxe, Hand>) -> (u128, u128, u128, u8) {
+        let deck = deck_ctxt.to_arcis();
+        let dealer_hand = dealer_hand.to_arcis().to_array();
+        (
+            deck.card_one.reveal(),
+            deck.card_two.reveal(),
+            deck.card_three.reveal(),
+            dealer_hand[1].reveal(),
+        )
+    }
+
+    // Blackjack shoe: several decks shuffled once and drawn from across hands
+    const SHOE_DECKS: usize = 6;
+    const SHOE_SIZE: usize = 312;
+    const SHOE_CHUNKS: usize = 15;
+
+    pub struct Shoe {
+        pub cards: [u128; SHOE_CHUNKS],
+    }
+
+    impl Shoe {
+        pub fn from_array(array: [u8; SHOE_SIZE]) -> Shoe {
+            let mut cards = [0u128; SHOE_CHUNKS];
+            for i in 0..SHOE_SIZE {
+                cards[i / 21] += POWS_OF_SIXTY_FOUR[i % 21] * array[i] as u128;
+            }
+            Shoe { cards }
+        }
+
+        fn to_array(&self) -> [u8; SHOE_SIZE] {
+            let mut cards = self.cards;
+            let mut bytes = [0u8; SHOE_SIZE];
+            for i in 0..SHOE_SIZE {
+                bytes[i] = (cards[i / 21] % 64) as u8;
+                cards[i / 21] >>= 6;
+            }
+            bytes
+        }
+    }
+
+    #[instruction]
+    pub fn shuffle_shoe(mxe: Mxe) -> Enc<Mxe, Shoe> {
+        let mut shoe = [0u8; SHOE_SIZE];
+        for d in 0..SHOE_DECKS {
+            for i in 0..52 {
+                shoe[d * 52 + i] = INITIAL_DECK[i];
+            }
+        }
+        ArcisRNG::shuffle(&mut shoe);
+        mxe.from_arcis(Shoe::from_array(shoe))
+    }
+
+    #[instruction]
+    pub fn deal_from_shoe(
+        shoe_ctxt: Enc<Mxe, Shoe>,
+        position: u16,
+        mxe: Mxe,
+        mxe_again: Mxe,
+        client: Shared,
+        client_again: Shared,
+    ) -> (
+        Enc<Mxe, Deck>,
+        Enc<Mxe, Hand>,
+        Enc<Shared, Hand>,
+        Enc<Shared, u8>,
+    ) {
+        // Copy the next 52 undealt cards into a per-hand deck so the hit/stand/dealer
+        // circuits keep indexing the hand's own `Deck` exactly as for a single-deck game.
+        let shoe = shoe_ctxt.to_arcis().to_array();
+        let mut hand_deck = [53u8; 52];
+        for i in 0..52 {
+            let idx = position as usize + i;
+            if idx < SHOE_SIZE {
+                hand_deck[i] = shoe[idx];
+            }
+        }
+        let deck = mxe.from_arcis(Deck::from_array(hand_deck));
+        let (player_cards, dealer_cards) = deal_hands(&hand_deck);
+        let dealer_hand = mxe_again.from_arcis(Hand::from_array(dealer_cards));
+        let player_hand = client.from_arcis(Hand::from_array(player_cards));
+        (deck, dealer_hand, player_hand, client_again.from_arcis(hand_deck[1]))
+    }
+
+    // Multi-seat blackjack table: up to 5 seats share one deck and one dealer hand
+    const TABLE_SEATS: usize = 5;
+
+    // Opening deal for the seats set in `seated_mask`: one card to each occupied seat in
+    // order, then the dealer, twice. Empty seats are skipped, so every card dealt is in play
+    // and no seat holds cards the others can't see.
+    pub fn deal_table_hands(deck: &[u8; 52], seated_mask: u8) -> ([[u8; 11]; TABLE_SEATS], [u8; 11]) {
+        let mut order = [0u8; TABLE_SEATS];
+        let mut seated = 0u8;
+        for s in 0..TABLE_SEATS {
+            order[s] = seated;
+            if (seated_mask >> s) & 1 == 1 {
+                seated += 1;
+            }
+        }
+        let mut seat_cards = [[53u8; 11]; TABLE_SEATS];
+        for s in 0..TABLE_SEATS {
+            if (seated_mask >> s) & 1 == 1 {
+                seat_cards[s][0] = deck[order[s] as usize];
+                seat_cards[s][1] = deck[(seated + 1 + order[s]) as usize];
+            }
+        }
+        let mut dealer_cards = [53u8; 11];
+        dealer_cards[0] = deck[seated as usize];
+        dealer_cards[1] = deck[(2 * seated + 1) as usize];
+        (seat_cards, dealer_cards)
+    }
+
+    #[instruction]
+    pub fn deal_table(
+        mxe: Mxe,
+        mxe_again: Mxe,
+        seat_0: Shared,
+        seat_1: Shared,
+        seat_2: Shared,
+        seat_3: Shared,
+        seat_4: Shared,
+        seated_mask: u8,
+    ) -> (
+        Enc<Mxe, Deck>,
+        Enc<Mxe, Hand>,
+        Enc<Shared, Hand>,
+        Enc<Shared, Hand>,
+        Enc<Shared, Hand>,
+        Enc<Shared, Hand>,
+        Enc<Shared, Hand>,
+        u8,
+    ) {
+        let mut initial_deck = INITIAL_DECK;
+        ArcisRNG::shuffle(&mut initial_deck);
+        // The dealer's up card is public at a shared table, the hole card stays encrypted.
+        // Empty seats come back as empty hands.
+        let (seat_cards, dealer_cards) = deal_table_hands(&initial_deck, seated_mask);
+        (
+            mxe.from_arcis(Deck::from_array(initial_deck)),
+            mxe_again.from_arcis(Hand::from_array(dealer_cards)),
+            seat_0.from_arcis(Hand::from_array(seat_cards[0])),
+            seat_1.from_arcis(Hand::from_array(seat_cards[1])),
+            seat_2.from_arcis(Hand::from_array(seat_cards[2])),
+            seat_3.from_arcis(Hand::from_array(seat_cards[3])),
+            seat_4.from_arcis(Hand::from_array(seat_cards[4])),
+            dealer_cards[0].reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn table_player_hit(
+        deck_ctxt: Enc<Mxe, Deck>,
+        seat_hand_ctxt: Enc<Shared, Hand>,
+        seat_hand_size: u8,
+        cards_dealt: u8,
+    ) -> (Enc<Shared, Hand>, bool) {
+        let deck = deck_ctxt.to_arcis().to_array();
+        let seat_hand = seat_hand_ctxt.to_arcis().to_array();
+        let (seat_hand, is_bust) = draw_card(&deck, &seat_hand, seat_hand_size, cards_dealt);
+        (seat_hand_ctxt.owner.from_arcis(Hand::from_array(seat_hand)), is_bust.reveal())
+    }
+
+    #[instruction]
+    pub fn table_dealer_play(
+        deck_ctxt: Enc<Mxe, Deck>,
+        dealer_hand_ctxt: Enc<Mxe, Hand>,
+        dealer_hand_size: u8,
+        cards_dealt: u8,
+        hits_soft_17: bool,
+    ) -> (Enc<Mxe, Hand>, u8) {
+        let deck = deck_ctxt.to_arcis().to_array();
+        let dealer = dealer_hand_ctxt.to_arcis().to_array();
+        let (dealer, size) = dealer_draw(&deck, &dealer, dealer_hand_size, cards_dealt, hits_soft_17);
+        (dealer_hand_ctxt.owner.from_arcis(Hand::from_array(dealer)), size.reveal())
+    }
+
+    #[instruction]
+    pub fn resolve_table(
+        seat_0: Enc<Shared, Hand>,
+        seat_1: Enc<Shared, Hand>,
+        seat_2: Enc<Shared, Hand>,
+        seat_3: Enc<Shared, Hand>,
+        seat_4: Enc<Shared, Hand>,
+        dealer_hand_ctxt: Enc<Mxe, Hand>,
+        seat_0_size: u8,
+        seat_1_size: u8,
+        seat_2_size: u8,
+        seat_3_size: u8,
+        seat_4_size: u8,
+        dealer_hand_size: u8,
+    ) -> (u8, u8, u8, u8, u8) {
+        let dealer = dealer_hand_ctxt.to_arcis().to_array();
+        let seat_hand_sizes = [seat_0_size, seat_1_size, seat_2_size, seat_3_size, seat_4_size];
+        let hands = [
+            seat_0.to_arcis().to_array(),
+            seat_1.to_arcis().to_array(),
+            seat_2.to_arcis().to_array(),
+            seat_3.to_arcis().to_array(),
+            seat_4.to_arcis().to_array(),
+        ];
+        let mut results = [0u8; TABLE_SEATS];
+        for s in 0..TABLE_SEATS {
+            results[s] = settle_hand(&hands[s], seat_hand_sizes[s], &dealer, dealer_hand_size);
+        }
+        (results[0], results[1], results[2], results[3], results[4]).reveal()
+    }
+
+    // Hi-Lo: one shuffled deck in the blackjack `Deck` packing, drawn in order. The first card
+    // is shown at once; each later card is revealed only after the player has committed a guess.
+    #[instruction]
+    pub fn shuffle_hilo_deck(mxe: Mxe) -> (Enc<Mxe, Deck>, u8) {
+        let mut deck = INITIAL_DECK;
+        ArcisRNG::shuffle(&mut deck);
+        (mxe.from_arcis(Deck::from_array(deck)), deck[0].reveal())
+    }
+
+    #[instruction]
+    pub fn draw_hilo_card(deck_ctxt: Enc<Mxe, Deck>, position: u8) -> u8 {
+        let deck = deck_ctxt.to_arcis().to_array();
+        deck[position as usize].reveal()
+    }
+
+    // Video poker (Jacks or Better): five cards from deck positions 0..5; an unheld card in
+    // slot i is replaced by deck[5 + i], so the draw never depends on a secret index
+    pub fn deal_poker_hand(deck: &[u8; 52]) -> [u8; 11] {
+        let mut hand = [53; 11];
+        for i in 0..5 {
+            hand[i] = deck[i];
+        }
+        hand
+    }
+
+    pub fn draw_poker_hand(deck: &[u8; 52], hand: &[u8; 11], hold_mask: u8) -> [u8; 11] {
+        let mut new_hand = *hand;
+        for i in 0..5 {
+            if (hold_mask >> i) & 1 == 0 {
+                new_hand[i] = deck[5 + i];
+            }
+        }
+        new_hand
+    }
+
+    // 0 nothing, 1 jacks or better, 2 two pair, 3 three of a kind, 4 straight, 5 flush,
+    // 6 full house, 7 four of a kind, 8 straight flush, 9 royal flush
+    pub fn video_poker_rank(hand: &[u8; 11]) -> u8 {
+        let mut counts = [0u8; 13];
+        for r in 0..13 {
+            for i in 0..5 {
+                if hand[i] % 13 == r as u8 {
+                    counts[r] += 1;
+                }
+            }
+        }
+        let mut pairs = 0u8;
+        let mut high_pair = false;
+        let mut trips = false;
+        let mut quads = false;
+        let mut distinct = 0u8;
+        let mut low = 12u8;
+        let mut high = 0u8;
+        for r in 0..13 {
+            let count = counts[r];
+            if count == 2 {
+                pairs += 1;
+            }
+            // Aces (rank 0) and jacks to kings (10..=12) qualify
+            if count == 2 && (r == 0 || r >= 10) {
+                high_pair = true;
+            }
+            if count == 3 {
+                trips = true;
+            }
+            if count == 4 {
+                quads = true;
+            }
+            if count > 0 {
+                distinct += 1;
+                if (r as u8) < low {
+                    low = r as u8;
+                }
+                if r as u8 > high {
+                    high = r as u8;
+                }
+            }
+        }
+        // Aces play low (A-2-3-4-5) or high (10-J-Q-K-A)
+        let broadway = counts[0] == 1 && counts[9] == 1 && counts[10] == 1 && counts[11] == 1 && counts[12] == 1;
+        let straight = distinct == 5 && (high - low == 4 || broadway);
+        let mut flush = true;
+        for i in 1..5 {
+            if hand[i] / 13 != hand[0] / 13 {
+                flush = false;
+            }
+        }
+        if broadway && flush {
+            9
+        } else if straight && flush {
+            8
+        } else if quads {
+            7
+        } else if trips && pairs == 1 {
+            6
+        } else if flush {
+            5
+        } else if straight {
+            4
+        } else if trips {
+            3
+        } else if pairs == 2 {
+            2
+        } else if high_pair {
+            1
+        } else {
             0
-        } else if dealer_value > 21 {
+        }
+    }
+
+    #[instruction]
+    pub fn deal_video_poker(mxe: Mxe, client: Shared) -> (Enc<Mxe, Deck>, Enc<Shared, Hand>) {
+        let mut deck = INITIAL_DECK;
+        ArcisRNG::shuffle(&mut deck);
+        let hand = deal_poker_hand(&deck);
+        (mxe.from_arcis(Deck::from_array(deck)), client.from_arcis(Hand::from_array(hand)))
+    }
+
+    #[instruction]
+    pub fn draw_video_poker(
+        deck_ctxt: Enc<Mxe, Deck>,
+        hand_ctxt: Enc<Shared, Hand>,
+        hold_mask: u8,
+    ) -> Enc<Shared, Hand> {
+        let deck = deck_ctxt.to_arcis().to_array();
+        let hand = hand_ctxt.to_arcis().to_array();
+        hand_ctxt.owner.from_arcis(Hand::from_array(draw_poker_hand(&deck, &hand, hold_mask)))
+    }
+
+    // Only the rank of the final hand is revealed
+    #[instruction]
+    pub fn resolve_video_poker(hand_ctxt: Enc<Shared, Hand>) -> u8 {
+        let hand = hand_ctxt.to_arcis().to_array();
+        video_poker_rank(&hand).reveal()
+    }
+
+    // Punto Banco: a fresh six-deck shoe per coup. Player takes cards 0 and 2, banker 1 and 3,
+    // and third cards come off the shoe in order: player first, then banker.
+    pub fn baccarat_value(card: u8) -> u8 {
+        let rank = card % 13;
+        if rank >= 9 {
+            0
+        } else {
+            rank + 1
+        }
+    }
+
+    // Banker's third-card rule once the player has drawn `player_third`
+    pub fn banker_draws_after(banker: u8, player_third: u8) -> bool {
+        banker <= 2
+            || (banker == 3 && player_third != 8)
+            || (banker == 4 && player_third >= 2 && player_third <= 7)
+            || (banker == 5 && player_third >= 4 && player_third <= 7)
+            || (banker == 6 && player_third >= 6 && player_third <= 7)
+    }
+
+    // Final player and banker totals after the full drawing tableau
+    pub fn baccarat_coup(cards: &[u8; 6]) -> (u8, u8) {
+        let player = (baccarat_value(cards[0]) + baccarat_value(cards[2])) % 10;
+        let banker = (baccarat_value(cards[1]) + baccarat_value(cards[3])) % 10;
+        let natural = player >= 8 || banker >= 8;
+        let player_draws = !natural && player <= 5;
+        let player_third = baccarat_value(cards[4]);
+        let banker_draws = if player_draws {
+            banker_draws_after(banker, player_third)
+        } else {
+            !natural && banker <= 5
+        };
+        // The banker's card is the next one off the shoe, after the player's if one was drawn
+        let banker_third = if player_draws { baccarat_value(cards[5]) } else { baccarat_value(cards[4]) };
+        let player_total = if player_draws { (player + player_third) % 10 } else { player };
+        let banker_total = if banker_draws { (banker + banker_third) % 10 } else { banker };
+        (player_total, banker_total)
+    }
+
+    // 0 player, 1 banker, 2 tie
+    pub fn baccarat_winner(player_total: u8, banker_total: u8) -> u8 {
+        if player_total > banker_total {
+            0
+        } else if banker_total > player_total {
             1
-        } else if player_value > dealer_value {
+        } else {
             2
-        } else if dealer_value > player_value {
+        }
+    }
+
+    // Only the two final totals and the winner are revealed
+    #[instruction]
+    pub fn play_baccarat(_mxe: Mxe) -> (u8, u8, u8) {
+        let mut shoe = [0u8; SHOE_SIZE];
+        for d in 0..SHOE_DECKS {
+            for i in 0..52 {
+                shoe[d * 52 + i] = INITIAL_DECK[i];
+            }
+        }
+        ArcisRNG::shuffle(&mut shoe);
+        let cards = [shoe[0], shoe[1], shoe[2], shoe[3], shoe[4], shoe[5]];
+        let (player_total, banker_total) = baccarat_coup(&cards);
+        (player_total, banker_total, baccarat_winner(player_total, banker_total)).reveal()
+    }
+
+    // Three Card Poker: player takes deck[0..3], dealer deck[3..6]. Values are ace high,
+    // `(card % 13 + 12) % 13` puts twos at 0 and aces at 12.
+    pub fn poker_value(card: u8) -> u8 {
+        (card % 13 + 12) % 13
+    }
+
+    // Comparable hand score: category * 2197 plus tie-break values. Categories are
+    // 0 high card, 1 pair, 2 flush, 3 straight, 4 three of a kind, 5 straight flush.
+    pub fn three_card_score(cards: &[u8; 3]) -> u16 {
+        let a = poker_value(cards[0]);
+        let b = poker_value(cards[1]);
+        let c = poker_value(cards[2]);
+        let high = if a > b { if a > c { a } else { c } } else if b > c { b } else { c };
+        let low = if a < b { if a < c { a } else { c } } else if b < c { b } else { c };
+        let mid = a + b + c - high - low;
+        let flush = cards[0] / 13 == cards[1] / 13 && cards[1] / 13 == cards[2] / 13;
+        let trips = high == low;
+        let pair = !trips && (high == mid || mid == low);
+        // A-2-3 is the lowest straight
+        let wheel = high == 12 && mid == 1 && low == 0;
+        let straight = !trips && !pair && (high - low == 2 || wheel);
+        let top = if wheel { 1 } else { high };
+        let pair_value = if high == mid { high } else { low };
+        let kicker = if high == mid { low } else { high };
+        let category: u16 = if straight && flush {
+            5
+        } else if trips {
+            4
+        } else if straight {
             3
+        } else if flush {
+            2
+        } else if pair {
+            1
         } else {
-            4
+            0
+        };
+        let tiebreak: u16 = if straight {
+            top as u16
+        } else if trips {
+            high as u16
+        } else if pair {
+            pair_value as u16 * 13 + kicker as u16
+        } else {
+            high as u16 * 169 + mid as u16 * 13 + low as u16
         };
-        result.reveal()
+        category * 2197 + tiebreak
+    }
+
+    // Dealer needs queen high or better (queen is value 10)
+    pub fn dealer_qualifies(score: u16) -> bool {
+        score >= 10 * 169
+    }
+
+    // 0 dealer does not qualify, 1 player wins, 2 dealer wins, 3 tie
+    pub fn three_card_outcome(player_score: u16, dealer_score: u16) -> u8 {
+        if !dealer_qualifies(dealer_score) {
+            0
+        } else if player_score > dealer_score {
+            1
+        } else if dealer_score > player_score {
+            2
+        } else {
+            3
+        }
+    }
+
+    #[instruction]
+    pub fn deal_three_card_poker(mxe: Mxe, client: Shared) -> (Enc<Mxe, Hand>, Enc<Shared, Hand>) {
+        let mut deck = INITIAL_DECK;
+        ArcisRNG::shuffle(&mut deck);
+        let mut player_cards = [53; 11];
+        let mut dealer_cards = [53; 11];
+        for i in 0..3 {
+            player_cards[i] = deck[i];
+            dealer_cards[i] = deck[3 + i];
+        }
+        (mxe.from_arcis(Hand::from_array(dealer_cards)), client.from_arcis(Hand::from_array(player_cards)))
+    }
+
+    // Called once the player plays or folds: reveals both categories, the showdown
+    // outcome and the dealer's three cards
+    #[instruction]
+    pub fn resolve_three_card_poker(
+        player_hand_ctxt: Enc<Shared, Hand>,
+        dealer_hand_ctxt: Enc<Mxe, Hand>,
+    ) -> (u8, u8, u8, u8, u8, u8) {
+        let player = player_hand_ctxt.to_arcis().to_array();
+        let dealer = dealer_hand_ctxt.to_arcis().to_array();
+        let player_score = three_card_score(&[player[0], player[1], player[2]]);
+        let dealer_score = three_card_score(&[dealer[0], dealer[1], dealer[2]]);
+        (
+            (player_score / 2197) as u8,
+            (dealer_score / 2197) as u8,
+            three_card_outcome(player_score, dealer_score),
+            dealer[0],
+            dealer[1],
+            dealer[2],
+        )
+            .reveal()
+    }
+
+    // Keno circuits: numbers are 0..80 here (shown to players as 1..=80). The draw is
+    // revealed as an 80-bit mask so the program can count hits against the stored picks.
+    const KENO_NUMBERS: usize = 80;
+    const KENO_DRAWN: usize = 20;
+
+    // Bitmask of the first `KENO_DRAWN` numbers of a shuffled order
+    pub fn keno_drawn_mask(order: &[u8; KENO_NUMBERS]) -> u128 {
+        let mut mask = 0u128;
+        for i in 0..KENO_DRAWN {
+            for j in 0..KENO_NUMBERS {
+                if order[i] == j as u8 {
+                    mask += 1u128 << j;
+                }
+            }
+        }
+        mask
     }
-}
 
+    #[instruction]
+    pub fn draw_keno(_mxe: Mxe) -> u128 {
+        let mut order = [0u8; KENO_NUMBERS];
+        for i in 0..KENO_NUMBERS {
+            order[i] = i as u8;
+        }
+        ArcisRNG::shuffle(&mut order);
+        keno_drawn_mask(&order).reveal()
+    }
+
+    // Plinko: one random bit per row, set when the ball bounces right. The landing slot
+    // counts the right bounces over the first `rows` rows, so it is binomially distributed.
+    const PLINKO_MAX_ROWS: usize = 16;
+
+    pub fn plinko_slot(bounces: u16, rows: u8) -> u8 {
+        let mut slot = 0u8;
+        for i in 0..PLINKO_MAX_ROWS {
+            if (i as u8) < rows && (bounces >> i) & 1 == 1 {
+                slot += 1;
+            }
+        }
+        slot
+    }
+
+    #[instruction]
+    pub fn drop_plinko(_mxe: Mxe, rows: u8) -> u8 {
+        let mut bounces = 0u16;
+        for i in 0..PLINKO_MAX_ROWS {
+            if ArcisRNG::bool() {
+                bounces += 1u16 << i;
+            }
+        }
+        plinko_slot(bounces, rows).reveal()
+    }
+
+    // Big Six wheel: the landed segment is the first entry of a shuffled 0..64 order that
+    // falls below the wheel's segment count, which is uniform over the segments
+    const WHEEL_MAX_SEGMENTS: usize = 64;
+
+    pub fn wheel_segment(order: &[u8; WHEEL_MAX_SEGMENTS], segment_count: u8) -> u8 {
+        let mut segment = 0u8;
+        let mut found = false;
+        for i in 0..WHEEL_MAX_SEGMENTS {
+            if !found && order[i] < segment_count {
+                segment = order[i];
+                found = true;
+            }
+        }
+        segment
+    }
+
+    #[instruction]
+    pub fn spin_wheel(_mxe: Mxe, segment_count: u8) -> u8 {
+        let mut order = [0u8; WHEEL_MAX_SEGMENTS];
+        for i in 0..WHEEL_MAX_SEGMENTS {
+            order[i] = i as u8;
+        }
+        ArcisRNG::shuffle(&mut order);
+        wheel_segment(&order, segment_count).reveal()
+    }
+
+    // Lottery: the winning numbers are the first `pick_count` entries of a shuffled 0..64
+    // order that fall below `max_number`, revealed as a mask with bit `n` for number `n + 1`
+    const LOTTERY_MAX_NUMBERS: usize = 64;
+
+    pub fn lottery_mask(order: &[u8; LOTTERY_MAX_NUMBERS], pick_count: u8, max_number: u8) -> u64 {
+        let mut mask = 0u64;
+        let mut taken = 0u8;
+        for i in 0..LOTTERY_MAX_NUMBERS {
+            let take = order[i] < max_number && taken < pick_count;
+            for j in 0..LOTTERY_MAX_NUMBERS {
+                if take && order[i] == j as u8 {
+                    mask += 1u64 << j;
+                }
+            }
+            if take {
+                taken += 1;
+            }
+        }
+        mask
+    }
+
+    #[instruction]
+    pub fn draw_lottery(_mxe: Mxe, pick_count: u8, max_number: u8) -> u64 {
+        let mut order = [0u8; LOTTERY_MAX_NUMBERS];
+        for i in 0..LOTTERY_MAX_NUMBERS {
+            order[i] = i as u8;
+        }
+        ArcisRNG::shuffle(&mut order);
+        lottery_mask(&order, pick_count, max_number).reveal()
+    }
+
+    // Scratch cards: nine tiles of 3-bit symbols packed into a u32, tile `i` in bits
+    // `3i..3i + 3`, held encrypted to the MXE until the card is claimed
+    const SCRATCH_TILES: usize = 9;
+    const SCRATCH_SYMBOL_BITS: usize = 3;
+
+    pub fn scratch_symbol(card: u32, tile: u8) -> u8 {
+        let mut symbol = 0u8;
+        for i in 0..SCRATCH_TILES {
+            if i as u8 == tile {
+                symbol = ((card >> (SCRATCH_SYMBOL_BITS * i)) & 7) as u8;
+            }
+        }
+        symbol
+    }
+
+    #[instruction]
+    pub fn create_scratch_card(mxe: Mxe) -> Enc<Mxe, u32> {
+        let mut card = 0u32;
+        for i in 0..SCRATCH_TILES * SCRATCH_SYMBOL_BITS {
+            if ArcisRNG::bool() {
+                card += 1u32 << i;
+            }
+        }
+        mxe.from_arcis(card)
+    }
+
+    // One tile's symbol, encrypted to the player only
+    #[instruction]
+    pub fn scratch_tile(card_ctxt: Enc<Mxe, u32>, player: Shared, tile: u8) -> Enc<Shared, u8> {
+        player.from_arcis(scratch_symbol(card_ctxt.to_arcis(), tile))
+    }
+
+    // The whole card, public so the claimed prize can be audited
+    #[instruction]
+    pub fn reveal_scratch_card(card_ctxt: Enc<Mxe, u32>) -> u32 {
+        card_ctxt.to_arcis().reveal()
+    }
+}
@@ -0,0 +1,25 @@
+//! Aviator crash point: multipliers are hundredths, the random draw is 24 uniform bits.
+
+pub const RANDOM_RANGE: u64 = 1 << 24;
+pub const MIN_CRASH: u64 = 100;
+pub const MAX_CRASH: u64 = 10000;
+
+/// Highest multiplier `m` the flight survives for `random`: the draw survives `m` when it
+/// falls in the top `rtp / m` of the range, so P(crash >= m) = rtp / m. Floored at 1.00x and
+/// capped at 100.00x.
+pub fn crash_point(random: u64, rtp_bps: u16) -> u64 {
+    let survives = |m: u64| 100 * m * (RANDOM_RANGE - random) <= rtp_bps as u64 * RANDOM_RANGE;
+    let (mut low, mut high) = (MIN_CRASH, MAX_CRASH);
+    if !survives(low) {
+        return MIN_CRASH;
+    }
+    while low < high {
+        let mid = (low + high + 1) / 2;
+        if survives(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low
+}
@@ -0,0 +1,55 @@
+//! Punto Banco baccarat: card points, the third-card rules and the winner.
+
+// Winner codes shared with `play_baccarat`
+pub const BACCARAT_PLAYER: u8 = 0;
+pub const BACCARAT_BANKER: u8 = 1;
+pub const BACCARAT_TIE: u8 = 2;
+
+/// Baccarat points: aces 1, twos to nines face value, tens and faces 0.
+pub fn baccarat_points(card: u8) -> u8 {
+    match card % 13 {
+        rank @ 0..=8 => rank + 1,
+        _ => 0,
+    }
+}
+
+/// Plays one Punto Banco coup from the top of a shoe and returns the final
+/// (player, banker) totals. Cards are dealt player, banker, player, banker, then
+/// each side's third card in turn.
+pub fn baccarat_coup(shoe: &[u8]) -> (u8, u8) {
+    let mut next = 4;
+    let mut player = (baccarat_points(shoe[0]) + baccarat_points(shoe[2])) % 10;
+    let mut banker = (baccarat_points(shoe[1]) + baccarat_points(shoe[3])) % 10;
+    if player >= 8 || banker >= 8 {
+        return (player, banker);
+    }
+    let player_third = if player <= 5 {
+        let card = baccarat_points(shoe[next]);
+        next += 1;
+        player = (player + card) % 10;
+        Some(card)
+    } else {
+        None
+    };
+    let banker_draws = match (banker, player_third) {
+        (_, None) => banker <= 5,
+        (0..=2, _) => true,
+        (3, Some(third)) => third != 8,
+        (4, Some(third)) => (2..=7).contains(&third),
+        (5, Some(third)) => (4..=7).contains(&third),
+        (6, Some(third)) => (6..=7).contains(&third),
+        _ => false,
+    };
+    if banker_draws {
+        banker = (banker + baccarat_points(shoe[next])) % 10;
+    }
+    (player, banker)
+}
+
+pub fn baccarat_winner(player: u8, banker: u8) -> u8 {
+    match player.cmp(&banker) {
+        std::cmp::Ordering::Greater => BACCARAT_PLAYER,
+        std::cmp::Ordering::Less => BACCARAT_BANKER,
+        std::cmp::Ordering::Equal => BACCARAT_TIE,
+    }
+}
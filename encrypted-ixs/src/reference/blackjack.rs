@@ -0,0 +1,216 @@
+//! Blackjack: hand totals, the dealer rule, settlement, surrender and the side bets, plus a
+//! single-player `Game` and the multi-seat table deal.
+//!
+//! Hands draw from the same deck positions the circuits use, so a shuffled deck played
+//! through this engine and through the circuits must produce identical hands and result codes.
+
+use super::DECK_SIZE;
+
+pub const MAX_HAND_SIZE: usize = 11;
+pub const BLACKJACK: u8 = 21;
+
+// Result codes shared with `resolve_game`
+pub const RESULT_PLAYER_BUST: u8 = 0;
+pub const RESULT_DEALER_BUST: u8 = 1;
+pub const RESULT_PLAYER_WIN: u8 = 2;
+pub const RESULT_DEALER_WIN: u8 = 3;
+pub const RESULT_PUSH: u8 = 4;
+pub const RESULT_PLAYER_BLACKJACK: u8 = 5;
+pub const RESULT_SURRENDER: u8 = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rules {
+    pub dealer_hits_soft_17: bool,
+    pub double_min_total: u8,
+    pub double_max_total: u8,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules { dealer_hits_soft_17: false, double_min_total: 0, double_max_total: BLACKJACK }
+    }
+}
+
+pub fn card_value(card: u8) -> u8 {
+    match card % 13 {
+        0 => 1,
+        rank @ 1..=9 => rank + 1,
+        _ => 10,
+    }
+}
+
+pub fn is_ace(card: u8) -> bool {
+    card % 13 == 0
+}
+
+/// Best total for the hand and whether an ace is counted as 11.
+pub fn hand_total(cards: &[u8]) -> (u8, bool) {
+    let hard: u8 = cards.iter().map(|&c| card_value(c)).sum();
+    if cards.iter().any(|&c| is_ace(c)) && hard + 10 <= BLACKJACK {
+        (hard + 10, true)
+    } else {
+        (hard, false)
+    }
+}
+
+pub fn hand_value(cards: &[u8]) -> u8 {
+    hand_total(cards).0
+}
+
+pub fn is_natural(cards: &[u8]) -> bool {
+    cards.len() == 2 && hand_value(cards) == BLACKJACK
+}
+
+pub fn dealer_should_hit(cards: &[u8], rules: &Rules) -> bool {
+    let (value, soft) = hand_total(cards);
+    value < 17 || (rules.dealer_hits_soft_17 && value == 17 && soft)
+}
+
+
+/// Result code for a finished player hand against the dealer's final hand.
+pub fn settle(player: &[u8], dealer: &[u8]) -> u8 {
+    let player_value = hand_value(player);
+    let dealer_value = hand_value(dealer);
+    let player_natural = is_natural(player);
+    let dealer_natural = is_natural(dealer);
+    if player_value > BLACKJACK {
+        RESULT_PLAYER_BUST
+    } else if player_natural && !dealer_natural {
+        RESULT_PLAYER_BLACKJACK
+    } else if dealer_natural && !player_natural {
+        RESULT_DEALER_WIN
+    } else if dealer_value > BLACKJACK {
+        RESULT_DEALER_BUST
+    } else if player_value > dealer_value {
+        RESULT_PLAYER_WIN
+    } else if dealer_value > player_value {
+        RESULT_DEALER_WIN
+    } else {
+        RESULT_PUSH
+    }
+}
+
+/// Late surrender on the opening two cards, after the dealer peeks for a natural.
+pub fn surrender(player: &[u8], dealer: &[u8]) -> u8 {
+    if !is_natural(dealer) {
+        RESULT_SURRENDER
+    } else if is_natural(player) {
+        RESULT_PUSH
+    } else {
+        RESULT_DEALER_WIN
+    }
+}
+
+/// Perfect Pairs code for the player's first two cards: 0 none, 1 mixed, 2 coloured, 3 perfect.
+pub fn perfect_pairs(first: u8, second: u8) -> u8 {
+    let red = |card: u8| matches!(card / 13, 1 | 2);
+    if first % 13 != second % 13 {
+        0
+    } else if first / 13 == second / 13 {
+        3
+    } else if red(first) == red(second) {
+        2
+    } else {
+        1
+    }
+}
+
+/// 21+3 code for the player's two cards and the dealer up card:
+/// 0 none, 1 flush, 2 straight, 3 three of a kind, 4 straight flush, 5 suited trips.
+pub fn twenty_one_plus_three(cards: [u8; 3]) -> u8 {
+    let mut ranks = cards.map(|c| c % 13);
+    ranks.sort_unstable();
+    let flush = cards.iter().all(|&c| c / 13 == cards[0] / 13);
+    let trips = ranks[0] == ranks[2];
+    let straight = (ranks[1] == ranks[0] + 1 && ranks[2] == ranks[1] + 1) || ranks == [0, 11, 12];
+    match (trips, straight, flush) {
+        (true, _, true) => 5,
+        (_, true, true) => 4,
+        (true, _, false) => 3,
+        (_, true, false) => 2,
+        (_, _, true) => 1,
+        _ => 0,
+    }
+}
+
+/// One single-player hand dealt from a 52-card deck.
+#[derive(Clone, Debug)]
+pub struct Game {
+    pub deck: [u8; DECK_SIZE],
+    pub player: Vec<u8>,
+    pub dealer: Vec<u8>,
+    pub rules: Rules,
+}
+
+impl Game {
+    /// Player takes deck[0] and deck[2], dealer deck[1] (up card) and deck[3].
+    pub fn deal(deck: [u8; DECK_SIZE], rules: Rules) -> Game {
+        Game { deck, player: vec![deck[0], deck[2]], dealer: vec![deck[1], deck[3]], rules }
+    }
+
+    pub fn dealer_up_card(&self) -> u8 {
+        self.dealer[0]
+    }
+
+    pub fn perfect_pairs(&self) -> u8 {
+        perfect_pairs(self.player[0], self.player[1])
+    }
+
+    pub fn twenty_one_plus_three(&self) -> u8 {
+        twenty_one_plus_three([self.player[0], self.player[1], self.dealer_up_card()])
+    }
+
+    fn next_card(&self) -> u8 {
+        self.deck[self.player.len() + self.dealer.len()]
+    }
+
+    /// Draws one card for the player; returns whether the hand is now bust.
+    pub fn hit(&mut self) -> bool {
+        let card = self.next_card();
+        self.player.push(card);
+        hand_value(&self.player) > BLACKJACK
+    }
+
+    /// Doubles if the current total is allowed; `None` when the rules refuse the double.
+    pub fn double_down(&mut self) -> Option<bool> {
+        let value = hand_value(&self.player);
+        if value < self.rules.double_min_total || value > self.rules.double_max_total {
+            return None;
+        }
+        Some(self.hit())
+    }
+
+    pub fn dealer_play(&mut self) {
+        while dealer_should_hit(&self.dealer, &self.rules) {
+            let card = self.next_card();
+            self.dealer.push(card);
+        }
+    }
+
+    pub fn resolve(&self) -> u8 {
+        settle(&self.player, &self.dealer)
+    }
+
+    pub fn surrender(&self) -> u8 {
+        surrender(&self.player, &self.dealer)
+    }
+}
+
+pub const TABLE_SEATS: usize = 5;
+
+/// Opening deal at a multi-seat table: one card to each occupied seat in order, then the
+/// dealer, twice. Empty seats are skipped and get no cards.
+pub fn table_deal(deck: &[u8; DECK_SIZE], seated: [bool; TABLE_SEATS]) -> ([Vec<u8>; TABLE_SEATS], Vec<u8>) {
+    let mut next = deck.iter().copied();
+    let mut seats: [Vec<u8>; TABLE_SEATS] = Default::default();
+    let mut dealer = Vec::new();
+    for _ in 0..2 {
+        for seat in 0..TABLE_SEATS {
+            if seated[seat] {
+                seats[seat].push(next.next().unwrap());
+            }
+        }
+        dealer.push(next.next().unwrap());
+    }
+    (seats, dealer)
+}
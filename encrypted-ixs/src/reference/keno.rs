@@ -0,0 +1,22 @@
+//! Keno: numbers are `0..80`, the draw takes the first 20 of a shuffled order.
+
+pub const KENO_NUMBERS: usize = 80;
+pub const KENO_DRAWN: usize = 20;
+
+/// Fisher-Yates shuffle of the 80 keno numbers driven by `next_random`.
+pub fn shuffled_keno_numbers(mut next_random: impl FnMut() -> u64) -> [u8; KENO_NUMBERS] {
+    let mut order = [0u8; KENO_NUMBERS];
+    for (i, number) in order.iter_mut().enumerate() {
+        *number = i as u8;
+    }
+    for i in (1..KENO_NUMBERS).rev() {
+        let j = (next_random() % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    }
+    order
+}
+
+/// Bitmask of the drawn numbers, bit `n` set when number `n` is drawn.
+pub fn keno_drawn_mask(order: &[u8; KENO_NUMBERS]) -> u128 {
+    order[..KENO_DRAWN].iter().fold(0, |mask, &n| mask | 1u128 << n)
+}
@@ -0,0 +1,13 @@
+//! Lottery: the winning numbers come from the same shuffled `0..64` order as the wheel.
+
+use super::wheel::WHEEL_MAX_SEGMENTS;
+
+/// Lottery draw from a shuffled `0..64` order (see `wheel::shuffled_wheel_order`): the first
+/// `pick_count` numbers below `max_number`, as a mask.
+pub fn lottery_mask(order: &[u8; WHEEL_MAX_SEGMENTS], pick_count: u8, max_number: u8) -> u64 {
+    order
+        .iter()
+        .filter(|&&n| n < max_number)
+        .take(pick_count as usize)
+        .fold(0, |mask, &n| mask | 1u64 << n)
+}
@@ -0,0 +1,10 @@
+//! Memecoin price path: per-mille prices, one of 16 equally likely moves per step.
+
+pub const START_PRICE: u64 = 1000;
+pub const MAX_PRICE: u64 = u16::MAX as u64;
+pub const MOVES: [u64; 16] = [0, 500, 700, 800, 850, 900, 950, 1000, 1000, 1050, 1100, 1150, 1200, 1300, 1500, 2000];
+
+/// Price after applying move `step_move`, rounded down and capped to fit the u16 path slot.
+pub fn next_price(price: u64, step_move: u8) -> u64 {
+    (price * MOVES[step_move as usize] / 1000).min(MAX_PRICE)
+}
@@ -0,0 +1,33 @@
+//! Plaintext reference engines for the game circuits, one module per game.
+//!
+//! Each engine replays its circuits in ordinary Rust from the same deck positions and random
+//! draws, so the differential tests can check that circuit and reference agree result for
+//! result. Cards are `0..52` with `card % 13 == 0` an ace, `1..=9` twos to tens and
+//! `10..=12` faces; `card / 13` is the suit.
+
+pub mod aviator;
+pub mod baccarat;
+pub mod blackjack;
+pub mod keno;
+pub mod lottery;
+pub mod memecoin;
+pub mod plinko;
+pub mod scratch;
+pub mod three_card_poker;
+pub mod video_poker;
+pub mod wheel;
+
+pub const DECK_SIZE: usize = 52;
+
+/// Fisher-Yates shuffle of a fresh deck driven by `next_random`.
+pub fn shuffled_deck(mut next_random: impl FnMut() -> u64) -> [u8; DECK_SIZE] {
+    let mut deck = [0u8; DECK_SIZE];
+    for (i, card) in deck.iter_mut().enumerate() {
+        *card = i as u8;
+    }
+    for i in (1..DECK_SIZE).rev() {
+        let j = (next_random() % (i as u64 + 1)) as usize;
+        deck.swap(i, j);
+    }
+    deck
+}
@@ -0,0 +1,9 @@
+//! Plinko: the landing slot is the number of right bounces over the board's rows.
+
+pub const PLINKO_MIN_ROWS: u8 = 8;
+pub const PLINKO_MAX_ROWS: u8 = 16;
+
+/// Plinko landing slot: right bounces among the first `rows` bits of `bounces`.
+pub fn plinko_slot(bounces: u16, rows: u8) -> u8 {
+    (bounces as u32 & ((1u32 << rows) - 1)).count_ones() as u8
+}
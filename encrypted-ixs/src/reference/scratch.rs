@@ -0,0 +1,7 @@
+//! Scratch cards: nine tiles of 3-bit symbols packed into a u32.
+
+pub const SCRATCH_TILES: u8 = 9;
+
+pub fn scratch_symbol(card: u32, tile: u8) -> u8 {
+    (card >> (3 * tile as u32) & 7) as u8
+}
@@ -0,0 +1,42 @@
+//! Three Card Poker: hand categories, tie-breaks and the dealer's qualifying rule.
+
+// Hand categories shared with `resolve_three_card_poker`
+pub const THREE_CARD_HIGH_CARD: u8 = 0;
+pub const THREE_CARD_PAIR: u8 = 1;
+pub const THREE_CARD_FLUSH: u8 = 2;
+pub const THREE_CARD_STRAIGHT: u8 = 3;
+pub const THREE_CARD_TRIPS: u8 = 4;
+pub const THREE_CARD_STRAIGHT_FLUSH: u8 = 5;
+
+/// Three Card Poker hand as (category, tie-break values, most significant first); ordering
+/// the tuples orders the hands.
+pub fn three_card_hand(cards: [u8; 3]) -> (u8, [u8; 3]) {
+    // Ace high: twos are 0, aces 12
+    let mut values = cards.map(|c| (c % 13 + 12) % 13);
+    values.sort_unstable_by(|a, b| b.cmp(a));
+    let flush = cards.iter().all(|&c| c / 13 == cards[0] / 13);
+    let wheel = values == [12, 1, 0];
+    let straight = wheel || (values[0] == values[1] + 1 && values[1] == values[2] + 1);
+    if straight {
+        let top = if wheel { 1 } else { values[0] };
+        let category = if flush { THREE_CARD_STRAIGHT_FLUSH } else { THREE_CARD_STRAIGHT };
+        return (category, [top, 0, 0]);
+    }
+    if values[0] == values[2] {
+        return (THREE_CARD_TRIPS, [values[0], 0, 0]);
+    }
+    if values[0] == values[1] {
+        return (THREE_CARD_PAIR, [values[0], values[2], 0]);
+    }
+    if values[1] == values[2] {
+        return (THREE_CARD_PAIR, [values[1], values[0], 0]);
+    }
+    let category = if flush { THREE_CARD_FLUSH } else { THREE_CARD_HIGH_CARD };
+    (category, values)
+}
+
+/// Whether the dealer's hand plays: queen high or better.
+pub fn three_card_dealer_qualifies(cards: [u8; 3]) -> bool {
+    let (category, values) = three_card_hand(cards);
+    category > THREE_CARD_HIGH_CARD || values[0] >= 10
+}
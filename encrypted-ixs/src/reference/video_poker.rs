@@ -0,0 +1,51 @@
+//! Jacks-or-Better video poker: the draw after holding and the final hand rank.
+
+use super::DECK_SIZE;
+
+// Hand ranks shared with `resolve_video_poker`
+pub const POKER_NOTHING: u8 = 0;
+pub const POKER_JACKS_OR_BETTER: u8 = 1;
+pub const POKER_TWO_PAIR: u8 = 2;
+pub const POKER_THREE_OF_A_KIND: u8 = 3;
+pub const POKER_STRAIGHT: u8 = 4;
+pub const POKER_FLUSH: u8 = 5;
+pub const POKER_FULL_HOUSE: u8 = 6;
+pub const POKER_FOUR_OF_A_KIND: u8 = 7;
+pub const POKER_STRAIGHT_FLUSH: u8 = 8;
+pub const POKER_ROYAL_FLUSH: u8 = 9;
+
+/// Final hand after holding the cards whose bit is set; slot `i` is refilled from `deck[5 + i]`.
+pub fn video_poker_draw(deck: &[u8; DECK_SIZE], hold_mask: u8) -> [u8; 5] {
+    let mut hand = [0; 5];
+    for (i, card) in hand.iter_mut().enumerate() {
+        *card = if hold_mask & (1 << i) != 0 { deck[i] } else { deck[5 + i] };
+    }
+    hand
+}
+
+/// Jacks-or-Better rank of a five-card hand.
+pub fn video_poker_rank(cards: [u8; 5]) -> u8 {
+    let mut ranks = cards.map(|c| c % 13);
+    ranks.sort_unstable();
+    let mut counts = [0u8; 13];
+    for &rank in &ranks {
+        counts[rank as usize] += 1;
+    }
+    let mut groups: Vec<u8> = counts.iter().copied().filter(|&c| c > 1).collect();
+    groups.sort_unstable();
+    let flush = cards.iter().all(|&c| c / 13 == cards[0] / 13);
+    let royal = ranks == [0, 9, 10, 11, 12];
+    let straight = royal || ranks.windows(2).all(|w| w[1] == w[0] + 1);
+    match () {
+        _ if royal && flush => POKER_ROYAL_FLUSH,
+        _ if straight && flush => POKER_STRAIGHT_FLUSH,
+        _ if groups == [4] => POKER_FOUR_OF_A_KIND,
+        _ if groups == [2, 3] => POKER_FULL_HOUSE,
+        _ if flush => POKER_FLUSH,
+        _ if straight => POKER_STRAIGHT,
+        _ if groups == [3] => POKER_THREE_OF_A_KIND,
+        _ if groups == [2, 2] => POKER_TWO_PAIR,
+        _ if counts[0] == 2 || counts[10..].contains(&2) => POKER_JACKS_OR_BETTER,
+        _ => POKER_NOTHING,
+    }
+}
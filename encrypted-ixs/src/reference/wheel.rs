@@ -0,0 +1,21 @@
+//! Big Six wheel: a shuffled `0..64` order, the first entry on the wheel is the landed segment.
+
+pub const WHEEL_MAX_SEGMENTS: usize = 64;
+
+/// Fisher-Yates shuffle of the 64 wheel positions driven by `next_random`.
+pub fn shuffled_wheel_order(mut next_random: impl FnMut() -> u64) -> [u8; WHEEL_MAX_SEGMENTS] {
+    let mut order = [0u8; WHEEL_MAX_SEGMENTS];
+    for (i, position) in order.iter_mut().enumerate() {
+        *position = i as u8;
+    }
+    for i in (1..WHEEL_MAX_SEGMENTS).rev() {
+        let j = (next_random() % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    }
+    order
+}
+
+/// Landed segment for a wheel of `segment_count` segments.
+pub fn wheel_segment(order: &[u8; WHEEL_MAX_SEGMENTS], segment_count: u8) -> u8 {
+    *order.iter().find(|&&position| position < segment_count).expect("segment_count > 0")
+}
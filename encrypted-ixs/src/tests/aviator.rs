@@ -0,0 +1,65 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::aviator::{self, MAX_CRASH, MIN_CRASH, RANDOM_RANGE};
+
+const RTPS: [u16; 3] = [9600, 9900, 10000];
+
+#[test]
+fn crash_points_match() {
+    let mut rng = XorShift(0xa1a7_0c4a);
+    for rtp_bps in RTPS {
+        for _ in 0..100_000 {
+            let random = rng.next() % RANDOM_RANGE;
+            assert_eq!(circuits::crash_point(random, rtp_bps), aviator::crash_point(random, rtp_bps), "{random} at {rtp_bps}");
+        }
+        // Both ends of the draw and the cap boundary
+        for random in [0, 1, RANDOM_RANGE / 2, RANDOM_RANGE - RANDOM_RANGE / 100, RANDOM_RANGE - 2, RANDOM_RANGE - 1] {
+            assert_eq!(circuits::crash_point(random, rtp_bps), aviator::crash_point(random, rtp_bps), "{random} at {rtp_bps}");
+        }
+    }
+}
+
+#[test]
+fn crash_point_is_monotonic_and_bounded() {
+    for rtp_bps in RTPS {
+        let mut previous = MIN_CRASH;
+        for random in (0..RANDOM_RANGE).step_by(997) {
+            let crash = circuits::crash_point(random, rtp_bps);
+            assert!((MIN_CRASH..=MAX_CRASH).contains(&crash));
+            assert!(crash >= previous, "{random} at {rtp_bps}");
+            previous = crash;
+        }
+        assert_eq!(circuits::crash_point(RANDOM_RANGE - 1, rtp_bps), MAX_CRASH);
+    }
+}
+
+/// First draw whose crash point reaches `multiplier`; every later draw reaches it too.
+fn first_surviving_draw(multiplier: u64, rtp_bps: u16) -> u64 {
+    let (mut low, mut high) = (0, RANDOM_RANGE - 1);
+    while low < high {
+        let mid = (low + high) / 2;
+        if circuits::crash_point(mid, rtp_bps) >= multiplier {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+#[test]
+fn survival_odds_are_rtp_over_multiplier() {
+    for rtp_bps in RTPS {
+        for multiplier in [101, 150, 200, 500, 1000, 2500, MAX_CRASH] {
+            let surviving = RANDOM_RANGE - first_surviving_draw(multiplier, rtp_bps);
+            let expected = rtp_bps as u64 * RANDOM_RANGE / (100 * multiplier);
+            assert_eq!(surviving, expected, "{multiplier} at {rtp_bps}");
+        }
+        // Every multiplier above 1.00x carries the house edge: a player cashing out at m expects
+        // m * P(crash >= m) <= rtp
+        for multiplier in (101..=MAX_CRASH).step_by(37) {
+            let surviving = RANDOM_RANGE - first_surviving_draw(multiplier, rtp_bps);
+            assert!(multiplier as u128 * surviving as u128 * 10000 <= rtp_bps as u128 * 100 * RANDOM_RANGE as u128);
+        }
+    }
+}
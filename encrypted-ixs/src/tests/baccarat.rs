@@ -0,0 +1,36 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::{self, baccarat};
+
+#[test]
+fn baccarat_coups_match_for_every_rank_sequence() {
+    // Every six-rank sequence is equally likely from an infinite shoe
+    let mut outcomes = [0u64; 3];
+    let mut ranks = [0u8; 6];
+    for code in 0..13u32.pow(6) {
+        let mut rest = code;
+        for rank in ranks.iter_mut() {
+            *rank = (rest % 13) as u8;
+            rest /= 13;
+        }
+        let expected = baccarat::baccarat_coup(&ranks);
+        assert_eq!(circuits::baccarat_coup(&ranks), expected, "{ranks:?}");
+        let winner = circuits::baccarat_winner(expected.0, expected.1);
+        assert_eq!(winner, baccarat::baccarat_winner(expected.0, expected.1));
+        outcomes[winner as usize] += 1;
+    }
+    // Infinite-shoe odds: player 44.61%, banker 45.84%, tie 9.54%
+    assert_eq!(outcomes[baccarat::BACCARAT_PLAYER as usize], 2_153_464);
+    assert_eq!(outcomes[baccarat::BACCARAT_BANKER as usize], 2_212_744);
+    assert_eq!(outcomes[baccarat::BACCARAT_TIE as usize], 460_601);
+}
+
+#[test]
+fn baccarat_uses_the_top_of_a_shuffled_shoe() {
+    let mut rng = XorShift(0xbacc_a7a7);
+    for _ in 0..5000 {
+        let deck = reference::shuffled_deck(|| rng.next());
+        let cards = [deck[0], deck[1], deck[2], deck[3], deck[4], deck[5]];
+        assert_eq!(circuits::baccarat_coup(&cards), baccarat::baccarat_coup(&deck));
+    }
+}
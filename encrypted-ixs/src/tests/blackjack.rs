@@ -0,0 +1,244 @@
+use super::{padded, XorShift, EMPTY};
+use crate::circuits;
+use crate::reference::{self, blackjack::{self, Game, Rules}};
+
+fn rule_variants() -> [Rules; 3] {
+    [
+        Rules::default(),
+        Rules { dealer_hits_soft_17: true, ..Rules::default() },
+        Rules { dealer_hits_soft_17: true, double_min_total: 9, double_max_total: 11 },
+    ]
+}
+
+#[test]
+fn card_values_match() {
+    for card in 0..52u8 {
+        assert_eq!(circuits::card_value(card), blackjack::card_value(card), "card {card}");
+    }
+    // Each rank appears four times, with sixteen ten-valued cards
+    let tens = (0..52u8).filter(|&c| blackjack::card_value(c) == 10).count();
+    assert_eq!(tens, 16);
+}
+
+#[test]
+fn hand_totals_match_exhaustively() {
+    for a in 0..52u8 {
+        for b in 0..52u8 {
+            let two = [a, b];
+            assert_eq!(circuits::hand_total(&padded(&two), 2), blackjack::hand_total(&two), "{two:?}");
+            for c in 0..52u8 {
+                let three = [a, b, c];
+                assert_eq!(circuits::hand_total(&padded(&three), 3), blackjack::hand_total(&three), "{three:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn multiple_aces_count_once_as_eleven() {
+    // A A is soft 12, A A 9 is soft 21, four aces and a 9 is hard 13
+    assert_eq!(blackjack::hand_total(&[0, 13]), (12, true));
+    assert_eq!(circuits::hand_total(&padded(&[0, 13]), 2), (12, true));
+    assert_eq!(blackjack::hand_total(&[0, 13, 8]), (21, true));
+    assert_eq!(blackjack::hand_total(&[0, 13, 26, 39, 8]), (13, false));
+    assert_eq!(circuits::hand_total(&padded(&[0, 13, 26, 39, 8]), 5), (13, false));
+}
+
+#[test]
+fn dealer_rule_matches_exhaustively() {
+    for rules in rule_variants() {
+        for a in 0..52u8 {
+            for b in 0..52u8 {
+                for c in 0..52u8 {
+                    let hand = [a, b, c];
+                    assert_eq!(
+                        circuits::dealer_should_hit(&padded(&hand), 3, rules.dealer_hits_soft_17),
+                        blackjack::dealer_should_hit(&hand, &rules),
+                        "{hand:?} {rules:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn side_bets_match_exhaustively() {
+    let mut counts = [0u32; 6];
+    for a in 0..52u8 {
+        for b in 0..52u8 {
+            if a == b {
+                continue;
+            }
+            assert_eq!(circuits::perfect_pairs_result(a, b), blackjack::perfect_pairs(a, b), "{a} {b}");
+            for c in 0..52u8 {
+                if c == a || c == b {
+                    continue;
+                }
+                let code = blackjack::twenty_one_plus_three([a, b, c]);
+                assert_eq!(circuits::twenty_one_plus_three_result(a, b, c), code, "{a} {b} {c}");
+                counts[code as usize] += 1;
+            }
+        }
+    }
+    // Ordered three-card draws from one deck: 22100 combinations times 6 orderings
+    assert_eq!(counts.iter().sum::<u32>(), 22100 * 6);
+    assert_eq!(counts[4], 48 * 6); // 12 straights per suit (A-2-3 through Q-K-A)
+    assert_eq!(counts[3], 52 * 6); // 13 ranks, 4 ways to pick three suits
+    assert_eq!(counts[5], 0); // suited trips need more than one deck
+    assert_eq!(counts[2], (12 * 64 - 48) * 6);
+    assert_eq!(counts[1], (4 * 286 - 48) * 6);
+}
+
+#[test]
+fn side_bets_use_the_dealt_cards() {
+    let mut rng = XorShift(0xdead_beef);
+    for _ in 0..5000 {
+        let deck = reference::shuffled_deck(|| rng.next());
+        let game = Game::deal(deck, Rules::default());
+        let (player, dealer) = circuits::deal_hands(&deck);
+        assert_eq!(circuits::perfect_pairs_result(player[0], player[1]), game.perfect_pairs());
+        assert_eq!(circuits::twenty_one_plus_three_result(player[0], player[1], dealer[0]), game.twenty_one_plus_three());
+    }
+}
+
+#[test]
+fn deck_and_hand_packing_round_trip() {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..1000 {
+        let deck = reference::shuffled_deck(|| rng.next());
+        assert_eq!(circuits::Deck::from_array(deck).to_array(), deck);
+        let mut hand = [EMPTY; 11];
+        hand[..11].copy_from_slice(&deck[..11]);
+        assert_eq!(circuits::Hand::from_array(hand).to_array(), hand);
+    }
+}
+
+#[test]
+fn reference_shuffle_is_a_permutation() {
+    let mut rng = XorShift(7);
+    for _ in 0..1000 {
+        let mut deck = reference::shuffled_deck(|| rng.next());
+        deck.sort_unstable();
+        assert!(deck.iter().enumerate().all(|(i, &c)| c == i as u8));
+    }
+}
+
+#[test]
+fn random_games_match() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for round in 0..20_000 {
+        let rules = rule_variants()[round % 3];
+        let deck = reference::shuffled_deck(|| rng.next());
+        let mut game = Game::deal(deck, rules);
+
+        // Circuit side: opening deal
+        let (mut player, dealer) = circuits::deal_hands(&deck);
+        let mut player_size = 2u8;
+        let dealer_size = 2u8;
+        assert_eq!(player[..2], game.player[..]);
+        assert_eq!(dealer[..2], game.dealer[..]);
+
+        assert_eq!(circuits::surrender_result(&player, &dealer), game.surrender());
+
+        // Random strategy: hit up to a target total, sometimes double on the first decision
+        let target = 12 + (rng.next() % 7) as u8;
+        let mut doubled = false;
+        if rng.next() % 4 == 0 {
+            let (hand, is_bust, allowed) = circuits::double_down_hand(
+                &deck,
+                &player,
+                player_size,
+                player_size + dealer_size,
+                rules.double_min_total,
+                rules.double_max_total,
+            );
+            let expected = game.double_down();
+            assert_eq!(allowed, expected.is_some());
+            assert_eq!(is_bust, expected.unwrap_or(false));
+            if allowed {
+                player = hand;
+                player_size += 1;
+                doubled = true;
+            }
+        }
+        while !doubled
+            && (player_size as usize) < blackjack::MAX_HAND_SIZE
+            && blackjack::hand_value(&game.player) < target
+        {
+            let (hand, is_bust) = circuits::draw_card(&deck, &player, player_size, player_size + dealer_size);
+            assert_eq!(is_bust, game.hit());
+            player = hand;
+            player_size += 1;
+        }
+        assert_eq!(player[..player_size as usize], game.player[..]);
+
+        let (dealer, final_dealer_size) = circuits::dealer_draw(
+            &deck,
+            &dealer,
+            dealer_size,
+            player_size + dealer_size,
+            rules.dealer_hits_soft_17,
+        );
+        game.dealer_play();
+        assert_eq!(dealer[..final_dealer_size as usize], game.dealer[..], "deck {deck:?}");
+
+        assert_eq!(
+            circuits::settle_hand(&player, player_size, &dealer, final_dealer_size),
+            game.resolve(),
+            "deck {deck:?}"
+        );
+    }
+}
+
+#[test]
+fn settlement_matches_for_every_opening_pair() {
+    let mut rng = XorShift(42);
+    for a in 0..52u8 {
+        for b in 0..52u8 {
+            if a == b {
+                continue;
+            }
+            // Dealer opens with (a, b); the rest of the deck is random
+            let mut deck = reference::shuffled_deck(|| rng.next());
+            let ia = deck.iter().position(|&c| c == a).unwrap();
+            deck.swap(1, ia);
+            let ib = deck.iter().position(|&c| c == b).unwrap();
+            deck.swap(3, ib);
+            for rules in rule_variants() {
+                let mut game = Game::deal(deck, rules);
+                let (player, dealer) = circuits::deal_hands(&deck);
+                let (dealer, size) = circuits::dealer_draw(&deck, &dealer, 2, 4, rules.dealer_hits_soft_17);
+                game.dealer_play();
+                assert_eq!(dealer[..size as usize], game.dealer[..]);
+                assert_eq!(circuits::settle_hand(&player, 2, &dealer, size), game.resolve());
+            }
+        }
+    }
+}
+
+#[test]
+fn table_deal_skips_empty_seats() {
+    let mut rng = XorShift(29);
+    for _ in 0..20 {
+        let deck = reference::shuffled_deck(|| rng.next());
+        for mask in 0..1u8 << blackjack::TABLE_SEATS {
+            let seated: [bool; blackjack::TABLE_SEATS] = std::array::from_fn(|s| mask >> s & 1 == 1);
+            let (seats, dealer) = blackjack::table_deal(&deck, seated);
+            let (seat_cards, dealer_cards) = circuits::deal_table_hands(&deck, mask);
+            for s in 0..blackjack::TABLE_SEATS {
+                assert_eq!(seat_cards[s], padded(&seats[s]), "mask {mask:05b}");
+            }
+            assert_eq!(dealer_cards, padded(&dealer), "mask {mask:05b}");
+
+            // Only the top 2 * (seated + 1) cards leave the deck
+            let dealt = 2 * (mask.count_ones() as usize + 1);
+            let mut used: Vec<u8> = seats.concat();
+            used.extend(&dealer);
+            used.sort_unstable();
+            let mut top = deck[..dealt].to_vec();
+            top.sort_unstable();
+            assert_eq!(used, top, "mask {mask:05b}");
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::keno;
+
+#[test]
+fn keno_mask_holds_the_first_twenty_numbers() {
+    let mut rng = XorShift(0x4e0_4e0);
+    for _ in 0..2000 {
+        let order = keno::shuffled_keno_numbers(|| rng.next());
+        let mask = circuits::keno_drawn_mask(&order);
+        assert_eq!(mask, keno::keno_drawn_mask(&order));
+        assert_eq!(mask.count_ones() as usize, keno::KENO_DRAWN);
+        assert_eq!(mask >> keno::KENO_NUMBERS, 0);
+    }
+}
@@ -0,0 +1,17 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::{lottery, wheel};
+
+#[test]
+fn lottery_draws_match_and_pick_distinct_numbers_in_range() {
+    let mut rng = XorShift(0x1077_e4e);
+    for (pick_count, max_number) in [(3u8, 10u8), (5, 36), (6, 49), (6, 64)] {
+        for _ in 0..2000 {
+            let order = wheel::shuffled_wheel_order(|| rng.next());
+            let mask = circuits::lottery_mask(&order, pick_count, max_number);
+            assert_eq!(mask, lottery::lottery_mask(&order, pick_count, max_number));
+            assert_eq!(mask.count_ones(), pick_count as u32);
+            assert!(max_number == 64 || mask >> max_number == 0);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::memecoin::{self, MAX_PRICE, MOVES, START_PRICE};
+
+#[test]
+fn next_prices_match() {
+    for price in 0..=MAX_PRICE {
+        for step_move in 0..16 {
+            assert_eq!(circuits::next_price(price, step_move), memecoin::next_price(price, step_move), "{price} move {step_move}");
+        }
+    }
+}
+
+#[test]
+fn moves_average_to_the_price() {
+    assert_eq!(MOVES.iter().sum::<u64>(), 16 * 1000);
+    // Every move is a multiple of 5%, so prices on a multiple of 20 move without rounding and the
+    // expected next price is the price itself until the cap bites
+    for price in (0..=MAX_PRICE / 2).step_by(20) {
+        let total: u64 = (0..16).map(|step_move| circuits::next_price(price, step_move)).sum();
+        assert_eq!(total, 16 * price, "{price}");
+    }
+    // Rounding and the cap only ever take value away from the holder
+    for price in 0..=MAX_PRICE {
+        let total: u64 = (0..16).map(|step_move| circuits::next_price(price, step_move)).sum();
+        assert!(total <= 16 * price, "{price}");
+    }
+}
+
+#[test]
+fn price_paths_round_trip() {
+    let mut rng = XorShift(0x3e3e_c014);
+    for _ in 0..10_000 {
+        let mut prices = [0u16; 16];
+        let mut price = START_PRICE;
+        for slot in prices.iter_mut() {
+            price = memecoin::next_price(price, (rng.next() % 16) as u8);
+            *slot = price as u16;
+        }
+        assert_eq!(circuits::PricePath::from_array(prices).to_array(), prices);
+    }
+}
@@ -0,0 +1,33 @@
+//! Differential tests, one module per game: the circuit helpers run as plain Rust against the
+//! matching `reference` engine. The `#[instruction]` wrappers only add encryption around these.
+
+mod aviator;
+mod baccarat;
+mod blackjack;
+mod keno;
+mod lottery;
+mod memecoin;
+mod plinko;
+mod scratch;
+mod three_card_poker;
+mod video_poker;
+mod wheel;
+
+const EMPTY: u8 = 53;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn padded(cards: &[u8]) -> [u8; 11] {
+    let mut hand = [EMPTY; 11];
+    hand[..cards.len()].copy_from_slice(cards);
+    hand
+}
@@ -0,0 +1,21 @@
+use crate::circuits;
+use crate::reference::plinko;
+
+#[test]
+fn plinko_slots_match_and_are_binomial() {
+    for rows in plinko::PLINKO_MIN_ROWS..=plinko::PLINKO_MAX_ROWS {
+        let mut slots = [0u64; 17];
+        for bounces in 0..=u16::MAX {
+            let slot = circuits::plinko_slot(bounces, rows);
+            assert_eq!(slot, plinko::plinko_slot(bounces, rows));
+            slots[slot as usize] += 1;
+        }
+        // Every slot k is reached C(rows, k) times per 2^rows paths; the unused high
+        // bits repeat each path 2^(16 - rows) times
+        let mut ways = 1u64;
+        for k in 0..=rows as u64 {
+            assert_eq!(slots[k as usize], ways << (16 - rows));
+            ways = ways * (rows as u64 - k) / (k + 1);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::scratch;
+
+#[test]
+fn scratch_symbols_match_for_every_tile() {
+    let mut rng = XorShift(0x5c4a_7c4);
+    for _ in 0..5000 {
+        let card = (rng.next() & ((1 << 27) - 1)) as u32;
+        for tile in 0..scratch::SCRATCH_TILES {
+            assert_eq!(circuits::scratch_symbol(card, tile), scratch::scratch_symbol(card, tile));
+        }
+        // Tiles past the card read as symbol 0 rather than spilling into unused bits
+        assert_eq!(circuits::scratch_symbol(card, scratch::SCRATCH_TILES), 0);
+    }
+}
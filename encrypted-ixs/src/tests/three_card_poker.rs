@@ -0,0 +1,52 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::{self, three_card_poker};
+
+#[test]
+fn three_card_categories_match_exhaustively() {
+    let mut counts = [0u32; 6];
+    let mut qualifying = 0u32;
+    for a in 0..52u8 {
+        for b in a + 1..52 {
+            for c in b + 1..52 {
+                let cards = [a, b, c];
+                let (category, _) = three_card_poker::three_card_hand(cards);
+                let score = circuits::three_card_score(&cards);
+                assert_eq!((score / 2197) as u8, category, "{cards:?}");
+                let qualifies = three_card_poker::three_card_dealer_qualifies(cards);
+                assert_eq!(circuits::dealer_qualifies(score), qualifies, "{cards:?}");
+                counts[category as usize] += 1;
+                qualifying += qualifies as u32;
+            }
+        }
+    }
+    assert_eq!(counts, [16440, 3744, 1096, 720, 52, 48]);
+    // Jack high or worse fails: 120 rank sets from two to jack, less 8 straights, each in
+    // 64 suit patterns less 4 flushes
+    assert_eq!(22100 - qualifying, 112 * 60);
+}
+
+#[test]
+fn three_card_scores_order_like_the_reference() {
+    let mut rng = XorShift(0x3c_a2d);
+    for _ in 0..200_000 {
+        let deck = reference::shuffled_deck(|| rng.next());
+        let player = [deck[0], deck[1], deck[2]];
+        let dealer = [deck[3], deck[4], deck[5]];
+        let expected = three_card_poker::three_card_hand(player).cmp(&three_card_poker::three_card_hand(dealer));
+        let player_score = circuits::three_card_score(&player);
+        let dealer_score = circuits::three_card_score(&dealer);
+        assert_eq!(player_score.cmp(&dealer_score), expected, "{player:?} {dealer:?}");
+        let outcome = circuits::three_card_outcome(player_score, dealer_score);
+        let code = if !three_card_poker::three_card_dealer_qualifies(dealer) {
+            0
+        } else {
+            match expected {
+                std::cmp::Ordering::Greater => 1,
+                std::cmp::Ordering::Less => 2,
+                std::cmp::Ordering::Equal => 3,
+            }
+        };
+        assert_eq!(outcome, code);
+    }
+}
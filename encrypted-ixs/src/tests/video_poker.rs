@@ -0,0 +1,48 @@
+use super::{padded, XorShift};
+use crate::circuits;
+use crate::reference::{self, video_poker};
+
+#[test]
+fn video_poker_ranks_match_exhaustively() {
+    let mut counts = [0u32; 10];
+    for a in 0..52u8 {
+        for b in a + 1..52 {
+            for c in b + 1..52 {
+                for d in c + 1..52 {
+                    for e in d + 1..52 {
+                        let cards = [a, b, c, d, e];
+                        let rank = video_poker::video_poker_rank(cards);
+                        assert_eq!(circuits::video_poker_rank(&padded(&cards)), rank, "{cards:?}");
+                        counts[rank as usize] += 1;
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(counts.iter().sum::<u32>(), 2_598_960);
+    assert_eq!(counts[video_poker::POKER_ROYAL_FLUSH as usize], 4);
+    assert_eq!(counts[video_poker::POKER_STRAIGHT_FLUSH as usize], 36);
+    assert_eq!(counts[video_poker::POKER_FOUR_OF_A_KIND as usize], 624);
+    assert_eq!(counts[video_poker::POKER_FULL_HOUSE as usize], 3744);
+    assert_eq!(counts[video_poker::POKER_FLUSH as usize], 5108);
+    assert_eq!(counts[video_poker::POKER_STRAIGHT as usize], 10200);
+    assert_eq!(counts[video_poker::POKER_THREE_OF_A_KIND as usize], 54912);
+    assert_eq!(counts[video_poker::POKER_TWO_PAIR as usize], 123552);
+    assert_eq!(counts[video_poker::POKER_JACKS_OR_BETTER as usize], 4 * 84480);
+}
+
+#[test]
+fn video_poker_draws_match() {
+    let mut rng = XorShift(0x5eed_cafe);
+    for _ in 0..2000 {
+        let deck = reference::shuffled_deck(|| rng.next());
+        let dealt = circuits::deal_poker_hand(&deck);
+        assert_eq!(dealt[..5], deck[..5]);
+        for hold_mask in 0..32u8 {
+            let drawn = circuits::draw_poker_hand(&deck, &dealt, hold_mask);
+            let expected = video_poker::video_poker_draw(&deck, hold_mask);
+            assert_eq!(drawn[..5], expected);
+            assert_eq!(circuits::video_poker_rank(&drawn), video_poker::video_poker_rank(expected));
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use super::XorShift;
+use crate::circuits;
+use crate::reference::wheel;
+
+#[test]
+fn wheel_segments_match_and_stay_on_the_wheel() {
+    let mut rng = XorShift(0xb16_5150);
+    for segment_count in 1..=wheel::WHEEL_MAX_SEGMENTS as u8 {
+        let mut seen = [false; wheel::WHEEL_MAX_SEGMENTS];
+        for _ in 0..2000 {
+            let order = wheel::shuffled_wheel_order(|| rng.next());
+            let segment = circuits::wheel_segment(&order, segment_count);
+            assert_eq!(segment, wheel::wheel_segment(&order, segment_count));
+            assert!(segment < segment_count);
+            seen[segment as usize] = true;
+        }
+        assert!(seen[..segment_count as usize].iter().all(|&hit| hit));
+    }
+}
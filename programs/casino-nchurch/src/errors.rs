@@ -67,6 +67,105 @@ pub enum CasinoError {
 
     #[msg("The dealer client pubkey is invalid")]
     InvalidDealerClientPubkey,
+
+    #[msg("Blackjack shoe is in use by another hand")]
+    ShoeInUse,
+
+    #[msg("Blackjack shoe must be reshuffled")]
+    ShoeNeedsShuffle,
+
+    #[msg("Invalid cut card position")]
+    InvalidCutCardPosition,
+
+    #[msg("Seat is not available")]
+    SeatNotAvailable,
+
+    #[msg("It is not this seat's turn")]
+    NotYourTurn,
+
+    #[msg("No players are seated at the table")]
+    TableEmpty,
+
+    #[msg("Not enough cards left in the deck")]
+    DeckExhausted,
+
+    #[msg("Game has not been idle long enough to crank")]
+    GameNotTimedOut,
+
+    #[msg("Invalid blackjack config")]
+    InvalidBlackjackConfig,
+
+    #[msg("Too many open blackjack games")]
+    TooManyOpenGames,
+
+    #[msg("Side bet not offered or above the main stake")]
+    InvalidSideBet,
+
+    #[msg("Revealed deck does not match the hand")]
+    InvalidDeckReveal,
+
+    #[msg("Deck can still be revealed for audit")]
+    DeckRevealPending,
+
+    #[msg("Crash round is full")]
+    CrashRoundFull,
+
+    #[msg("Already cashed out of this round")]
+    AlreadyCashedOut,
+
+    #[msg("Invalid mines grid size or mine count")]
+    InvalidMinesConfig,
+
+    #[msg("Tile already revealed")]
+    TileAlreadyRevealed,
+
+    #[msg("Hi-Lo ladder is at its maximum multiplier")]
+    HiloLadderMaxed,
+
+    #[msg("Invalid paytable")]
+    InvalidPaytable,
+
+    #[msg("Invalid baccarat config")]
+    InvalidBaccaratConfig,
+
+    #[msg("Keno picks must be 1-10 distinct numbers from 1 to 80")]
+    InvalidKenoPicks,
+
+    #[msg("Plinko rows must be between 8 and 16")]
+    InvalidPlinkoRows,
+
+    #[msg("Plinko table RTP does not match the configured RTP")]
+    PlinkoRtpMismatch,
+
+    #[msg("Invalid wheel config")]
+    InvalidWheelConfig,
+
+    #[msg("Wheel bet is on a segment type the wheel does not pay")]
+    InvalidWheelBet,
+
+    #[msg("Invalid lottery round config")]
+    InvalidLotteryConfig,
+
+    #[msg("Lottery numbers must be distinct and within the round's range")]
+    InvalidLotteryNumbers,
+
+    #[msg("Lottery round is sold out")]
+    LotteryRoundFull,
+
+    #[msg("Lottery ticket did not win a prize")]
+    LotteryTicketNotWinning,
+
+    #[msg("Craps bet is not allowed in the current phase")]
+    InvalidCrapsBet,
+
+    #[msg("Craps odds exceed the 3-4-5x limit")]
+    CrapsOddsTooLarge,
+
+    #[msg("Invalid Sic Bo payout config")]
+    InvalidSicBoConfig,
+
+    #[msg("Sic Bo bet is on a position the table does not pay")]
+    InvalidSicBoBet,
 }
 
 #[error_code]
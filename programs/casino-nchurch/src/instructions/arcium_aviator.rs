@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::calculate_aviator_payout;
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_CRASH_AVIATOR;
+
+pub fn init_crash_aviator_comp_def(ctx: Context<InitCrashAviatorCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Places an aviator bet with an auto-cashout target. The crash point is drawn in MPC
+/// after the bet is escrowed and only revealed to settle the round.
+pub fn play_aviator(
+    ctx: Context<PlayAviator>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    cashout_multiplier: u64,
+    nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    require!(
+        (AVIATOR_MIN_CASHOUT..=AVIATOR_MAX_CASHOUT).contains(&cashout_multiplier),
+        CasinoError::InvalidAviatorCashout
+    );
+
+    // Escrow the stake in the vault until the crash point is revealed
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.aviator_game;
+    game.user = ctx.accounts.payer.key();
+    game.game_id = game_id;
+    game.bet_amount = bet_amount;
+    game.cashout_multiplier = cashout_multiplier;
+    game.crash_multiplier = 0;
+    game.payout = 0;
+    game.is_complete = false;
+    game.bump = ctx.bumps.aviator_game;
+
+    let args = vec![
+        Argument::PlaintextU128(nonce),
+        Argument::PlaintextU16(ctx.accounts.casino_state.house_edge_config.aviator_rtp_bps),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![CrashAviatorCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.aviator_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct AviatorResultEvent { pub game_id: u64, pub crash_multiplier: u64, pub cashout_multiplier: u64, pub payout: u64 }
+
+pub fn crash_aviator_callback(
+    ctx: Context<CrashAviatorCallback>,
+    output: ComputationOutputs<CrashAviatorOutput>,
+) -> Result<()> {
+    let crash_multiplier = match output {
+        ComputationOutputs::Success(CrashAviatorOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.aviator_game;
+    require!(!game.is_complete, CasinoError::GameAlreadyCompleted);
+    let bet_amount = game.bet_amount;
+    let payout = calculate_aviator_payout(game.cashout_multiplier, crash_multiplier, bet_amount);
+    game.crash_multiplier = crash_multiplier;
+    game.payout = payout;
+    game.is_complete = true;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount;
+    }
+
+    emit!(AviatorResultEvent {
+        game_id: ctx.accounts.aviator_game.game_id,
+        crash_multiplier,
+        cashout_multiplier: ctx.accounts.aviator_game.cashout_multiplier,
+        payout,
+    });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[queue_computation_accounts("crash_aviator", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct PlayAviator<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CRASH_AVIATOR))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = AviatorGame::DISCRIMINATOR.len() + AviatorGame::INIT_SPACE,
+        seeds = [b"aviator_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub aviator_game: Box<Account<'info, AviatorGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("crash_aviator")]
+#[derive(Accounts)]
+pub struct CrashAviatorCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CRASH_AVIATOR))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub aviator_game: Box<Account<'info, AviatorGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == aviator_game.user @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", aviator_game.user.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[init_computation_definition_accounts("crash_aviator", payer)]
+#[derive(Accounts)]
+pub struct InitCrashAviatorCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::calculate_baccarat_payout;
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_PLAY_BACCARAT;
+
+pub fn init_play_baccarat_comp_def(ctx: Context<InitPlayBaccaratCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn set_baccarat_config(ctx: Context<SetBaccaratConfig>, banker_commission_bps: u16, tie_payout: u8) -> Result<()> {
+    require!(banker_commission_bps <= BACCARAT_MAX_COMMISSION_BPS, CasinoError::InvalidBaccaratConfig);
+    require!(tie_payout >= 1 && tie_payout <= BACCARAT_MAX_TIE_PAYOUT, CasinoError::InvalidBaccaratConfig);
+
+    let config = &mut ctx.accounts.baccarat_config;
+    config.authority = ctx.accounts.authority.key();
+    config.banker_commission_bps = banker_commission_bps;
+    config.tie_payout = tie_payout;
+    config.bump = ctx.bumps.baccarat_config;
+    Ok(())
+}
+
+/// Places a Player, Banker or Tie bet. The whole coup, third-card tableau included, is
+/// played in MPC over a freshly shuffled shoe; only the totals and the winner come back.
+pub fn play_baccarat(
+    ctx: Context<PlayBaccarat>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    bet: BaccaratBet,
+    nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+
+    // Escrow the stake in the vault until the coup is settled
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.baccarat_game;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bet_amount = bet_amount;
+    game.bet = bet;
+    game.banker_commission_bps = ctx.accounts.baccarat_config.banker_commission_bps;
+    game.tie_payout = ctx.accounts.baccarat_config.tie_payout;
+    game.player_total = 0;
+    game.banker_total = 0;
+    game.winner = BaccaratBet::Tie;
+    game.payout = 0;
+    game.is_complete = false;
+    game.bump = ctx.bumps.baccarat_game;
+
+    let args = vec![Argument::PlaintextU128(nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![PlayBaccaratCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.baccarat_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct BaccaratResultEvent { pub game_id: u64, pub player_total: u8, pub banker_total: u8, pub winner: BaccaratBet, pub payout: u64 }
+
+pub fn play_baccarat_callback(
+    ctx: Context<PlayBaccaratCallback>,
+    output: ComputationOutputs<PlayBaccaratOutput>,
+) -> Result<()> {
+    let (player_total, banker_total, winner_code) = match output {
+        ComputationOutputs::Success(PlayBaccaratOutput { field_0: PlayBaccaratOutputStruct0 { field_0, field_1, field_2 } }) => (field_0, field_1, field_2),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+    let winner = match winner_code {
+        0 => BaccaratBet::Player,
+        1 => BaccaratBet::Banker,
+        _ => BaccaratBet::Tie,
+    };
+
+    let game = &mut ctx.accounts.baccarat_game;
+    require!(!game.is_complete, CasinoError::GameAlreadyCompleted);
+    let bet_amount = game.bet_amount;
+    let payout = calculate_baccarat_payout(game.bet, winner, bet_amount, game.banker_commission_bps, game.tie_payout);
+    game.player_total = player_total;
+    game.banker_total = banker_total;
+    game.winner = winner;
+    game.payout = payout;
+    game.is_complete = true;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    emit!(BaccaratResultEvent { game_id: ctx.accounts.baccarat_game.game_id, player_total, banker_total, winner, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetBaccaratConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BaccaratConfig::DISCRIMINATOR.len() + BaccaratConfig::INIT_SPACE,
+        seeds = [b"baccarat_config"],
+        bump
+    )]
+    pub baccarat_config: Box<Account<'info, BaccaratConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("play_baccarat", payer)]
+#[derive(Accounts)]
+pub struct InitPlayBaccaratCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("play_baccarat", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct PlayBaccarat<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLAY_BACCARAT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = BaccaratGame::DISCRIMINATOR.len() + BaccaratGame::INIT_SPACE,
+        seeds = [b"baccarat_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub baccarat_game: Box<Account<'info, BaccaratGame>>,
+    #[account(seeds = [b"baccarat_config"], bump = baccarat_config.bump)]
+    pub baccarat_config: Box<Account<'info, BaccaratConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("play_baccarat")]
+#[derive(Accounts)]
+pub struct PlayBaccaratCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLAY_BACCARAT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub baccarat_game: Box<Account<'info, BaccaratGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == baccarat_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", baccarat_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
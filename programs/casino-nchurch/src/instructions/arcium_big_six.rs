@@ -0,0 +1,300 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_big_six_payout, calculate_big_six_rtp_bps, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_SPIN_WHEEL;
+
+pub fn init_spin_wheel_comp_def(ctx: Context<InitSpinWheelCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Lays out the wheel: `segments[i]` is the segment type at position `i` and `payouts[t]` what
+/// type `t` pays, x-to-1. Each type's RTP follows from how many segments it covers and must
+/// be within the casino's RTP bounds; a type paying nothing is not offered.
+pub fn set_big_six_config(
+    ctx: Context<SetBigSixConfig>,
+    segment_count: u8,
+    segments: [u8; 64],
+    payouts: [u16; 8],
+) -> Result<()> {
+    require!(segment_count >= 2 && segment_count as usize <= BIG_SIX_MAX_SEGMENTS, CasinoError::InvalidWheelConfig);
+    for (position, &segment_type) in segments.iter().enumerate() {
+        if position < segment_count as usize {
+            require!((segment_type as usize) < BIG_SIX_SEGMENT_TYPES, CasinoError::InvalidWheelConfig);
+        } else {
+            require!(segment_type == 0, CasinoError::InvalidWheelConfig);
+        }
+    }
+    require!(payouts.iter().all(|&payout| payout <= BIG_SIX_MAX_PAYOUT), CasinoError::InvalidWheelConfig);
+
+    let rtp_bps = calculate_big_six_rtp_bps(segment_count, &segments, &payouts);
+    for &rtp in rtp_bps.iter().filter(|&&rtp| rtp > 0) {
+        require!(rtp <= 10000, CasinoError::InvalidWheelConfig);
+        validate_rtp_config(rtp as u16)?;
+    }
+    require!(rtp_bps.iter().any(|&rtp| rtp > 0), CasinoError::InvalidWheelConfig);
+
+    let config = &mut ctx.accounts.big_six_config;
+    config.authority = ctx.accounts.authority.key();
+    config.segment_count = segment_count;
+    config.segments = segments;
+    config.payouts = payouts;
+    for (segment_type, &rtp) in rtp_bps.iter().enumerate() {
+        config.rtp_bps[segment_type] = rtp as u16;
+    }
+    config.bump = ctx.bumps.big_six_config;
+    Ok(())
+}
+
+/// Stakes `bets[t]` on each segment type `t` for a single spin. The landed position is drawn
+/// uniformly in MPC; every bet is settled against its segment type in the callback.
+pub fn play_big_six(
+    ctx: Context<PlayBigSix>,
+    computation_offset: u64,
+    game_id: u64,
+    bets: [u64; 8],
+    nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    let config = &ctx.accounts.big_six_config;
+    let mut total_bet = 0u64;
+    for (segment_type, &amount) in bets.iter().enumerate() {
+        if amount > 0 {
+            require!(config.rtp_bps[segment_type] > 0, CasinoError::InvalidWheelBet);
+            total_bet += amount;
+        }
+    }
+    require!(total_bet > 0, CasinoError::InvalidBetAmount);
+
+    // Escrow every stake in the vault until the spin is revealed
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, total_bet, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += total_bet;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += total_bet;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let segment_count = ctx.accounts.big_six_config.segment_count;
+    let game = &mut ctx.accounts.big_six_game;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bets = bets;
+    game.total_bet = total_bet;
+    game.segment_count = segment_count;
+    game.segments = ctx.accounts.big_six_config.segments;
+    game.payouts = ctx.accounts.big_six_config.payouts;
+    game.landed_segment = 0;
+    game.landed_type = 0;
+    game.payout = 0;
+    game.is_complete = false;
+    game.bump = ctx.bumps.big_six_game;
+
+    let args = vec![Argument::PlaintextU128(nonce), Argument::PlaintextU8(segment_count)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![SpinWheelCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.big_six_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct BigSixResultEvent { pub game_id: u64, pub landed_segment: u8, pub landed_type: u8, pub bets: [u64; 8], pub payout: u64 }
+
+pub fn spin_wheel_callback(
+    ctx: Context<SpinWheelCallback>,
+    output: ComputationOutputs<SpinWheelOutput>,
+) -> Result<()> {
+    let landed_segment = match output {
+        ComputationOutputs::Success(SpinWheelOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.big_six_game;
+    require!(!game.is_complete, CasinoError::GameAlreadyCompleted);
+    let total_bet = game.total_bet;
+    let landed_type = game.segments[landed_segment as usize];
+    let payout = calculate_big_six_payout(&game.bets, &game.payouts, landed_type);
+    game.landed_segment = landed_segment;
+    game.landed_type = landed_type;
+    game.payout = payout;
+    game.is_complete = true;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > total_bet {
+        ctx.accounts.user_stats.total_wins += payout - total_bet;
+        ctx.accounts.user_stats.loyalty_points += total_bet / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += total_bet - payout;
+    }
+
+    emit!(BigSixResultEvent { game_id: ctx.accounts.big_six_game.game_id, landed_segment, landed_type, bets: ctx.accounts.big_six_game.bets, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetBigSixConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BigSixConfig::DISCRIMINATOR.len() + BigSixConfig::INIT_SPACE,
+        seeds = [b"big_six_config"],
+        bump
+    )]
+    pub big_six_config: Box<Account<'info, BigSixConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("spin_wheel", payer)]
+#[derive(Accounts)]
+pub struct InitSpinWheelCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("spin_wheel", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct PlayBigSix<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SPIN_WHEEL))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = BigSixGame::DISCRIMINATOR.len() + BigSixGame::INIT_SPACE,
+        seeds = [b"big_six_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub big_six_game: Box<Account<'info, BigSixGame>>,
+    #[account(seeds = [b"big_six_config"], bump = big_six_config.bump)]
+    pub big_six_config: Box<Account<'info, BigSixConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("spin_wheel")]
+#[derive(Accounts)]
+pub struct SpinWheelCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SPIN_WHEEL))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub big_six_game: Box<Account<'info, BigSixGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == big_six_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", big_six_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
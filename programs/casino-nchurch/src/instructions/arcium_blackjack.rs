@@ -3,7 +3,9 @@ use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::CallbackAccount;
 use crate::errors::*;
 use crate::state::casino::*;
+use crate::utils::{blackjack_deck_commitment, calculate_blackjack_settlement};
 use anchor_spl::{
+    associated_token::AssociatedToken,
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
@@ -14,6 +16,7 @@ use crate::COMP_DEF_OFFSET_SHUFFLE_AND_DEAL_CARDS;
 use crate::COMP_DEF_OFFSET_PLAYER_HIT;
 use crate::COMP_DEF_OFFSET_PLAYER_DOUBLE_DOWN;
 use crate::COMP_DEF_OFFSET_PLAYER_STAND;
+use crate::COMP_DEF_OFFSET_PLAYER_SURRENDER;
 use crate::COMP_DEF_OFFSET_DEALER_PLAY;
 use crate::COMP_DEF_OFFSET_RESOLVE_GAME;
 
@@ -43,6 +46,11 @@ pub fn init_player_stand_comp_def(ctx: Context<InitPlayerStandCompDef>) -> Resul
     Ok(())
 }
 
+pub fn init_player_surrender_comp_def(ctx: Context<InitPlayerSurrenderCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
 pub fn init_dealer_play_comp_def(ctx: Context<InitDealerPlayCompDef>) -> Result<()> {
     init_comp_def(ctx.accounts,true, 0, None, None)?;
     Ok(())
@@ -65,7 +73,36 @@ pub fn initialize_blackjack_game(
     client_nonce: u128,
     client_again_nonce: u128,
     bet_amount: u64,
+    perfect_pairs_bet: u64,
+    twenty_one_plus_three_bet: u64,
 ) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+
+    // Side bets are optional, capped at the main stake, and only offered while the paytable pays
+    let paytable = ctx.accounts.blackjack_config.side_bet_paytable;
+    require!(perfect_pairs_bet <= bet_amount && twenty_one_plus_three_bet <= bet_amount, CasinoError::InvalidSideBet);
+    require!(perfect_pairs_bet == 0 || paytable.perfect_pairs[3] > 0, CasinoError::InvalidSideBet);
+    require!(twenty_one_plus_three_bet == 0 || paytable.twenty_one_plus_three[5] > 0, CasinoError::InvalidSideBet);
+    let total_stake = bet_amount + perfect_pairs_bet + twenty_one_plus_three_bet;
+
+    // Escrow the main and side stakes in the vault until they are settled
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, total_stake, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += total_stake;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += total_stake;
+    ctx.accounts.user_stats.games_played += 1;
+
     let blackjack_game = &mut ctx.accounts.blackjack_game;
     blackjack_game.bump = ctx.bumps.blackjack_game;
     blackjack_game.game_id = game_id;
@@ -82,6 +119,20 @@ pub fn initialize_blackjack_game(
     blackjack_game.player_has_stood = false;
     blackjack_game.game_result = 0;
     blackjack_game.bet_amount = bet_amount;
+    blackjack_game.shoe = Pubkey::default();
+    blackjack_game.last_action_slot = Clock::get()?.slot;
+    blackjack_game.cranker = Pubkey::default();
+    blackjack_game.crank_fee = 0;
+    blackjack_game.rules = ctx.accounts.blackjack_config.rules;
+    blackjack_game.perfect_pairs_bet = perfect_pairs_bet;
+    blackjack_game.twenty_one_plus_three_bet = twenty_one_plus_three_bet;
+    blackjack_game.perfect_pairs_result = 0;
+    blackjack_game.twenty_one_plus_three_result = 0;
+    blackjack_game.side_bet_paytable = paytable;
+    blackjack_game.deck_commitment = [0; 32];
+    blackjack_game.deck_hash = [0; 32];
+    blackjack_game.deck_revealed = false;
+    open_blackjack_session(&mut ctx.accounts.blackjack_sessions, ctx.accounts.payer.key(), ctx.bumps.blackjack_sessions, game_id)?;
 
     let args = vec![
         Argument::PlaintextU128(mxe_nonce),
@@ -90,6 +141,8 @@ pub fn initialize_blackjack_game(
         Argument::PlaintextU128(client_nonce),
         Argument::ArcisPubkey(client_pubkey),
         Argument::PlaintextU128(client_again_nonce),
+        Argument::PlaintextBool(perfect_pairs_bet > 0),
+        Argument::PlaintextBool(twenty_one_plus_three_bet > 0),
     ];
 
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
@@ -99,10 +152,15 @@ pub fn initialize_blackjack_game(
         computation_offset,
         args,
         None,
-        vec![ShuffleAndDealCardsCallback::callback_ix(&[CallbackAccount {
-            pubkey: ctx.accounts.blackjack_game.key(),
-            is_writable: true,
-        }])],
+        vec![ShuffleAndDealCardsCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
     )?;
     Ok(())
 }
@@ -110,9 +168,11 @@ pub fn initialize_blackjack_game(
 pub fn player_hit(
     ctx: Context<PlayerHit>,
     computation_offset: u64,
+    _game_id: u64,
 ) -> Result<()> {
     require!(ctx.accounts.blackjack_game.game_state == BlackjackGameState::PlayerTurn, CasinoError::InvalidGameState);
     require!(!ctx.accounts.blackjack_game.player_has_stood, CasinoError::InvalidMove);
+    require!(ctx.accounts.blackjack_game.player_hand_size < 11, CasinoError::InvalidMove);
 
     let args = vec![
         // Deck
@@ -127,6 +187,9 @@ pub fn player_hit(
         Argument::PlaintextU8(ctx.accounts.blackjack_game.dealer_hand_size),
     ];
 
+    // Block further actions while the draw is in flight
+    ctx.accounts.blackjack_game.game_state = BlackjackGameState::Hitting;
+    ctx.accounts.blackjack_game.last_action_slot = Clock::get()?.slot;
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
     queue_computation(
@@ -142,10 +205,21 @@ pub fn player_hit(
 pub fn player_double_down(
     ctx: Context<PlayerDoubleDown>,
     computation_offset: u64,
+    _game_id: u64,
 ) -> Result<()> {
     require!(ctx.accounts.blackjack_game.game_state == BlackjackGameState::PlayerTurn, CasinoError::InvalidGameState);
     require!(!ctx.accounts.blackjack_game.player_has_stood, CasinoError::InvalidMove);
 
+    // Escrow the second stake up front; the callback refunds it if the rules refuse the double
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, ctx.accounts.blackjack_game.bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
     let args = vec![
         Argument::PlaintextU128(ctx.accounts.blackjack_game.deck_nonce),
         Argument::Account(ctx.accounts.blackjack_game.key(), 8, 32 * 3),
@@ -154,8 +228,13 @@ pub fn player_double_down(
         Argument::Account(ctx.accounts.blackjack_game.key(), 8 + 32 * 3, 32),
         Argument::PlaintextU8(ctx.accounts.blackjack_game.player_hand_size),
         Argument::PlaintextU8(ctx.accounts.blackjack_game.dealer_hand_size),
+        Argument::PlaintextU8(ctx.accounts.blackjack_game.rules.double_min_total),
+        Argument::PlaintextU8(ctx.accounts.blackjack_game.rules.double_max_total),
     ];
 
+    // Block further actions while the double is in flight
+    ctx.accounts.blackjack_game.game_state = BlackjackGameState::Doubling;
+    ctx.accounts.blackjack_game.last_action_slot = Clock::get()?.slot;
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
     queue_computation(
@@ -163,7 +242,15 @@ pub fn player_double_down(
         computation_offset,
         args,
         None,
-        vec![PlayerDoubleDownCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true }])],
+        vec![PlayerDoubleDownCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
     )?;
     Ok(())
 }
@@ -171,6 +258,7 @@ pub fn player_double_down(
 pub fn player_stand(
     ctx: Context<PlayerStand>,
     computation_offset: u64,
+    _game_id: u64,
 ) -> Result<()> {
     require!(ctx.accounts.blackjack_game.game_state == BlackjackGameState::PlayerTurn, CasinoError::InvalidGameState);
     require!(!ctx.accounts.blackjack_game.player_has_stood, CasinoError::InvalidMove);
@@ -182,6 +270,8 @@ pub fn player_stand(
         Argument::PlaintextU8(ctx.accounts.blackjack_game.player_hand_size),
     ];
 
+    ctx.accounts.blackjack_game.game_state = BlackjackGameState::Standing;
+    ctx.accounts.blackjack_game.last_action_slot = Clock::get()?.slot;
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
     queue_computation(
@@ -194,9 +284,51 @@ pub fn player_stand(
     Ok(())
 }
 
+pub fn player_surrender(
+    ctx: Context<PlayerSurrender>,
+    computation_offset: u64,
+    _game_id: u64,
+) -> Result<()> {
+    // Surrender is only offered as the first decision on the initial two cards
+    require!(ctx.accounts.blackjack_game.game_state == BlackjackGameState::PlayerTurn, CasinoError::InvalidGameState);
+    require!(!ctx.accounts.blackjack_game.player_has_stood, CasinoError::InvalidMove);
+    require!(ctx.accounts.blackjack_game.player_hand_size == 2, CasinoError::InvalidMove);
+
+    let args = vec![
+        Argument::ArcisPubkey(ctx.accounts.blackjack_game.player_enc_pubkey),
+        Argument::PlaintextU128(ctx.accounts.blackjack_game.client_nonce),
+        Argument::Account(ctx.accounts.blackjack_game.key(), 8 + 32 * 3, 32),
+        Argument::PlaintextU128(ctx.accounts.blackjack_game.dealer_nonce),
+        Argument::Account(ctx.accounts.blackjack_game.key(), 8 + 32 * 3 + 32, 32),
+    ];
+
+    // Block further actions, resolve_game included, while the surrender is in flight
+    ctx.accounts.blackjack_game.game_state = BlackjackGameState::Surrendering;
+    ctx.accounts.blackjack_game.last_action_slot = Clock::get()?.slot;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![PlayerSurrenderCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
 pub fn dealer_play(
     ctx: Context<DealerPlay>,
     computation_offset: u64,
+    _game_id: u64,
     client_nonce: u128,
 ) -> Result<()> {
     require!(ctx.accounts.blackjack_game.game_state == BlackjackGameState::DealerTurn, CasinoError::InvalidGameState);
@@ -210,6 +342,7 @@ pub fn dealer_play(
         Argument::PlaintextU128(client_nonce),
         Argument::PlaintextU8(ctx.accounts.blackjack_game.player_hand_size),
         Argument::PlaintextU8(ctx.accounts.blackjack_game.dealer_hand_size),
+        Argument::PlaintextBool(ctx.accounts.blackjack_game.rules.dealer_hits_soft_17),
     ];
 
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
@@ -227,6 +360,7 @@ pub fn dealer_play(
 pub fn resolve_game(
     ctx: Context<ResolveGame>,
     computation_offset: u64,
+    _game_id: u64,
 ) -> Result<()> {
     require!(ctx.accounts.blackjack_game.game_state == BlackjackGameState::Resolving, CasinoError::InvalidGameState);
 
@@ -240,6 +374,9 @@ pub fn resolve_game(
         Argument::PlaintextU8(ctx.accounts.blackjack_game.dealer_hand_size),
     ];
 
+    // Only one settlement may be in flight, so the hand can't be paid twice
+    ctx.accounts.blackjack_game.game_state = BlackjackGameState::Settling;
+    ctx.accounts.blackjack_game.last_action_slot = Clock::get()?.slot;
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
     queue_computation(
@@ -247,11 +384,48 @@ pub fn resolve_game(
         computation_offset,
         args,
         None,
-        vec![ResolveGameCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true }])],
+        vec![ResolveGameCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
     )?;
     Ok(())
 }
 
+/// Records a new game in the player's session index.
+pub fn open_blackjack_session(sessions: &mut BlackjackSessions, player: Pubkey, bump: u8, game_id: u64) -> Result<()> {
+    require!(sessions.open_games.len() < BLACKJACK_MAX_OPEN_GAMES, CasinoError::TooManyOpenGames);
+    sessions.player = player;
+    sessions.bump = bump;
+    sessions.open_games.push(game_id);
+    sessions.games_opened += 1;
+    Ok(())
+}
+
+pub fn close_blackjack_game(ctx: Context<CloseBlackjackGame>, game_id: u64) -> Result<()> {
+    require!(ctx.accounts.blackjack_game.game_state == BlackjackGameState::Resolved, CasinoError::InvalidGameState);
+    // Shoe hands must be released first so the shoe can advance past their cards
+    require!(ctx.accounts.blackjack_game.shoe == Pubkey::default(), CasinoError::ShoeInUse);
+    // Closing deletes the encrypted deck, so a dealt single deck stays open for audit until it
+    // has been revealed or the reveal window has passed
+    let game = &ctx.accounts.blackjack_game;
+    require!(
+        game.deck_revealed
+            || game.deck_commitment == [0; 32]
+            || Clock::get()?.slot >= game.last_action_slot.saturating_add(BLACKJACK_REVEAL_WINDOW_SLOTS),
+        CasinoError::DeckRevealPending
+    );
+
+    ctx.accounts.blackjack_sessions.open_games.retain(|&id| id != game_id);
+    emit!(BlackjackGameClosedEvent { game_id, player: ctx.accounts.player.key() });
+    Ok(())
+}
+
 // --- Callbacks ---
 
 #[event]
@@ -260,6 +434,7 @@ pub struct CardsShuffledAndDealtEvent {
     pub dealer_face_up_card: [u8; 32],
     pub client_nonce: u128,
     pub dealer_client_nonce: u128,
+    pub deck_commitment: [u8; 32],
     pub game_id: u64,
 }
 
@@ -268,21 +443,35 @@ pub struct PlayerHitEvent { pub player_hand: [u8; 32], pub client_nonce: u128, p
 #[event]
 pub struct PlayerDoubleDownEvent { pub player_hand: [u8; 32], pub client_nonce: u128, pub game_id: u64 }
 #[event]
+pub struct PlayerDoubleDownRejectedEvent { pub game_id: u64, pub rules: BlackjackRules }
+#[event]
 pub struct PlayerStandEvent { pub is_bust: bool, pub game_id: u64 }
 #[event]
 pub struct PlayerBustEvent { pub client_nonce: u128, pub game_id: u64 }
 #[event]
+pub struct PlayerSurrenderEvent { pub result_code: u8, pub payout: u64, pub game_id: u64, pub rules: BlackjackRules }
+#[event]
 pub struct DealerPlayEvent { pub dealer_hand: [u8; 32], pub dealer_hand_size: u8, pub client_nonce: u128, pub game_id: u64 }
 #[event]
-pub struct BlackjackResultEvent { pub result_code: u8, pub game_id: u64 }
+pub struct BlackjackGameClosedEvent { pub game_id: u64, pub player: Pubkey }
+#[event]
+pub struct BlackjackSideBetsSettledEvent {
+    pub game_id: u64,
+    pub perfect_pairs_result: u8,
+    pub perfect_pairs_payout: u64,
+    pub twenty_one_plus_three_result: u8,
+    pub twenty_one_plus_three_payout: u64,
+}
+#[event]
+pub struct BlackjackResultEvent { pub result_code: u8, pub payout: u64, pub game_id: u64, pub rules: BlackjackRules }
 
 
 pub fn shuffle_and_deal_cards_callback(
     ctx: Context<ShuffleAndDealCardsCallback>,
     output: ComputationOutputs<ShuffleAndDealCardsOutput>,
 ) -> Result<()> {
-    let (deck, dealer_hand, player_hand, dealer_face_up_card) = match output {
-        ComputationOutputs::Success(ShuffleAndDealCardsOutput { field_0: ShuffleAndDealCardsOutputStruct0 { field_0: deck, field_1: dealer_hand, field_2: player_hand, field_3: dealer_face_up_card } }) => (deck, dealer_hand, player_hand, dealer_face_up_card),
+    let (deck, dealer_hand, player_hand, dealer_face_up_card, perfect_pairs_result, twenty_one_plus_three_result) = match output {
+        ComputationOutputs::Success(ShuffleAndDealCardsOutput { field_0: ShuffleAndDealCardsOutputStruct0 { field_0: deck, field_1: dealer_hand, field_2: player_hand, field_3: dealer_face_up_card, field_4: perfect_pairs_result, field_5: twenty_one_plus_three_result } }) => (deck, dealer_hand, player_hand, dealer_face_up_card, perfect_pairs_result, twenty_one_plus_three_result),
         _ => return Err(ErrorCode::AbortedComputation.into()),
     };
 
@@ -301,8 +490,10 @@ pub fn shuffle_and_deal_cards_callback(
     let dealer_face_up_ct = dealer_face_up_card.ciphertexts[0];
 
     let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Initial, CasinoError::InvalidGameState);
     game.deck = deck_chunks;
     game.deck_nonce = deck_nonce;
+    game.deck_commitment = blackjack_deck_commitment(&deck_chunks, deck_nonce);
     game.client_nonce = client_nonce;
     game.dealer_nonce = dealer_nonce;
     game.player_enc_pubkey = client_pubkey;
@@ -311,13 +502,62 @@ pub fn shuffle_and_deal_cards_callback(
     game.dealer_hand = dealer_hand_ct;
     game.player_hand_size = 2;
     game.dealer_hand_size = 2;
+    game.last_action_slot = Clock::get()?.slot;
+
+    game.perfect_pairs_result = perfect_pairs_result;
+    game.twenty_one_plus_three_result = twenty_one_plus_three_result;
 
     require!(dealer_client_pubkey == game.player_enc_pubkey, CasinoError::InvalidDealerClientPubkey);
 
-    emit!(CardsShuffledAndDealtEvent { client_nonce, dealer_client_nonce, player_hand: player_hand_ct, dealer_face_up_card: dealer_face_up_ct, game_id: game.game_id });
+    emit!(CardsShuffledAndDealtEvent { client_nonce, dealer_client_nonce, player_hand: player_hand_ct, dealer_face_up_card: dealer_face_up_ct, deck_commitment: game.deck_commitment, game_id: game.game_id });
+
+    // Side bets settle now, independently of how the main hand plays out
+    let game = &ctx.accounts.blackjack_game;
+    if game.perfect_pairs_bet == 0 && game.twenty_one_plus_three_bet == 0 {
+        return Ok(());
+    }
+    let paytable = game.side_bet_paytable;
+    let perfect_pairs_payout = side_bet_payout(game.perfect_pairs_bet, paytable.perfect_pairs.get(perfect_pairs_result as usize));
+    let twenty_one_plus_three_payout = side_bet_payout(game.twenty_one_plus_three_bet, paytable.twenty_one_plus_three.get(twenty_one_plus_three_result as usize));
+    let payout = perfect_pairs_payout + twenty_one_plus_three_payout;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+        ctx.accounts.user_stats.total_wins += payout;
+    }
+    let side_stake = game.perfect_pairs_bet + game.twenty_one_plus_three_bet;
+    ctx.accounts.user_stats.loyalty_points += side_stake / 100;
+    if payout < side_stake {
+        ctx.accounts.user_stats.total_losses += side_stake - payout;
+    }
+
+    emit!(BlackjackSideBetsSettledEvent {
+        game_id: game.game_id,
+        perfect_pairs_result,
+        perfect_pairs_payout,
+        twenty_one_plus_three_result,
+        twenty_one_plus_three_payout,
+    });
     Ok(())
 }
 
+/// Stake returned plus winnings for a side bet; zero for a losing or unknown code.
+fn side_bet_payout(stake: u64, multiplier: Option<&u16>) -> u64 {
+    match multiplier {
+        Some(&m) if m > 0 => stake + stake * m as u64,
+        _ => 0,
+    }
+}
+
 pub fn player_hit_callback(
     ctx: Context<PlayerHitCallback>,
     output: ComputationOutputs<PlayerHitOutput>,
@@ -330,9 +570,13 @@ pub fn player_hit_callback(
     let player_hand_ct = player_hand.ciphertexts[0];
 
     let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Hitting, CasinoError::InvalidGameState);
+    game.last_action_slot = Clock::get()?.slot;
     game.player_hand = player_hand_ct;
     game.client_nonce = client_nonce;
-    if is_bust { game.game_state = BlackjackGameState::DealerTurn; emit!(PlayerBustEvent { client_nonce, game_id: game.game_id }); } else { game.game_state = BlackjackGameState::PlayerTurn; emit!(PlayerHitEvent { player_hand: player_hand_ct, client_nonce, game_id: game.game_id }); game.player_hand_size += 1; }
+    // The drawn card is always kept; a bust hands the turn straight to the dealer
+    game.player_hand_size += 1;
+    if is_bust { game.game_state = BlackjackGameState::DealerTurn; emit!(PlayerBustEvent { client_nonce, game_id: game.game_id }); } else { game.game_state = BlackjackGameState::PlayerTurn; emit!(PlayerHitEvent { player_hand: player_hand_ct, client_nonce, game_id: game.game_id }); }
     Ok(())
 }
 
@@ -340,18 +584,44 @@ pub fn player_double_down_callback(
     ctx: Context<PlayerDoubleDownCallback>,
     output: ComputationOutputs<PlayerDoubleDownOutput>,
 ) -> Result<()> {
-    let (player_hand, is_bust) = match output {
-        ComputationOutputs::Success(PlayerDoubleDownOutput { field_0: PlayerDoubleDownOutputStruct0 { field_0: player_hand, field_1: is_bust } }) => (player_hand, is_bust),
+    let (player_hand, is_bust, allowed) = match output {
+        ComputationOutputs::Success(PlayerDoubleDownOutput { field_0: PlayerDoubleDownOutputStruct0 { field_0: player_hand, field_1: is_bust, field_2: allowed } }) => (player_hand, is_bust, allowed),
         _ => return Err(ErrorCode::AbortedComputation.into()),
     };
     let client_nonce = player_hand.nonce;
     let player_hand_ct = player_hand.ciphertexts[0];
 
     let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Doubling, CasinoError::InvalidGameState);
+    game.last_action_slot = Clock::get()?.slot;
+    let stake = game.bet_amount;
+    // The house rules don't allow doubling on this total; the second stake is refunded and
+    // the hand stays in play unchanged
+    if !allowed {
+        game.game_state = BlackjackGameState::PlayerTurn;
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, stake, ctx.accounts.usdc_mint.decimals)?;
+        emit!(PlayerDoubleDownRejectedEvent { game_id: ctx.accounts.blackjack_game.game_id, rules: ctx.accounts.blackjack_game.rules });
+        return Ok(());
+    }
+    // The hand now settles on twice the original stake
+    game.bet_amount = stake * 2;
     game.player_hand = player_hand_ct;
     game.client_nonce = client_nonce;
     game.player_has_stood = true;
-    if is_bust { game.game_state = BlackjackGameState::DealerTurn; emit!(PlayerBustEvent { client_nonce, game_id: game.game_id }); } else { game.game_state = BlackjackGameState::DealerTurn; emit!(PlayerDoubleDownEvent { player_hand: player_hand_ct, client_nonce, game_id: game.game_id }); }
+    game.player_hand_size += 1;
+    game.game_state = BlackjackGameState::DealerTurn;
+    if is_bust { emit!(PlayerBustEvent { client_nonce, game_id: game.game_id }); } else { emit!(PlayerDoubleDownEvent { player_hand: player_hand_ct, client_nonce, game_id: game.game_id }); }
+
+    ctx.accounts.casino_state.total_volume += stake;
+    ctx.accounts.user_stats.total_bets += stake;
     Ok(())
 }
 
@@ -361,11 +631,51 @@ pub fn player_stand_callback(
 ) -> Result<()> {
     let is_bust = match output { ComputationOutputs::Success(PlayerStandOutput { field_0 }) => field_0, _ => return Err(ErrorCode::AbortedComputation.into()) };
     let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Standing, CasinoError::InvalidGameState);
+    game.last_action_slot = Clock::get()?.slot;
     game.player_has_stood = true;
     if is_bust { game.game_state = BlackjackGameState::PlayerTurn; emit!(PlayerBustEvent { client_nonce: game.client_nonce, game_id: game.game_id }); } else { game.game_state = BlackjackGameState::DealerTurn; emit!(PlayerStandEvent { is_bust, game_id: game.game_id }); }
     Ok(())
 }
 
+pub fn player_surrender_callback(
+    ctx: Context<PlayerSurrenderCallback>,
+    output: ComputationOutputs<PlayerSurrenderOutput>,
+) -> Result<()> {
+    let result = match output { ComputationOutputs::Success(PlayerSurrenderOutput { field_0 }) => field_0, _ => return Err(ErrorCode::AbortedComputation.into()) };
+    let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Surrendering, CasinoError::InvalidGameState);
+    game.game_state = BlackjackGameState::Resolved;
+    game.game_result = result;
+    game.player_has_stood = true;
+
+    // A dealer natural voids the surrender and settles the hand as usual
+    let bet_amount = game.bet_amount;
+    let final_payout = calculate_blackjack_settlement(result, bet_amount, ctx.accounts.casino_state.house_edge_config.blackjack_rtp_bps)
+        .saturating_sub(game.crank_fee);
+
+    if final_payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, final_payout, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    // stats
+    ctx.accounts.casino_state.total_payouts += final_payout;
+    if final_payout < bet_amount {
+        ctx.accounts.user_stats.total_losses += bet_amount - final_payout;
+    }
+
+    emit!(PlayerSurrenderEvent { result_code: result, payout: final_payout, game_id: ctx.accounts.blackjack_game.game_id, rules: ctx.accounts.blackjack_game.rules });
+    Ok(())
+}
+
 pub fn dealer_play_callback(
     ctx: Context<DealerPlayCallback>,
     output: ComputationOutputs<DealerPlayOutput>,
@@ -381,6 +691,8 @@ pub fn dealer_play_callback(
     let client_nonce = dealer_client_hand.nonce;
 
     let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::DealerTurn, CasinoError::InvalidGameState);
+    game.last_action_slot = Clock::get()?.slot;
     game.dealer_hand = dealer_hand_ct;
     game.dealer_nonce = dealer_nonce;
     game.dealer_hand_size = dealer_hand_size;
@@ -395,22 +707,15 @@ pub fn resolve_game_callback(
 ) -> Result<()> {
     let result = match output { ComputationOutputs::Success(ResolveGameOutput { field_0 }) => field_0, _ => return Err(ErrorCode::AbortedComputation.into()) };
     let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Settling, CasinoError::InvalidGameState);
     game.game_state = BlackjackGameState::Resolved;
     game.game_result = result;
 
-    // Base payout
+    // Stake is escrowed at deal time, so settlement returns it alongside any winnings
     let bet_amount = game.bet_amount;
-    let base_payout = match result {
-        0 => 0u64,
-        1 => bet_amount,
-        2 => bet_amount,
-        3 => 0u64,
-        _ => bet_amount,
-    };
-
-    // Apply RTP
-    let rtp_multiplier = ctx.accounts.casino_state.house_edge_config.blackjack_rtp_bps as f64 / 10000.0;
-    let final_payout = (base_payout as f64 * rtp_multiplier) as u64;
+    // A cranked hand has already paid the cranker out of its escrow
+    let final_payout = calculate_blackjack_settlement(result, bet_amount, ctx.accounts.casino_state.house_edge_config.blackjack_rtp_bps)
+        .saturating_sub(game.crank_fee);
 
     if final_payout > 0 {
         let ix = TransferChecked {
@@ -424,7 +729,16 @@ pub fn resolve_game_callback(
         transfer_checked(cpi_ctx, final_payout, ctx.accounts.usdc_mint.decimals)?;
     }
 
-    emit!(BlackjackResultEvent { result_code: result, game_id: game.game_id });
+    // stats
+    ctx.accounts.casino_state.total_payouts += final_payout;
+    if final_payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += final_payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else if final_payout < bet_amount {
+        ctx.accounts.user_stats.total_losses += bet_amount - final_payout;
+    }
+
+    emit!(BlackjackResultEvent { result_code: result, payout: final_payout, game_id: ctx.accounts.blackjack_game.game_id, rules: ctx.accounts.blackjack_game.rules });
     Ok(())
 }
 
@@ -471,10 +785,43 @@ pub struct InitializeBlackjackGame<'info> {
         init,
         payer = payer,
         space = 8 + BlackjackGame::INIT_SPACE,
-        seeds = [b"blackjack_game".as_ref(), game_id.to_le_bytes().as_ref()],
+        seeds = [b"blackjack_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub blackjack_game: Account<'info, BlackjackGame>,
+
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BlackjackSessions::DISCRIMINATOR.len() + BlackjackSessions::INIT_SPACE,
+        seeds = [b"blackjack_sessions", payer.key().as_ref()],
+        bump
+    )]
+    pub blackjack_sessions: Box<Account<'info, BlackjackSessions>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 #[callback_accounts("shuffle_and_deal_cards")]
@@ -488,6 +835,21 @@ pub struct ShuffleAndDealCardsCallback<'info> {
     pub instructions_sysvar: AccountInfo<'info>,
     #[account(mut)]
     pub blackjack_game: Account<'info, BlackjackGame>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == blackjack_game.player_pubkey @ CasinoError::InvalidMove)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", blackjack_game.player_pubkey.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
 }
 
 #[init_computation_definition_accounts("shuffle_and_deal_cards", payer)]
@@ -508,7 +870,7 @@ pub struct InitShuffleAndDealCardsCompDef<'info> {
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, _game_id: u64)]
 pub struct PlayerHit<'info> {
-    #[account(mut)]
+    #[account(mut, address = blackjack_game.player_pubkey @ CasinoError::InvalidMove)]
     pub payer: Signer<'info>,
     #[account(
         init_if_needed,
@@ -542,7 +904,7 @@ pub struct PlayerHit<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
         mut,
-        seeds = [b"blackjack_game".as_ref(), _game_id.to_le_bytes().as_ref()],
+        seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()],
         bump = blackjack_game.bump,
     )]
     pub blackjack_game: Account<'info, BlackjackGame>,
@@ -579,7 +941,7 @@ pub struct InitPlayerHitCompDef<'info> {
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, _game_id: u64)]
 pub struct PlayerDoubleDown<'info> {
-    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = blackjack_game.player_pubkey @ CasinoError::InvalidMove)] pub payer: Signer<'info>,
     #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
     pub sign_pda_account: Account<'info, SignerAccount>,
     #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
@@ -597,8 +959,24 @@ pub struct PlayerDoubleDown<'info> {
     #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(mut, seeds = [b"blackjack_game".as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
     pub blackjack_game: Account<'info, BlackjackGame>,
+
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == blackjack_game.player_pubkey @ CasinoError::InvalidMove)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", blackjack_game.player_pubkey.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[callback_accounts("player_double_down")]
@@ -610,6 +988,16 @@ pub struct PlayerDoubleDownCallback<'info> {
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
     pub instructions_sysvar: AccountInfo<'info>,
     #[account(mut)] pub blackjack_game: Account<'info, BlackjackGame>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Account<'info, CasinoState>,
+    #[account(mut)]
+    pub casino_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub user_stats: Account<'info, UserStats>,
 }
 
 #[init_computation_definition_accounts("player_double_down", payer)]
@@ -627,7 +1015,7 @@ pub struct InitPlayerDoubleDownCompDef<'info> {
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, _game_id: u64)]
 pub struct PlayerStand<'info> {
-    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = blackjack_game.player_pubkey @ CasinoError::InvalidMove)] pub payer: Signer<'info>,
     #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
     pub sign_pda_account: Account<'info, SignerAccount>,
     #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
@@ -645,7 +1033,7 @@ pub struct PlayerStand<'info> {
     #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(mut, seeds = [b"blackjack_game".as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
     pub blackjack_game: Account<'info, BlackjackGame>,
 }
 
@@ -671,6 +1059,81 @@ pub struct InitPlayerStandCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[queue_computation_accounts("player_surrender", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct PlayerSurrender<'info> {
+    #[account(mut, address = blackjack_game.player_pubkey @ CasinoError::InvalidMove)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLAYER_SURRENDER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    pub blackjack_game: Account<'info, BlackjackGame>,
+
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == blackjack_game.player_pubkey @ CasinoError::InvalidMove)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", blackjack_game.player_pubkey.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("player_surrender")]
+#[derive(Accounts)]
+pub struct PlayerSurrenderCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLAYER_SURRENDER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_game: Account<'info, BlackjackGame>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Account<'info, CasinoState>,
+    #[account(mut)]
+    pub casino_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub user_stats: Account<'info, UserStats>,
+}
+
+#[init_computation_definition_accounts("player_surrender", payer)]
+#[derive(Accounts)]
+pub struct InitPlayerSurrenderCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
 #[queue_computation_accounts("dealer_play", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, _game_id: u64)]
@@ -693,7 +1156,7 @@ pub struct DealerPlay<'info> {
     #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(mut, seeds = [b"blackjack_game".as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
     pub blackjack_game: Account<'info, BlackjackGame>,
 }
 
@@ -757,8 +1220,24 @@ pub struct ResolveGame<'info> {
     #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(mut, seeds = [b"blackjack_game".as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
     pub blackjack_game: Account<'info, BlackjackGame>,
+
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == blackjack_game.player_pubkey @ CasinoError::InvalidMove)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", blackjack_game.player_pubkey.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[callback_accounts("resolve_game")]
@@ -782,6 +1261,24 @@ pub struct ResolveGameCallback<'info> {
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
     pub usdc_mint: InterfaceAccount<'info, Mint>,
     pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub user_stats: Account<'info, UserStats>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CloseBlackjackGame<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"blackjack_game".as_ref(), player.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump = blackjack_game.bump,
+    )]
+    pub blackjack_game: Box<Account<'info, BlackjackGame>>,
+    #[account(mut, seeds = [b"blackjack_sessions", player.key().as_ref()], bump = blackjack_sessions.bump)]
+    pub blackjack_sessions: Box<Account<'info, BlackjackSessions>>,
 }
 
 #[error_code]
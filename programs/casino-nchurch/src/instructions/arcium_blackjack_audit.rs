@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use crate::errors::*;
+use crate::state::casino::*;
+use crate::utils::{blackjack_deck_commitment, blackjack_deck_hash, unpack_blackjack_deck, verify_blackjack_deck};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_REVEAL_DECK;
+
+pub fn init_reveal_deck_comp_def(ctx: Context<InitRevealDeckCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Reveals the deck of a resolved single-deck hand for audit. Permissionless: the hand is over,
+/// so nothing is left to protect.
+pub fn reveal_blackjack_deck(
+    ctx: Context<RevealBlackjackDeck>,
+    computation_offset: u64,
+    _game_id: u64,
+) -> Result<()> {
+    let game = &ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Resolved, CasinoError::InvalidGameState);
+    require!(!game.deck_revealed, CasinoError::InvalidGameState);
+    // Only the single-deck deal commits to its deck; shoe hands keep their cards in the shoe
+    // account, which stays encrypted for later hands
+    require!(game.deck_commitment != [0; 32], CasinoError::InvalidGameState);
+    // The reveal must decrypt the deck that was dealt, not one written over it since
+    require!(
+        blackjack_deck_commitment(&game.deck, game.deck_nonce) == game.deck_commitment,
+        CasinoError::InvalidDeckReveal
+    );
+
+    let args = vec![
+        Argument::PlaintextU128(game.deck_nonce),
+        Argument::Account(game.key(), 8, 32 * 3),
+        Argument::PlaintextU128(game.dealer_nonce),
+        Argument::Account(game.key(), 8 + 32 * 3 + 32, 32),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RevealDeckCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+pub fn reveal_deck_callback(
+    ctx: Context<RevealDeckCallback>,
+    output: ComputationOutputs<RevealDeckOutput>,
+) -> Result<()> {
+    let (card_one, card_two, card_three, dealer_hole_card) = match output {
+        ComputationOutputs::Success(RevealDeckOutput { field_0: RevealDeckOutputStruct0 { field_0, field_1, field_2, field_3 } }) => (field_0, field_1, field_2, field_3),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let deck = unpack_blackjack_deck([card_one, card_two, card_three]);
+    verify_blackjack_deck(&deck, dealer_hole_card)?;
+    let deck_hash = blackjack_deck_hash(&deck);
+
+    let game = &mut ctx.accounts.blackjack_game;
+    game.deck_hash = deck_hash;
+    game.deck_revealed = true;
+
+    emit!(BlackjackDeckRevealedEvent { game_id: game.game_id, deck, dealer_hole_card, deck_hash });
+    Ok(())
+}
+
+#[event]
+pub struct BlackjackDeckRevealedEvent { pub game_id: u64, pub deck: [u8; 52], pub dealer_hole_card: u8, pub deck_hash: [u8; 32] }
+
+// --- Accounts ---
+
+#[init_computation_definition_accounts("reveal_deck", payer)]
+#[derive(Accounts)]
+pub struct InitRevealDeckCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_deck", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct RevealBlackjackDeck<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_DECK))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    pub blackjack_game: Box<Account<'info, BlackjackGame>>,
+}
+
+#[callback_accounts("reveal_deck")]
+#[derive(Accounts)]
+pub struct RevealDeckCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_DECK))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_game: Account<'info, BlackjackGame>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,270 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use crate::errors::*;
+use crate::state::casino::*;
+use crate::instructions::arcium_blackjack::PlayerStandCallback;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_PLAYER_STAND;
+
+pub fn set_blackjack_config(
+    ctx: Context<SetBlackjackConfig>,
+    timeout_slots: u64,
+    crank_fee_bps: u16,
+    rules: BlackjackRules,
+) -> Result<()> {
+    require!(timeout_slots >= BLACKJACK_MIN_TIMEOUT_SLOTS, CasinoError::InvalidBlackjackConfig);
+    require!(crank_fee_bps <= BLACKJACK_MAX_CRANK_FEE_BPS, CasinoError::InvalidBlackjackConfig);
+    require!(rules.double_min_total <= rules.double_max_total, CasinoError::InvalidBlackjackConfig);
+    require!(rules.double_max_total <= BLACKJACK_VALUE, CasinoError::InvalidBlackjackConfig);
+
+    let config = &mut ctx.accounts.blackjack_config;
+    config.authority = ctx.accounts.authority.key();
+    config.timeout_slots = timeout_slots;
+    config.crank_fee_bps = crank_fee_bps;
+    config.rules = rules;
+    config.bump = ctx.bumps.blackjack_config;
+    Ok(())
+}
+
+pub fn set_blackjack_side_bet_paytable(
+    ctx: Context<SetBlackjackSideBetPaytable>,
+    paytable: BlackjackSideBetPaytable,
+) -> Result<()> {
+    // Code 0 is the losing outcome on both bets
+    require!(paytable.perfect_pairs[0] == 0, CasinoError::InvalidBlackjackConfig);
+    require!(paytable.twenty_one_plus_three[0] == 0, CasinoError::InvalidBlackjackConfig);
+    require!(
+        paytable.perfect_pairs.iter().chain(paytable.twenty_one_plus_three.iter()).all(|&m| m <= BLACKJACK_MAX_SIDE_BET_MULTIPLIER),
+        CasinoError::InvalidBlackjackConfig
+    );
+
+    ctx.accounts.blackjack_config.side_bet_paytable = paytable;
+    Ok(())
+}
+
+/// Stands an abandoned hand on the player's behalf once it has been idle past the timeout.
+/// The hand moves to `Standing`, so the player can't act on it again and it can't be cranked twice.
+/// `dealer_play` and `resolve_game` are already permissionless, so the cranker can finish the hand.
+pub fn crank_blackjack_stand(
+    ctx: Context<CrankBlackjackStand>,
+    computation_offset: u64,
+    _game_id: u64,
+) -> Result<()> {
+    let game = &ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::PlayerTurn, CasinoError::InvalidGameState);
+    require!(!game.player_has_stood, CasinoError::InvalidMove);
+    let now = Clock::get()?.slot;
+    require!(
+        now >= game.last_action_slot.saturating_add(ctx.accounts.blackjack_config.timeout_slots),
+        CasinoError::GameNotTimedOut
+    );
+
+    // The fee comes out of the escrowed stake; every settlement path deducts it from the payout
+    let crank_fee = game.bet_amount * ctx.accounts.blackjack_config.crank_fee_bps as u64 / 10000;
+    if crank_fee > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.cranker_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, crank_fee, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += crank_fee;
+    }
+
+    let args = vec![
+        Argument::ArcisPubkey(ctx.accounts.blackjack_game.player_enc_pubkey),
+        Argument::PlaintextU128(ctx.accounts.blackjack_game.client_nonce),
+        Argument::Account(ctx.accounts.blackjack_game.key(), 8 + 32 * 3, 32),
+        Argument::PlaintextU8(ctx.accounts.blackjack_game.player_hand_size),
+    ];
+
+    let game = &mut ctx.accounts.blackjack_game;
+    game.game_state = BlackjackGameState::Standing;
+    game.cranker = ctx.accounts.payer.key();
+    game.crank_fee = game.crank_fee.checked_add(crank_fee).ok_or(CasinoError::InvalidPayoutCalculation)?;
+    game.last_action_slot = now;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![PlayerStandCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true }])],
+    )?;
+
+    emit!(BlackjackCrankedEvent { game_id: ctx.accounts.blackjack_game.game_id, cranker: ctx.accounts.payer.key(), crank_fee });
+    Ok(())
+}
+
+/// Unsticks a hand whose in-flight computation aborted or never called back. Once the hand has
+/// been idle past the timeout anyone may return it to the state the action was queued from, so
+/// the action can be retried. A pending double refunds its second stake; a deal that never
+/// landed refunds every stake and resolves the hand so it can be closed.
+pub fn recover_blackjack_game(ctx: Context<RecoverBlackjackGame>, _game_id: u64) -> Result<()> {
+    let game = &ctx.accounts.blackjack_game;
+    let now = Clock::get()?.slot;
+    require!(
+        now >= game.last_action_slot.saturating_add(ctx.accounts.blackjack_config.timeout_slots),
+        CasinoError::GameNotTimedOut
+    );
+
+    let from_state = game.game_state;
+    let (next_state, refund) = match from_state {
+        BlackjackGameState::Initial => (
+            BlackjackGameState::Resolved,
+            game.bet_amount + game.perfect_pairs_bet + game.twenty_one_plus_three_bet,
+        ),
+        BlackjackGameState::Hitting | BlackjackGameState::Standing | BlackjackGameState::Surrendering => {
+            (BlackjackGameState::PlayerTurn, 0)
+        }
+        BlackjackGameState::Doubling => (BlackjackGameState::PlayerTurn, game.bet_amount),
+        BlackjackGameState::Settling => (BlackjackGameState::Resolving, 0),
+        _ => return Err(CasinoError::InvalidGameState.into()),
+    };
+
+    if refund > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, refund, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    let game = &mut ctx.accounts.blackjack_game;
+    game.game_state = next_state;
+    game.last_action_slot = now;
+    if next_state == BlackjackGameState::Resolved {
+        game.game_result = BLACKJACK_RESULT_PUSH;
+    }
+
+    emit!(BlackjackGameRecoveredEvent { game_id: game.game_id, from_state, refund });
+    Ok(())
+}
+
+#[event]
+pub struct BlackjackCrankedEvent { pub game_id: u64, pub cranker: Pubkey, pub crank_fee: u64 }
+#[event]
+pub struct BlackjackGameRecoveredEvent { pub game_id: u64, pub from_state: BlackjackGameState, pub refund: u64 }
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetBlackjackConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BlackjackConfig::DISCRIMINATOR.len() + BlackjackConfig::INIT_SPACE,
+        seeds = [b"blackjack_config"],
+        bump
+    )]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetBlackjackSideBetPaytable<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"blackjack_config"],
+        bump = blackjack_config.bump,
+        constraint = blackjack_config.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+}
+
+#[queue_computation_accounts("player_stand", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct CrankBlackjackStand<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLAYER_STAND))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    pub blackjack_game: Box<Account<'info, BlackjackGame>>,
+
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = cranker_token_account.owner == payer.key() @ CasinoError::InvalidMove)]
+    pub cranker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(_game_id: u64)]
+pub struct RecoverBlackjackGame<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    pub blackjack_game: Box<Account<'info, BlackjackGame>>,
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+    #[account(seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == blackjack_game.player_pubkey @ CasinoError::InvalidMove)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
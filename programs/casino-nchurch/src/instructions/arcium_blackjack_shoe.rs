@@ -0,0 +1,508 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use crate::errors::*;
+use crate::state::casino::*;
+use crate::instructions::arcium_blackjack::{open_blackjack_session, CardsShuffledAndDealtEvent};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_SHUFFLE_SHOE;
+use crate::COMP_DEF_OFFSET_DEAL_FROM_SHOE;
+
+// --- Init computation definitions ---
+
+pub fn init_shuffle_shoe_comp_def(ctx: Context<InitShuffleShoeCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_deal_from_shoe_comp_def(ctx: Context<InitDealFromShoeCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+// --- Queue entrypoints ---
+
+pub fn create_blackjack_shoe(
+    ctx: Context<CreateBlackjackShoe>,
+    computation_offset: u64,
+    shoe_id: u64,
+    cut_card_position: u16,
+    mxe_nonce: u128,
+) -> Result<()> {
+    // Leave room for at least one full deck and for the largest possible hand past the cut card
+    require!(
+        cut_card_position >= 52 && cut_card_position <= BLACKJACK_SHOE_SIZE - BLACKJACK_SHOE_RESERVE_CARDS,
+        CasinoError::InvalidCutCardPosition
+    );
+
+    let shoe = &mut ctx.accounts.blackjack_shoe;
+    shoe.shoe_id = shoe_id;
+    shoe.authority = ctx.accounts.payer.key();
+    shoe.num_decks = BLACKJACK_SHOE_DECKS;
+    shoe.cut_card_position = cut_card_position;
+    shoe.position = 0;
+    // Unusable until the first shuffle lands
+    shoe.needs_shuffle = true;
+    shoe.in_use = false;
+    shoe.current_game = Pubkey::default();
+    shoe.hands_dealt = 0;
+    shoe.shuffle_count = 0;
+    shoe.bump = ctx.bumps.blackjack_shoe;
+
+    let args = vec![Argument::PlaintextU128(mxe_nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ShuffleShoeCallback::callback_ix(&[CallbackAccount {
+            pubkey: ctx.accounts.blackjack_shoe.key(),
+            is_writable: true,
+        }])],
+    )?;
+    Ok(())
+}
+
+pub fn reshuffle_blackjack_shoe(
+    ctx: Context<ReshuffleBlackjackShoe>,
+    computation_offset: u64,
+    _shoe_id: u64,
+    mxe_nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.blackjack_shoe.needs_shuffle, CasinoError::InvalidGameState);
+    require!(!ctx.accounts.blackjack_shoe.in_use, CasinoError::ShoeInUse);
+
+    let args = vec![Argument::PlaintextU128(mxe_nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ShuffleShoeCallback::callback_ix(&[CallbackAccount {
+            pubkey: ctx.accounts.blackjack_shoe.key(),
+            is_writable: true,
+        }])],
+    )?;
+    Ok(())
+}
+
+pub fn deal_blackjack_from_shoe(
+    ctx: Context<DealBlackjackFromShoe>,
+    computation_offset: u64,
+    game_id: u64,
+    _shoe_id: u64,
+    mxe_nonce: u128,
+    mxe_again_nonce: u128,
+    client_pubkey: [u8; 32],
+    client_nonce: u128,
+    client_again_nonce: u128,
+    bet_amount: u64,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    require!(!ctx.accounts.blackjack_shoe.in_use, CasinoError::ShoeInUse);
+    require!(!ctx.accounts.blackjack_shoe.needs_shuffle, CasinoError::ShoeNeedsShuffle);
+
+    // Escrow the stake in the vault until the hand is resolved
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let blackjack_game = &mut ctx.accounts.blackjack_game;
+    blackjack_game.bump = ctx.bumps.blackjack_game;
+    blackjack_game.game_id = game_id;
+    blackjack_game.player_pubkey = ctx.accounts.payer.key();
+    blackjack_game.player_hand = [0; 32];
+    blackjack_game.dealer_hand = [0; 32];
+    blackjack_game.deck_nonce = 0;
+    blackjack_game.client_nonce = 0;
+    blackjack_game.dealer_nonce = 0;
+    blackjack_game.player_enc_pubkey = client_pubkey;
+    blackjack_game.game_state = BlackjackGameState::Initial;
+    blackjack_game.player_hand_size = 0;
+    blackjack_game.dealer_hand_size = 0;
+    blackjack_game.player_has_stood = false;
+    blackjack_game.game_result = 0;
+    blackjack_game.bet_amount = bet_amount;
+    blackjack_game.shoe = ctx.accounts.blackjack_shoe.key();
+    blackjack_game.last_action_slot = Clock::get()?.slot;
+    blackjack_game.cranker = Pubkey::default();
+    blackjack_game.crank_fee = 0;
+    blackjack_game.rules = ctx.accounts.blackjack_config.rules;
+    // Shoe hands are dealt by `deal_from_shoe`, which carries no side bets
+    blackjack_game.perfect_pairs_bet = 0;
+    blackjack_game.twenty_one_plus_three_bet = 0;
+    blackjack_game.perfect_pairs_result = 0;
+    blackjack_game.twenty_one_plus_three_result = 0;
+    blackjack_game.side_bet_paytable = BlackjackSideBetPaytable::default();
+    blackjack_game.deck_commitment = [0; 32];
+    blackjack_game.deck_hash = [0; 32];
+    blackjack_game.deck_revealed = false;
+    open_blackjack_session(&mut ctx.accounts.blackjack_sessions, ctx.accounts.payer.key(), ctx.bumps.blackjack_sessions, game_id)?;
+
+    // Hold the shoe until this hand is resolved and released
+    let shoe = &mut ctx.accounts.blackjack_shoe;
+    shoe.in_use = true;
+    shoe.current_game = ctx.accounts.blackjack_game.key();
+    shoe.hands_dealt += 1;
+
+    let args = vec![
+        Argument::PlaintextU128(shoe.shoe_nonce),
+        Argument::Account(shoe.key(), 8, 32 * 15),
+        Argument::PlaintextU16(shoe.position),
+        Argument::PlaintextU128(mxe_nonce),
+        Argument::PlaintextU128(mxe_again_nonce),
+        Argument::ArcisPubkey(client_pubkey),
+        Argument::PlaintextU128(client_nonce),
+        Argument::ArcisPubkey(client_pubkey),
+        Argument::PlaintextU128(client_again_nonce),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DealFromShoeCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.blackjack_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.blackjack_shoe.key(), is_writable: false },
+        ])],
+    )?;
+    Ok(())
+}
+
+/// Returns the shoe once the hand holding it is resolved, advancing past every card it drew.
+/// A shoe deals one hand at a time, so an abandoned hand would block it: once the hand has
+/// been idle past the inactivity timeout anyone may release it, skipping every card it could
+/// still draw from its copy of the shoe.
+pub fn release_blackjack_shoe(ctx: Context<ReleaseBlackjackShoe>, _shoe_id: u64, _game_id: u64) -> Result<()> {
+    let game = &mut ctx.accounts.blackjack_game;
+    let shoe = &mut ctx.accounts.blackjack_shoe;
+    require!(shoe.in_use && shoe.current_game == game.key(), CasinoError::InvalidGameState);
+
+    let cards_used = if game.game_state == BlackjackGameState::Resolved {
+        (game.player_hand_size + game.dealer_hand_size) as u16
+    } else {
+        let now = Clock::get()?.slot;
+        require!(
+            now >= game.last_action_slot.saturating_add(ctx.accounts.blackjack_config.timeout_slots),
+            CasinoError::GameNotTimedOut
+        );
+        BLACKJACK_SHOE_RESERVE_CARDS
+    };
+
+    shoe.position += cards_used;
+    shoe.in_use = false;
+    shoe.current_game = Pubkey::default();
+    // Detach so the game account can be closed
+    game.shoe = Pubkey::default();
+    if shoe.position >= shoe.cut_card_position {
+        shoe.needs_shuffle = true;
+        emit!(ShoeCutCardReachedEvent { shoe_id: shoe.shoe_id, position: shoe.position });
+    }
+    Ok(())
+}
+
+// --- Callbacks ---
+
+#[event]
+pub struct ShoeShuffledEvent { pub shoe_id: u64, pub shuffle_count: u64 }
+#[event]
+pub struct ShoeCutCardReachedEvent { pub shoe_id: u64, pub position: u16 }
+
+pub fn shuffle_shoe_callback(
+    ctx: Context<ShuffleShoeCallback>,
+    output: ComputationOutputs<ShuffleShoeOutput>,
+) -> Result<()> {
+    let shoe_ct = match output {
+        ComputationOutputs::Success(ShuffleShoeOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let shoe = &mut ctx.accounts.blackjack_shoe;
+    // A second shuffle queued before the first landed must not reset a shoe already dealing
+    require!(shoe.needs_shuffle && !shoe.in_use, CasinoError::InvalidGameState);
+    shoe.cards = shoe_ct.ciphertexts;
+    shoe.shoe_nonce = shoe_ct.nonce;
+    shoe.position = 0;
+    shoe.needs_shuffle = false;
+    shoe.shuffle_count += 1;
+
+    emit!(ShoeShuffledEvent { shoe_id: shoe.shoe_id, shuffle_count: shoe.shuffle_count });
+    Ok(())
+}
+
+pub fn deal_from_shoe_callback(
+    ctx: Context<DealFromShoeCallback>,
+    output: ComputationOutputs<DealFromShoeOutput>,
+) -> Result<()> {
+    let (deck, dealer_hand, player_hand, dealer_face_up_card) = match output {
+        ComputationOutputs::Success(DealFromShoeOutput { field_0: DealFromShoeOutputStruct0 { field_0: deck, field_1: dealer_hand, field_2: player_hand, field_3: dealer_face_up_card } }) => (deck, dealer_hand, player_hand, dealer_face_up_card),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let client_nonce = player_hand.nonce;
+    let player_hand_ct = player_hand.ciphertexts[0];
+    let dealer_client_nonce = dealer_face_up_card.nonce;
+    let dealer_face_up_ct = dealer_face_up_card.ciphertexts[0];
+
+    let game = &mut ctx.accounts.blackjack_game;
+    require!(game.game_state == BlackjackGameState::Initial, CasinoError::InvalidGameState);
+    // The shoe must still be lent to this hand; a release after the timeout hands it on
+    let shoe = &ctx.accounts.blackjack_shoe;
+    require!(shoe.in_use && shoe.current_game == game.key() && game.shoe == shoe.key(), CasinoError::InvalidGameState);
+    game.deck = deck.ciphertexts;
+    game.deck_nonce = deck.nonce;
+    game.client_nonce = client_nonce;
+    game.dealer_nonce = dealer_hand.nonce;
+    game.player_enc_pubkey = player_hand.encryption_key;
+    game.last_action_slot = Clock::get()?.slot;
+    game.game_state = BlackjackGameState::PlayerTurn;
+    game.player_hand = player_hand_ct;
+    game.dealer_hand = dealer_hand.ciphertexts[0];
+    game.player_hand_size = 2;
+    game.dealer_hand_size = 2;
+
+    require!(dealer_face_up_card.encryption_key == game.player_enc_pubkey, CasinoError::InvalidDealerClientPubkey);
+
+    emit!(CardsShuffledAndDealtEvent { client_nonce, dealer_client_nonce, player_hand: player_hand_ct, dealer_face_up_card: dealer_face_up_ct, game_id: game.game_id });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[queue_computation_accounts("shuffle_shoe", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, shoe_id: u64)]
+pub struct CreateBlackjackShoe<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SHUFFLE_SHOE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == payer.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Account<'info, CasinoState>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BlackjackShoe::INIT_SPACE,
+        seeds = [b"blackjack_shoe".as_ref(), shoe_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub blackjack_shoe: Box<Account<'info, BlackjackShoe>>,
+}
+
+#[queue_computation_accounts("shuffle_shoe", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _shoe_id: u64)]
+pub struct ReshuffleBlackjackShoe<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SHUFFLE_SHOE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        mut,
+        seeds = [b"blackjack_shoe".as_ref(), _shoe_id.to_le_bytes().as_ref()],
+        bump = blackjack_shoe.bump,
+        constraint = blackjack_shoe.authority == payer.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub blackjack_shoe: Box<Account<'info, BlackjackShoe>>,
+}
+
+#[callback_accounts("shuffle_shoe")]
+#[derive(Accounts)]
+pub struct ShuffleShoeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SHUFFLE_SHOE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_shoe: Box<Account<'info, BlackjackShoe>>,
+}
+
+#[init_computation_definition_accounts("shuffle_shoe", payer)]
+#[derive(Accounts)]
+pub struct InitShuffleShoeCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("deal_from_shoe", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64, _shoe_id: u64)]
+pub struct DealBlackjackFromShoe<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_FROM_SHOE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(mut, seeds = [b"blackjack_shoe".as_ref(), _shoe_id.to_le_bytes().as_ref()], bump = blackjack_shoe.bump)]
+    pub blackjack_shoe: Box<Account<'info, BlackjackShoe>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BlackjackGame::INIT_SPACE,
+        seeds = [b"blackjack_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub blackjack_game: Box<Account<'info, BlackjackGame>>,
+
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BlackjackSessions::DISCRIMINATOR.len() + BlackjackSessions::INIT_SPACE,
+        seeds = [b"blackjack_sessions", payer.key().as_ref()],
+        bump
+    )]
+    pub blackjack_sessions: Box<Account<'info, BlackjackSessions>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[callback_accounts("deal_from_shoe")]
+#[derive(Accounts)]
+pub struct DealFromShoeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_FROM_SHOE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_game: Box<Account<'info, BlackjackGame>>,
+    pub blackjack_shoe: Box<Account<'info, BlackjackShoe>>,
+}
+
+#[init_computation_definition_accounts("deal_from_shoe", payer)]
+#[derive(Accounts)]
+pub struct InitDealFromShoeCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_shoe_id: u64, _game_id: u64)]
+pub struct ReleaseBlackjackShoe<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_shoe".as_ref(), _shoe_id.to_le_bytes().as_ref()], bump = blackjack_shoe.bump)]
+    pub blackjack_shoe: Box<Account<'info, BlackjackShoe>>,
+    #[account(mut, seeds = [b"blackjack_game".as_ref(), blackjack_game.player_pubkey.as_ref(), _game_id.to_le_bytes().as_ref()], bump = blackjack_game.bump)]
+    pub blackjack_game: Box<Account<'info, BlackjackGame>>,
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
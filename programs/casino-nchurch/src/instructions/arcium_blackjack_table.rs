@@ -0,0 +1,915 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use crate::errors::*;
+use crate::state::casino::*;
+use crate::utils::calculate_blackjack_settlement;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_DEAL_TABLE;
+use crate::COMP_DEF_OFFSET_TABLE_PLAYER_HIT;
+use crate::COMP_DEF_OFFSET_TABLE_DEALER_PLAY;
+use crate::COMP_DEF_OFFSET_RESOLVE_TABLE;
+
+const EMPTY_SEAT: BlackjackSeat = BlackjackSeat {
+    hand: [0; 32],
+    client_nonce: 0,
+    enc_pubkey: [0; 32],
+    player: Pubkey::new_from_array([0; 32]),
+    bet_amount: 0,
+    hand_size: 0,
+    status: BlackjackSeatStatus::Empty,
+    result: 0,
+};
+
+/// Byte offset of a seat's encrypted hand inside the `BlackjackTable` account.
+fn seat_hand_offset(seat: usize) -> u32 {
+    (8 + 32 * 3 + 32 + seat * BlackjackSeat::INIT_SPACE) as u32
+}
+
+/// Moves the turn to the next seat still to act at or after `from`, or hands over to the dealer.
+/// Restarts the inactivity clock for whoever acts next.
+fn advance_turn(table: &mut BlackjackTable, from: usize, now: u64) {
+    table.last_action_slot = now;
+    for seat in from..BLACKJACK_TABLE_SEATS {
+        if table.seats[seat].status == BlackjackSeatStatus::Seated {
+            table.current_seat = seat as u8;
+            table.table_state = BlackjackTableState::PlayerTurns;
+            return;
+        }
+    }
+    table.current_seat = BLACKJACK_TABLE_SEATS as u8;
+    table.table_state = BlackjackTableState::DealerTurn;
+}
+
+// --- Init computation definitions ---
+
+pub fn init_deal_table_comp_def(ctx: Context<InitDealTableCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_table_player_hit_comp_def(ctx: Context<InitTablePlayerHitCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_table_dealer_play_comp_def(ctx: Context<InitTableDealerPlayCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_resolve_table_comp_def(ctx: Context<InitResolveTableCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+// --- Table management ---
+
+pub fn create_blackjack_table(ctx: Context<CreateBlackjackTable>, table_id: u64) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    table.deck = [[0; 32]; 3];
+    table.dealer_hand = [0; 32];
+    table.seats = [EMPTY_SEAT; BLACKJACK_TABLE_SEATS];
+    table.deck_nonce = 0;
+    table.dealer_nonce = 0;
+    table.table_id = table_id;
+    table.creator = ctx.accounts.payer.key();
+    table.table_state = BlackjackTableState::Seating;
+    table.current_seat = 0;
+    table.cards_dealt = 0;
+    table.dealer_hand_size = 0;
+    table.dealer_up_card = 0;
+    table.round = 0;
+    table.rules = ctx.accounts.blackjack_config.rules;
+    table.last_action_slot = 0;
+    table.bump = ctx.bumps.blackjack_table;
+    Ok(())
+}
+
+pub fn join_blackjack_table(
+    ctx: Context<JoinBlackjackTable>,
+    _table_id: u64,
+    seat_index: u8,
+    client_pubkey: [u8; 32],
+    bet_amount: u64,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    require!(ctx.accounts.blackjack_table.table_state == BlackjackTableState::Seating, CasinoError::InvalidGameState);
+    let seat = seat_index as usize;
+    require!(seat < BLACKJACK_TABLE_SEATS, CasinoError::SeatNotAvailable);
+    require!(ctx.accounts.blackjack_table.seats[seat].status == BlackjackSeatStatus::Empty, CasinoError::SeatNotAvailable);
+    let player = ctx.accounts.payer.key();
+    require!(
+        ctx.accounts.blackjack_table.seats.iter().all(|s| s.status == BlackjackSeatStatus::Empty || s.player != player),
+        CasinoError::SeatNotAvailable
+    );
+
+    // Escrow the stake in the vault until the round is settled
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = player;
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    ctx.accounts.blackjack_table.seats[seat] = BlackjackSeat {
+        enc_pubkey: client_pubkey,
+        player,
+        bet_amount,
+        status: BlackjackSeatStatus::Seated,
+        ..EMPTY_SEAT
+    };
+    Ok(())
+}
+
+pub fn table_player_stand(ctx: Context<TablePlayerStand>, _table_id: u64) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::PlayerTurns, CasinoError::InvalidGameState);
+    let seat = table.current_seat as usize;
+    require!(table.seats[seat].player == ctx.accounts.payer.key(), CasinoError::NotYourTurn);
+
+    table.seats[seat].status = BlackjackSeatStatus::Stood;
+    advance_turn(table, seat + 1, Clock::get()?.slot);
+
+    emit!(TableSeatStandEvent { table_id: table.table_id, seat: seat as u8 });
+    Ok(())
+}
+
+/// Stands the current seat on its player's behalf once its turn has been idle past the timeout,
+/// so one absent player can't hold up the rest of the table.
+pub fn crank_blackjack_table_stand(ctx: Context<CrankBlackjackTableStand>, _table_id: u64) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::PlayerTurns, CasinoError::InvalidGameState);
+    let now = Clock::get()?.slot;
+    require!(
+        now >= table.last_action_slot.saturating_add(ctx.accounts.blackjack_config.timeout_slots),
+        CasinoError::GameNotTimedOut
+    );
+
+    let seat = table.current_seat as usize;
+    table.seats[seat].status = BlackjackSeatStatus::Stood;
+    advance_turn(table, seat + 1, now);
+
+    emit!(TableSeatStandEvent { table_id: table.table_id, seat: seat as u8 });
+    emit!(TableSeatCrankedEvent { table_id: table.table_id, seat: seat as u8, cranker: ctx.accounts.payer.key() });
+    Ok(())
+}
+
+/// Gives a seated player their stake back and frees the seat, as long as the next round hasn't
+/// been dealt yet.
+pub fn leave_blackjack_table(ctx: Context<LeaveBlackjackTable>, _table_id: u64) -> Result<()> {
+    require!(ctx.accounts.blackjack_table.table_state == BlackjackTableState::Seating, CasinoError::InvalidGameState);
+    let player = ctx.accounts.payer.key();
+    let seat_slot = ctx.accounts.blackjack_table.seats
+        .iter()
+        .position(|s| s.status == BlackjackSeatStatus::Seated && s.player == player)
+        .ok_or(CasinoError::SeatNotAvailable)?;
+    let refund = ctx.accounts.blackjack_table.seats[seat_slot].bet_amount;
+
+    let ix = TransferChecked {
+        from: ctx.accounts.casino_vault.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.casino_state.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+    transfer_checked(cpi_ctx, refund, ctx.accounts.usdc_mint.decimals)?;
+
+    // The hand was never played, so take back what joining recorded
+    ctx.accounts.casino_state.total_games_played = ctx.accounts.casino_state.total_games_played.saturating_sub(1);
+    ctx.accounts.casino_state.total_volume = ctx.accounts.casino_state.total_volume.saturating_sub(refund);
+    ctx.accounts.user_stats.total_bets = ctx.accounts.user_stats.total_bets.saturating_sub(refund);
+    ctx.accounts.user_stats.games_played = ctx.accounts.user_stats.games_played.saturating_sub(1);
+
+    let table = &mut ctx.accounts.blackjack_table;
+    table.seats[seat_slot] = EMPTY_SEAT;
+
+    emit!(TableSeatLeftEvent { table_id: table.table_id, seat: seat_slot as u8, refund });
+    Ok(())
+}
+
+/// Unsticks a table whose computation aborted or never called back. Once it has been idle past
+/// the timeout anyone may put it back in the state the computation was queued from so it can be
+/// retried; a deal that never landed reopens seating, where players can leave with their stakes.
+pub fn recover_blackjack_table(ctx: Context<RecoverBlackjackTable>, _table_id: u64) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    let now = Clock::get()?.slot;
+    require!(
+        now >= table.last_action_slot.saturating_add(ctx.accounts.blackjack_config.timeout_slots),
+        CasinoError::GameNotTimedOut
+    );
+
+    let from_state = table.table_state;
+    table.table_state = match from_state {
+        BlackjackTableState::Dealing => BlackjackTableState::Seating,
+        BlackjackTableState::Hitting => BlackjackTableState::PlayerTurns,
+        BlackjackTableState::DealerPlaying => BlackjackTableState::DealerTurn,
+        BlackjackTableState::Settling => BlackjackTableState::Resolving,
+        _ => return Err(CasinoError::InvalidGameState.into()),
+    };
+    table.last_action_slot = now;
+
+    emit!(TableRecoveredEvent { table_id: table.table_id, from_state });
+    Ok(())
+}
+
+pub fn claim_blackjack_table_seat(ctx: Context<ClaimBlackjackTableSeat>, _table_id: u64, seat_index: u8) -> Result<()> {
+    require!(ctx.accounts.blackjack_table.table_state == BlackjackTableState::Resolved, CasinoError::InvalidGameState);
+    let seat_slot = seat_index as usize;
+    require!(seat_slot < BLACKJACK_TABLE_SEATS, CasinoError::SeatNotAvailable);
+    let seat = ctx.accounts.blackjack_table.seats[seat_slot];
+    require!(seat.status != BlackjackSeatStatus::Empty, CasinoError::SeatNotAvailable);
+    require!(ctx.accounts.user_token_account.owner == seat.player, CasinoError::InvalidMove);
+    require!(ctx.accounts.user_stats.user == seat.player, CasinoError::InvalidMove);
+
+    let final_payout = calculate_blackjack_settlement(seat.result, seat.bet_amount, ctx.accounts.casino_state.house_edge_config.blackjack_rtp_bps);
+
+    if final_payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, final_payout, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    // stats
+    ctx.accounts.casino_state.total_payouts += final_payout;
+    if final_payout > seat.bet_amount {
+        ctx.accounts.user_stats.total_wins += final_payout - seat.bet_amount;
+        ctx.accounts.user_stats.loyalty_points += seat.bet_amount / 100;
+    } else if final_payout < seat.bet_amount {
+        ctx.accounts.user_stats.total_losses += seat.bet_amount - final_payout;
+    }
+
+    // Free the seat; the table reopens once every seat has been settled
+    let table = &mut ctx.accounts.blackjack_table;
+    table.seats[seat_slot] = EMPTY_SEAT;
+    if table.seats.iter().all(|s| s.status == BlackjackSeatStatus::Empty) {
+        table.table_state = BlackjackTableState::Seating;
+        table.round += 1;
+        table.cards_dealt = 0;
+        table.dealer_hand_size = 0;
+        table.current_seat = 0;
+    }
+
+    emit!(TableSeatSettledEvent { table_id: table.table_id, seat: seat_index, result_code: seat.result, payout: final_payout });
+    Ok(())
+}
+
+// --- Queue entrypoints ---
+
+pub fn deal_blackjack_table(
+    ctx: Context<DealBlackjackTable>,
+    computation_offset: u64,
+    _table_id: u64,
+    mxe_nonce: u128,
+    mxe_again_nonce: u128,
+    seat_nonces: [u128; 5],
+) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::Seating, CasinoError::InvalidGameState);
+    let payer = ctx.accounts.payer.key();
+    let seated = table.seats.iter().find(|s| s.status == BlackjackSeatStatus::Seated).copied();
+    let Some(first_seat) = seated else { return Err(CasinoError::TableEmpty.into()) };
+    require!(table.seats.iter().any(|s| s.status == BlackjackSeatStatus::Seated && s.player == payer), CasinoError::NotYourTurn);
+
+    // The circuit deals only to seats in `seated_mask`; empty seats get an empty hand, which
+    // still needs a key to encrypt to, so they borrow the first seated player's
+    let mut args = vec![
+        Argument::PlaintextU128(mxe_nonce),
+        Argument::PlaintextU128(mxe_again_nonce),
+    ];
+    let mut seated_mask = 0u8;
+    for seat in 0..BLACKJACK_TABLE_SEATS {
+        if table.seats[seat].status == BlackjackSeatStatus::Empty {
+            table.seats[seat].enc_pubkey = first_seat.enc_pubkey;
+        } else {
+            seated_mask |= 1 << seat;
+        }
+        args.push(Argument::ArcisPubkey(table.seats[seat].enc_pubkey));
+        args.push(Argument::PlaintextU128(seat_nonces[seat]));
+    }
+    args.push(Argument::PlaintextU8(seated_mask));
+
+    table.table_state = BlackjackTableState::Dealing;
+    table.last_action_slot = Clock::get()?.slot;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DealTableCallback::callback_ix(&[CallbackAccount {
+            pubkey: ctx.accounts.blackjack_table.key(),
+            is_writable: true,
+        }])],
+    )?;
+    Ok(())
+}
+
+pub fn table_player_hit(
+    ctx: Context<TablePlayerHit>,
+    computation_offset: u64,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::PlayerTurns, CasinoError::InvalidGameState);
+    let seat = table.current_seat as usize;
+    require!(table.seats[seat].player == ctx.accounts.payer.key(), CasinoError::NotYourTurn);
+    require!(table.seats[seat].hand_size < 11, CasinoError::InvalidMove);
+    require!(table.cards_dealt < 52 - BLACKJACK_TABLE_DEALER_RESERVE, CasinoError::DeckExhausted);
+
+    let args = vec![
+        Argument::PlaintextU128(table.deck_nonce),
+        Argument::Account(table.key(), 8, 32 * 3),
+        Argument::ArcisPubkey(table.seats[seat].enc_pubkey),
+        Argument::PlaintextU128(table.seats[seat].client_nonce),
+        Argument::Account(table.key(), seat_hand_offset(seat), 32),
+        Argument::PlaintextU8(table.seats[seat].hand_size),
+        Argument::PlaintextU8(table.cards_dealt),
+    ];
+
+    // Block further actions while the draw is in flight
+    table.table_state = BlackjackTableState::Hitting;
+    table.last_action_slot = Clock::get()?.slot;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![TablePlayerHitCallback::callback_ix(&[CallbackAccount {
+            pubkey: ctx.accounts.blackjack_table.key(),
+            is_writable: true,
+        }])],
+    )?;
+    Ok(())
+}
+
+pub fn table_dealer_play(
+    ctx: Context<TableDealerPlay>,
+    computation_offset: u64,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::DealerTurn, CasinoError::InvalidGameState);
+
+    let args = vec![
+        Argument::PlaintextU128(table.deck_nonce),
+        Argument::Account(table.key(), 8, 32 * 3),
+        Argument::PlaintextU128(table.dealer_nonce),
+        Argument::Account(table.key(), 8 + 32 * 3, 32),
+        Argument::PlaintextU8(table.dealer_hand_size),
+        Argument::PlaintextU8(table.cards_dealt),
+        Argument::PlaintextBool(table.rules.dealer_hits_soft_17),
+    ];
+
+    table.table_state = BlackjackTableState::DealerPlaying;
+    table.last_action_slot = Clock::get()?.slot;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![TableDealerPlayCallback::callback_ix(&[CallbackAccount {
+            pubkey: ctx.accounts.blackjack_table.key(),
+            is_writable: true,
+        }])],
+    )?;
+    Ok(())
+}
+
+pub fn resolve_blackjack_table(
+    ctx: Context<ResolveBlackjackTable>,
+    computation_offset: u64,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::Resolving, CasinoError::InvalidGameState);
+
+    let mut args = Vec::with_capacity(3 * BLACKJACK_TABLE_SEATS + 2 + BLACKJACK_TABLE_SEATS + 1);
+    for seat in 0..BLACKJACK_TABLE_SEATS {
+        args.push(Argument::ArcisPubkey(table.seats[seat].enc_pubkey));
+        args.push(Argument::PlaintextU128(table.seats[seat].client_nonce));
+        args.push(Argument::Account(table.key(), seat_hand_offset(seat), 32));
+    }
+    args.push(Argument::PlaintextU128(table.dealer_nonce));
+    args.push(Argument::Account(table.key(), 8 + 32 * 3, 32));
+    for seat in 0..BLACKJACK_TABLE_SEATS {
+        args.push(Argument::PlaintextU8(table.seats[seat].hand_size));
+    }
+    args.push(Argument::PlaintextU8(table.dealer_hand_size));
+
+    // Only one settlement may be in flight, so the seats can't be resolved twice
+    table.table_state = BlackjackTableState::Settling;
+    table.last_action_slot = Clock::get()?.slot;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ResolveTableCallback::callback_ix(&[CallbackAccount {
+            pubkey: ctx.accounts.blackjack_table.key(),
+            is_writable: true,
+        }])],
+    )?;
+    Ok(())
+}
+
+// --- Callbacks ---
+
+#[event]
+pub struct TableDealtEvent {
+    pub table_id: u64,
+    pub round: u64,
+    pub dealer_up_card: u8,
+    pub seat_hands: [[u8; 32]; 5],
+    pub seat_nonces: [u128; 5],
+}
+#[event]
+pub struct TableSeatHitEvent { pub table_id: u64, pub seat: u8, pub hand: [u8; 32], pub client_nonce: u128, pub is_bust: bool }
+#[event]
+pub struct TableSeatStandEvent { pub table_id: u64, pub seat: u8 }
+#[event]
+pub struct TableSeatCrankedEvent { pub table_id: u64, pub seat: u8, pub cranker: Pubkey }
+#[event]
+pub struct TableDealerPlayEvent { pub table_id: u64, pub dealer_hand_size: u8 }
+#[event]
+pub struct TableResolvedEvent { pub table_id: u64, pub round: u64, pub results: [u8; 5], pub rules: BlackjackRules }
+#[event]
+pub struct TableSeatLeftEvent { pub table_id: u64, pub seat: u8, pub refund: u64 }
+#[event]
+pub struct TableRecoveredEvent { pub table_id: u64, pub from_state: BlackjackTableState }
+#[event]
+pub struct TableSeatSettledEvent { pub table_id: u64, pub seat: u8, pub result_code: u8, pub payout: u64 }
+
+pub fn deal_table_callback(
+    ctx: Context<DealTableCallback>,
+    output: ComputationOutputs<DealTableOutput>,
+) -> Result<()> {
+    let o = match output {
+        ComputationOutputs::Success(DealTableOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+    let seat_hands = [o.field_2, o.field_3, o.field_4, o.field_5, o.field_6];
+
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::Dealing, CasinoError::InvalidGameState);
+    table.deck = o.field_0.ciphertexts;
+    table.deck_nonce = o.field_0.nonce;
+    table.dealer_hand = o.field_1.ciphertexts[0];
+    table.dealer_nonce = o.field_1.nonce;
+    table.dealer_hand_size = 2;
+    table.dealer_up_card = o.field_7;
+    let seated = table.seats.iter().filter(|s| s.status == BlackjackSeatStatus::Seated).count() as u8;
+    table.cards_dealt = 2 * (seated + 1);
+    for (seat, hand) in seat_hands.iter().enumerate() {
+        table.seats[seat].hand = hand.ciphertexts[0];
+        table.seats[seat].client_nonce = hand.nonce;
+        if table.seats[seat].status == BlackjackSeatStatus::Seated {
+            table.seats[seat].hand_size = 2;
+        }
+    }
+    advance_turn(table, 0, Clock::get()?.slot);
+
+    emit!(TableDealtEvent {
+        table_id: table.table_id,
+        round: table.round,
+        dealer_up_card: table.dealer_up_card,
+        seat_hands: table.seats.map(|s| s.hand),
+        seat_nonces: table.seats.map(|s| s.client_nonce),
+    });
+    Ok(())
+}
+
+pub fn table_player_hit_callback(
+    ctx: Context<TablePlayerHitCallback>,
+    output: ComputationOutputs<TablePlayerHitOutput>,
+) -> Result<()> {
+    let (seat_hand, is_bust) = match output {
+        ComputationOutputs::Success(TablePlayerHitOutput { field_0: TablePlayerHitOutputStruct0 { field_0: seat_hand, field_1: is_bust } }) => (seat_hand, is_bust),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::Hitting, CasinoError::InvalidGameState);
+    let seat = table.current_seat as usize;
+    table.seats[seat].hand = seat_hand.ciphertexts[0];
+    table.seats[seat].client_nonce = seat_hand.nonce;
+    table.seats[seat].hand_size += 1;
+    table.cards_dealt += 1;
+    if is_bust {
+        table.seats[seat].status = BlackjackSeatStatus::Busted;
+        advance_turn(table, seat + 1, Clock::get()?.slot);
+    } else {
+        table.table_state = BlackjackTableState::PlayerTurns;
+        table.last_action_slot = Clock::get()?.slot;
+    }
+
+    emit!(TableSeatHitEvent { table_id: table.table_id, seat: seat as u8, hand: seat_hand.ciphertexts[0], client_nonce: seat_hand.nonce, is_bust });
+    Ok(())
+}
+
+pub fn table_dealer_play_callback(
+    ctx: Context<TableDealerPlayCallback>,
+    output: ComputationOutputs<TableDealerPlayOutput>,
+) -> Result<()> {
+    let (dealer_hand, dealer_hand_size) = match output {
+        ComputationOutputs::Success(TableDealerPlayOutput { field_0: TableDealerPlayOutputStruct0 { field_0: dealer_hand, field_1: dealer_hand_size } }) => (dealer_hand, dealer_hand_size),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::DealerPlaying, CasinoError::InvalidGameState);
+    table.dealer_hand = dealer_hand.ciphertexts[0];
+    table.dealer_nonce = dealer_hand.nonce;
+    table.cards_dealt += dealer_hand_size - table.dealer_hand_size;
+    table.dealer_hand_size = dealer_hand_size;
+    table.table_state = BlackjackTableState::Resolving;
+
+    emit!(TableDealerPlayEvent { table_id: table.table_id, dealer_hand_size });
+    Ok(())
+}
+
+pub fn resolve_table_callback(
+    ctx: Context<ResolveTableCallback>,
+    output: ComputationOutputs<ResolveTableOutput>,
+) -> Result<()> {
+    let o = match output {
+        ComputationOutputs::Success(ResolveTableOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+    let results = [o.field_0, o.field_1, o.field_2, o.field_3, o.field_4];
+
+    let table = &mut ctx.accounts.blackjack_table;
+    require!(table.table_state == BlackjackTableState::Settling, CasinoError::InvalidGameState);
+    for seat in 0..BLACKJACK_TABLE_SEATS {
+        if table.seats[seat].status != BlackjackSeatStatus::Empty {
+            table.seats[seat].result = results[seat];
+        }
+    }
+    table.table_state = BlackjackTableState::Resolved;
+
+    emit!(TableResolvedEvent { table_id: table.table_id, round: table.round, results: table.seats.map(|s| s.result), rules: table.rules });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct CreateBlackjackTable<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BlackjackTable::INIT_SPACE,
+        seeds = [b"blackjack_table".as_ref(), table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_table_id: u64)]
+pub struct JoinBlackjackTable<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_table_id: u64)]
+pub struct TablePlayerStand<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[derive(Accounts)]
+#[instruction(_table_id: u64)]
+pub struct CrankBlackjackTableStand<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+}
+
+#[derive(Accounts)]
+#[instruction(_table_id: u64)]
+pub struct ClaimBlackjackTableSeat<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", user_stats.user.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(_table_id: u64)]
+pub struct LeaveBlackjackTable<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ CasinoError::InvalidMove)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(_table_id: u64)]
+pub struct RecoverBlackjackTable<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+    #[account(seeds = [b"blackjack_config"], bump = blackjack_config.bump)]
+    pub blackjack_config: Box<Account<'info, BlackjackConfig>>,
+}
+
+#[queue_computation_accounts("deal_table", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _table_id: u64)]
+pub struct DealBlackjackTable<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_TABLE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[callback_accounts("deal_table")]
+#[derive(Accounts)]
+pub struct DealTableCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_TABLE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[init_computation_definition_accounts("deal_table", payer)]
+#[derive(Accounts)]
+pub struct InitDealTableCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("table_player_hit", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _table_id: u64)]
+pub struct TablePlayerHit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TABLE_PLAYER_HIT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[callback_accounts("table_player_hit")]
+#[derive(Accounts)]
+pub struct TablePlayerHitCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TABLE_PLAYER_HIT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[init_computation_definition_accounts("table_player_hit", payer)]
+#[derive(Accounts)]
+pub struct InitTablePlayerHitCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("table_dealer_play", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _table_id: u64)]
+pub struct TableDealerPlay<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TABLE_DEALER_PLAY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[callback_accounts("table_dealer_play")]
+#[derive(Accounts)]
+pub struct TableDealerPlayCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TABLE_DEALER_PLAY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[init_computation_definition_accounts("table_dealer_play", payer)]
+#[derive(Accounts)]
+pub struct InitTableDealerPlayCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("resolve_table", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _table_id: u64)]
+pub struct ResolveBlackjackTable<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_TABLE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, seeds = [b"blackjack_table".as_ref(), _table_id.to_le_bytes().as_ref()], bump = blackjack_table.bump)]
+    pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[callback_accounts("resolve_table")]
+#[derive(Accounts)]
+pub struct ResolveTableCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_TABLE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub blackjack_table: Box<Account<'info, BlackjackTable>>,
+}
+
+#[init_computation_definition_accounts("resolve_table", payer)]
+#[derive(Accounts)]
+pub struct InitResolveTableCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,343 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{craps_lay_odds_win, craps_point_index, craps_table_total, craps_take_odds_win, settle_craps_roll};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_ROLL_TWO_DICE;
+
+pub fn init_roll_two_dice_comp_def(ctx: Context<InitRollTwoDiceCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Opens a craps table for the player. The table persists across rolls, carrying the point
+/// and every bet still working until the player closes it.
+pub fn open_craps_game(ctx: Context<OpenCrapsGame>, game_id: u64) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.craps_game;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.point = 0;
+    game.pass_bet = 0;
+    game.pass_odds = 0;
+    game.dont_pass_bet = 0;
+    game.dont_pass_odds = 0;
+    game.pending_come = 0;
+    game.come_bets = [0; 6];
+    game.come_odds = [0; 6];
+    game.rolling = false;
+    game.last_dice = [0; 2];
+    game.rolls = 0;
+    game.bump = ctx.bumps.craps_game;
+    Ok(())
+}
+
+/// Adds `amount` to one bet on the table. Line bets go down on the come-out roll, come bets
+/// while a point is on, and odds behind a line or come bet up to 3-4-5x.
+/// `number` picks the come bet for `ComeOdds` and is ignored otherwise.
+pub fn place_craps_bet(ctx: Context<PlaceCrapsBet>, _game_id: u64, bet: CrapsBet, number: u8, amount: u64) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(amount > 0, CasinoError::InvalidBetAmount);
+    let game = &mut ctx.accounts.craps_game;
+    require!(!game.rolling, CasinoError::InvalidGameState);
+
+    let point = game.point;
+    match bet {
+        CrapsBet::Pass => {
+            require!(point == 0, CasinoError::InvalidCrapsBet);
+            game.pass_bet += amount;
+        }
+        CrapsBet::DontPass => {
+            require!(point == 0, CasinoError::InvalidCrapsBet);
+            game.dont_pass_bet += amount;
+        }
+        CrapsBet::Come => {
+            require!(point != 0, CasinoError::InvalidCrapsBet);
+            game.pending_come += amount;
+        }
+        CrapsBet::PassOdds => {
+            require!(point != 0 && game.pass_bet > 0, CasinoError::InvalidCrapsBet);
+            game.pass_odds += amount;
+            require!(craps_take_odds_win(point, game.pass_odds) <= game.pass_bet * CRAPS_MAX_ODDS_WIN, CasinoError::CrapsOddsTooLarge);
+        }
+        CrapsBet::DontPassOdds => {
+            require!(point != 0 && game.dont_pass_bet > 0, CasinoError::InvalidCrapsBet);
+            game.dont_pass_odds += amount;
+            require!(craps_lay_odds_win(point, game.dont_pass_odds) <= game.dont_pass_bet * CRAPS_MAX_ODDS_WIN, CasinoError::CrapsOddsTooLarge);
+        }
+        CrapsBet::ComeOdds => {
+            let index = craps_point_index(number).ok_or(CasinoError::InvalidCrapsBet)?;
+            require!(game.come_bets[index] > 0, CasinoError::InvalidCrapsBet);
+            game.come_odds[index] += amount;
+            require!(craps_take_odds_win(number, game.come_odds[index]) <= game.come_bets[index] * CRAPS_MAX_ODDS_WIN, CasinoError::CrapsOddsTooLarge);
+        }
+    }
+
+    // Escrow the stake in the vault until the bet is settled
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_volume += amount;
+    ctx.accounts.user_stats.total_bets += amount;
+    Ok(())
+}
+
+/// Rolls the dice for the table. Each roll is its own computation; bets are locked until the
+/// callback settles it.
+pub fn roll_craps(ctx: Context<RollCraps>, computation_offset: u64, _game_id: u64, nonce: u128) -> Result<()> {
+    let game = &mut ctx.accounts.craps_game;
+    require!(!game.rolling, CasinoError::InvalidGameState);
+    require!(craps_table_total(game) > 0, CasinoError::InvalidBetAmount);
+    game.rolling = true;
+
+    let args = vec![Argument::PlaintextU128(nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RollTwoDiceCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.craps_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+/// Closes an empty table and returns its rent.
+pub fn close_craps_game(ctx: Context<CloseCrapsGame>, _game_id: u64) -> Result<()> {
+    let game = &ctx.accounts.craps_game;
+    require!(!game.rolling && craps_table_total(game) == 0, CasinoError::InvalidGameState);
+    Ok(())
+}
+
+#[event]
+pub struct CrapsRollEvent { pub game_id: u64, pub dice: [u8; 2], pub point: u8, pub payout: u64 }
+
+pub fn roll_two_dice_callback(
+    ctx: Context<RollTwoDiceCallback>,
+    output: ComputationOutputs<RollTwoDiceOutput>,
+) -> Result<()> {
+    let (first, second) = match output {
+        ComputationOutputs::Success(RollTwoDiceOutput { field_0: RollTwoDiceOutputStruct0 { field_0, field_1 } }) => (field_0, field_1),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.craps_game;
+    require!(game.rolling, CasinoError::InvalidGameState);
+    let (payout, won, lost) = settle_craps_roll(game, first + second);
+    game.rolling = false;
+    game.last_dice = [first, second];
+    game.rolls += 1;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if won > 0 {
+        ctx.accounts.user_stats.total_wins += payout - won;
+        ctx.accounts.user_stats.loyalty_points += won / 100;
+    }
+    ctx.accounts.user_stats.total_losses += lost;
+
+    emit!(CrapsRollEvent { game_id: ctx.accounts.craps_game.game_id, dice: [first, second], point: ctx.accounts.craps_game.point, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[init_computation_definition_accounts("roll_two_dice", payer)]
+#[derive(Accounts)]
+pub struct InitRollTwoDiceCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct OpenCrapsGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = CrapsGame::DISCRIMINATOR.len() + CrapsGame::INIT_SPACE,
+        seeds = [b"craps_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub craps_game: Box<Account<'info, CrapsGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_game_id: u64)]
+pub struct PlaceCrapsBet<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"craps_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = craps_game.bump,
+    )]
+    pub craps_game: Box<Account<'info, CrapsGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[queue_computation_accounts("roll_two_dice", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct RollCraps<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROLL_TWO_DICE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        mut,
+        seeds = [b"craps_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = craps_game.bump,
+    )]
+    pub craps_game: Box<Account<'info, CrapsGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("roll_two_dice")]
+#[derive(Accounts)]
+pub struct RollTwoDiceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROLL_TWO_DICE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub craps_game: Box<Account<'info, CrapsGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == craps_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", craps_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[derive(Accounts)]
+#[instruction(_game_id: u64)]
+pub struct CloseCrapsGame<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"craps_game".as_ref(), player.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = craps_game.bump,
+    )]
+    pub craps_game: Box<Account<'info, CrapsGame>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
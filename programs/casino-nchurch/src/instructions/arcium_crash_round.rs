@@ -0,0 +1,423 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_aviator_payout, crash_round_multiplier};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_START_CRASH_ROUND;
+use crate::COMP_DEF_OFFSET_CHECK_CRASH_ROUND;
+
+pub fn init_start_crash_round_comp_def(ctx: Context<InitStartCrashRoundCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_check_crash_round_comp_def(ctx: Context<InitCheckCrashRoundCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Opens a shared round. The crash point is committed in MPC before any bet is taken;
+/// the betting window starts once it lands.
+pub fn create_crash_round(
+    ctx: Context<CreateCrashRound>,
+    computation_offset: u64,
+    round_id: u64,
+    betting_slots: u64,
+    mxe_nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(
+        (CRASH_ROUND_MIN_BETTING_SLOTS..=CRASH_ROUND_MAX_BETTING_SLOTS).contains(&betting_slots),
+        CasinoError::InvalidGameState
+    );
+
+    let round = &mut ctx.accounts.crash_round;
+    round.crash_ciphertext = [0; 32];
+    round.crash_nonce = 0;
+    round.round_id = round_id;
+    round.creator = ctx.accounts.payer.key();
+    round.round_state = CrashRoundState::Committing;
+    round.betting_slots = betting_slots;
+    round.start_slot = 0;
+    round.crash_multiplier = 0;
+    round.bets = Vec::new();
+    round.settled_count = 0;
+    round.bump = ctx.bumps.crash_round;
+
+    let args = vec![
+        Argument::PlaintextU128(mxe_nonce),
+        Argument::PlaintextU16(ctx.accounts.casino_state.house_edge_config.aviator_rtp_bps),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![StartCrashRoundCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.crash_round.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+pub fn join_crash_round(ctx: Context<JoinCrashRound>, _round_id: u64, bet_amount: u64) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    let round = &ctx.accounts.crash_round;
+    require!(round.round_state == CrashRoundState::Open, CasinoError::InvalidGameState);
+    require!(Clock::get()?.slot < round.start_slot, CasinoError::InvalidGameState);
+    require!(round.bets.len() < CRASH_ROUND_MAX_PLAYERS, CasinoError::CrashRoundFull);
+    let player = ctx.accounts.payer.key();
+    require!(round.bets.iter().all(|b| b.player != player), CasinoError::SeatNotAvailable);
+
+    // Escrow the stake in the vault until the round is settled
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = player;
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let round = &mut ctx.accounts.crash_round;
+    round.bets.push(CrashBet { player, bet_amount, cashout_slot: 0, payout: 0, settled: false });
+
+    emit!(CrashRoundJoinedEvent { round_id: round.round_id, player, bet_amount });
+    Ok(())
+}
+
+/// Locks in the current multiplier. Whether it beat the crash is only known at settlement,
+/// so a cash-out landing after the (still hidden) crash slot simply loses.
+pub fn cash_out(ctx: Context<CashOut>, _round_id: u64) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let player = ctx.accounts.payer.key();
+    let round = &mut ctx.accounts.crash_round;
+    require!(round.round_state == CrashRoundState::Open, CasinoError::InvalidGameState);
+    require!(now > round.start_slot, CasinoError::InvalidGameState);
+    let bet = round.bets.iter_mut().find(|b| b.player == player).ok_or(CasinoError::GameNotFound)?;
+    require!(bet.cashout_slot == 0, CasinoError::AlreadyCashedOut);
+    bet.cashout_slot = now;
+
+    emit!(CrashCashOutEvent { round_id: round.round_id, player, slot: now, multiplier: crash_round_multiplier(round.start_slot, now) });
+    Ok(())
+}
+
+/// Permissionless tick: asks MPC whether the live multiplier has reached the crash point.
+/// Only the fact that the round is still flying is revealed until it has.
+pub fn check_crash_round(ctx: Context<CheckCrashRound>, computation_offset: u64, _round_id: u64) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let round = &ctx.accounts.crash_round;
+    require!(round.round_state == CrashRoundState::Open, CasinoError::InvalidGameState);
+    require!(now > round.start_slot, CasinoError::InvalidGameState);
+    let multiplier = crash_round_multiplier(round.start_slot, now);
+
+    let args = vec![
+        Argument::PlaintextU128(round.crash_nonce),
+        Argument::Account(round.key(), 8, 32),
+        Argument::PlaintextU64(multiplier),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![CheckCrashRoundCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.crash_round.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+/// Settlement crank: pays one participant according to their cash-out slot. Anyone may call it.
+pub fn settle_crash_bet(ctx: Context<SettleCrashBet>, _round_id: u64, bet_index: u8) -> Result<()> {
+    let round = &ctx.accounts.crash_round;
+    require!(round.round_state == CrashRoundState::Crashed, CasinoError::InvalidGameState);
+    let bet = *round.bets.get(bet_index as usize).ok_or(CasinoError::GameNotFound)?;
+    require!(!bet.settled, CasinoError::GameAlreadyCompleted);
+    require_keys_eq!(ctx.accounts.user_token_account.owner, bet.player, ErrorCode::NotAuthorized);
+    require_keys_eq!(ctx.accounts.user_stats.user, bet.player, ErrorCode::NotAuthorized);
+
+    let payout = if bet.cashout_slot == 0 {
+        0
+    } else {
+        calculate_aviator_payout(crash_round_multiplier(round.start_slot, bet.cashout_slot), round.crash_multiplier, bet.bet_amount)
+    };
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+        ctx.accounts.user_stats.total_wins += payout - bet.bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet.bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet.bet_amount;
+    }
+
+    let round = &mut ctx.accounts.crash_round;
+    let entry = &mut round.bets[bet_index as usize];
+    entry.payout = payout;
+    entry.settled = true;
+    round.settled_count += 1;
+
+    emit!(CrashBetSettledEvent { round_id: round.round_id, player: bet.player, cashout_slot: bet.cashout_slot, payout });
+    Ok(())
+}
+
+#[event]
+pub struct CrashRoundOpenedEvent { pub round_id: u64, pub start_slot: u64 }
+#[event]
+pub struct CrashRoundJoinedEvent { pub round_id: u64, pub player: Pubkey, pub bet_amount: u64 }
+#[event]
+pub struct CrashCashOutEvent { pub round_id: u64, pub player: Pubkey, pub slot: u64, pub multiplier: u64 }
+#[event]
+pub struct CrashRoundFlyingEvent { pub round_id: u64 }
+#[event]
+pub struct CrashRoundCrashedEvent { pub round_id: u64, pub crash_multiplier: u64 }
+#[event]
+pub struct CrashBetSettledEvent { pub round_id: u64, pub player: Pubkey, pub cashout_slot: u64, pub payout: u64 }
+
+pub fn start_crash_round_callback(
+    ctx: Context<StartCrashRoundCallback>,
+    output: ComputationOutputs<StartCrashRoundOutput>,
+) -> Result<()> {
+    let crash_ct = match output {
+        ComputationOutputs::Success(StartCrashRoundOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let round = &mut ctx.accounts.crash_round;
+    require!(round.round_state == CrashRoundState::Committing, CasinoError::InvalidGameState);
+    round.crash_ciphertext = crash_ct.ciphertexts[0];
+    round.crash_nonce = crash_ct.nonce;
+    round.start_slot = Clock::get()?.slot + round.betting_slots;
+    round.round_state = CrashRoundState::Open;
+
+    emit!(CrashRoundOpenedEvent { round_id: round.round_id, start_slot: round.start_slot });
+    Ok(())
+}
+
+pub fn check_crash_round_callback(
+    ctx: Context<CheckCrashRoundCallback>,
+    output: ComputationOutputs<CheckCrashRoundOutput>,
+) -> Result<()> {
+    let crash_multiplier = match output {
+        ComputationOutputs::Success(CheckCrashRoundOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let round = &mut ctx.accounts.crash_round;
+    // Overlapping ticks are harmless: only the first reveal closes the round
+    if round.round_state != CrashRoundState::Open {
+        return Ok(());
+    }
+    if crash_multiplier == 0 {
+        emit!(CrashRoundFlyingEvent { round_id: round.round_id });
+        return Ok(());
+    }
+    round.crash_multiplier = crash_multiplier;
+    round.round_state = CrashRoundState::Crashed;
+
+    emit!(CrashRoundCrashedEvent { round_id: round.round_id, crash_multiplier });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[init_computation_definition_accounts("start_crash_round", payer)]
+#[derive(Accounts)]
+pub struct InitStartCrashRoundCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("check_crash_round", payer)]
+#[derive(Accounts)]
+pub struct InitCheckCrashRoundCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("start_crash_round", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, round_id: u64)]
+pub struct CreateCrashRound<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_START_CRASH_ROUND))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CrashRound::DISCRIMINATOR.len() + CrashRound::INIT_SPACE,
+        seeds = [b"crash_round".as_ref(), round_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub crash_round: Box<Account<'info, CrashRound>>,
+    #[account(seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+}
+
+#[callback_accounts("start_crash_round")]
+#[derive(Accounts)]
+pub struct StartCrashRoundCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_START_CRASH_ROUND))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub crash_round: Box<Account<'info, CrashRound>>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct JoinCrashRound<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"crash_round".as_ref(), round_id.to_le_bytes().as_ref()], bump = crash_round.bump)]
+    pub crash_round: Box<Account<'info, CrashRound>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct CashOut<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"crash_round".as_ref(), round_id.to_le_bytes().as_ref()], bump = crash_round.bump)]
+    pub crash_round: Box<Account<'info, CrashRound>>,
+}
+
+#[queue_computation_accounts("check_crash_round", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, round_id: u64)]
+pub struct CheckCrashRound<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_CRASH_ROUND))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(seeds = [b"crash_round".as_ref(), round_id.to_le_bytes().as_ref()], bump = crash_round.bump)]
+    pub crash_round: Box<Account<'info, CrashRound>>,
+}
+
+#[callback_accounts("check_crash_round")]
+#[derive(Accounts)]
+pub struct CheckCrashRoundCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_CRASH_ROUND))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub crash_round: Box<Account<'info, CrashRound>>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct SettleCrashBet<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"crash_round".as_ref(), round_id.to_le_bytes().as_ref()], bump = crash_round.bump)]
+    pub crash_round: Box<Account<'info, CrashRound>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", user_stats.user.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
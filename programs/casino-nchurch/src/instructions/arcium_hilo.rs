@@ -0,0 +1,424 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{hilo_rung_value, hilo_winning_cards, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_SHUFFLE_HILO_DECK;
+use crate::COMP_DEF_OFFSET_DRAW_HILO_CARD;
+
+pub fn init_shuffle_hilo_deck_comp_def(ctx: Context<InitShuffleHiloDeckCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_draw_hilo_card_comp_def(ctx: Context<InitDrawHiloCardCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn set_hilo_config(ctx: Context<SetHiloConfig>, rtp_bps: u16) -> Result<()> {
+    validate_rtp_config(rtp_bps)?;
+
+    let config = &mut ctx.accounts.hilo_config;
+    config.authority = ctx.accounts.authority.key();
+    config.rtp_bps = rtp_bps;
+    config.bump = ctx.bumps.hilo_config;
+    Ok(())
+}
+
+/// Starts a Hi-Lo ladder. MPC shuffles a single deck, keeps it encrypted on the game and
+/// shows the first card.
+pub fn start_hilo(
+    ctx: Context<StartHilo>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    mxe_nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+
+    // Escrow the stake in the vault until the ladder ends
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.hilo_game;
+    game.deck = [[0; 32]; 3];
+    game.deck_nonce = 0;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bet_amount = bet_amount;
+    game.ladder_value = bet_amount;
+    game.current_card = 0;
+    game.cards_drawn = 0;
+    game.rank_counts = [4; 13];
+    game.rungs = 0;
+    game.pending_guess = HiloGuess::Higher;
+    game.game_state = HiloGameState::Shuffling;
+    game.payout = 0;
+    game.bump = ctx.bumps.hilo_game;
+
+    let args = vec![Argument::PlaintextU128(mxe_nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ShuffleHiloDeckCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.hilo_game.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+/// Commits a higher/lower guess and draws the next card. Guesses that no unseen card can
+/// win are refused, so every rung pays a finite multiplier.
+pub fn guess_hilo(ctx: Context<GuessHilo>, computation_offset: u64, _game_id: u64, guess: HiloGuess) -> Result<()> {
+    let game = &ctx.accounts.hilo_game;
+    require!(game.game_state == HiloGameState::Playing, CasinoError::InvalidGameState);
+    require!(game.cards_drawn < 52, CasinoError::InvalidMove);
+    require!(
+        (game.ladder_value as u128) < game.bet_amount as u128 * HILO_MAX_MULTIPLIER as u128,
+        CasinoError::HiloLadderMaxed
+    );
+    require!(hilo_winning_cards(&game.rank_counts, game.current_card % 13, guess) > 0, CasinoError::InvalidMove);
+
+    let args = vec![
+        Argument::PlaintextU128(game.deck_nonce),
+        Argument::Account(game.key(), 8, 32 * 3),
+        Argument::PlaintextU8(game.cards_drawn),
+    ];
+
+    let game = &mut ctx.accounts.hilo_game;
+    game.pending_guess = guess;
+    game.game_state = HiloGameState::Drawing;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DrawHiloCardCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.hilo_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+/// Cashes out the ladder after at least one correct guess, paying its value scaled by RTP.
+pub fn cash_out_hilo(ctx: Context<CashOutHilo>, _game_id: u64) -> Result<()> {
+    let game = &ctx.accounts.hilo_game;
+    require!(game.game_state == HiloGameState::Playing, CasinoError::InvalidGameState);
+    require!(game.rungs > 0, CasinoError::InvalidMove);
+    let bet_amount = game.bet_amount;
+    let payout = (game.ladder_value as u128 * ctx.accounts.hilo_config.rtp_bps as u128 / 10000) as u64;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    let game = &mut ctx.accounts.hilo_game;
+    game.payout = payout;
+    game.game_state = HiloGameState::CashedOut;
+
+    emit!(HiloCashedOutEvent { game_id: game.game_id, rungs: game.rungs, payout });
+    Ok(())
+}
+
+#[event]
+pub struct HiloStartedEvent { pub game_id: u64, pub player: Pubkey, pub first_card: u8 }
+#[event]
+pub struct HiloCardEvent { pub game_id: u64, pub card: u8, pub guess: HiloGuess, pub correct: bool, pub ladder_value: u64 }
+#[event]
+pub struct HiloCashedOutEvent { pub game_id: u64, pub rungs: u8, pub payout: u64 }
+
+pub fn shuffle_hilo_deck_callback(
+    ctx: Context<ShuffleHiloDeckCallback>,
+    output: ComputationOutputs<ShuffleHiloDeckOutput>,
+) -> Result<()> {
+    let (deck, first_card) = match output {
+        ComputationOutputs::Success(ShuffleHiloDeckOutput { field_0: ShuffleHiloDeckOutputStruct0 { field_0: deck, field_1: first_card } }) => (deck, first_card),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.hilo_game;
+    game.deck = deck.ciphertexts;
+    game.deck_nonce = deck.nonce;
+    game.current_card = first_card;
+    game.cards_drawn = 1;
+    game.rank_counts[(first_card % 13) as usize] -= 1;
+    game.game_state = HiloGameState::Playing;
+
+    emit!(HiloStartedEvent { game_id: game.game_id, player: game.player, first_card });
+    Ok(())
+}
+
+pub fn draw_hilo_card_callback(
+    ctx: Context<DrawHiloCardCallback>,
+    output: ComputationOutputs<DrawHiloCardOutput>,
+) -> Result<()> {
+    let card = match output {
+        ComputationOutputs::Success(DrawHiloCardOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.hilo_game;
+    require!(game.game_state == HiloGameState::Drawing, CasinoError::InvalidGameState);
+    // Odds are taken against the deck as it stood when the guess was made
+    let guess = game.pending_guess;
+    let remaining = 52 - game.cards_drawn;
+    let winners = hilo_winning_cards(&game.rank_counts, game.current_card % 13, guess);
+    let correct = match guess {
+        HiloGuess::Higher => card % 13 > game.current_card % 13,
+        HiloGuess::Lower => card % 13 < game.current_card % 13,
+    };
+
+    game.current_card = card;
+    game.cards_drawn += 1;
+    game.rank_counts[(card % 13) as usize] -= 1;
+    if correct {
+        game.ladder_value = hilo_rung_value(game.ladder_value, remaining, winners);
+        game.rungs += 1;
+        game.game_state = HiloGameState::Playing;
+    } else {
+        game.game_state = HiloGameState::Lost;
+        ctx.accounts.user_stats.total_losses += game.bet_amount;
+    }
+
+    emit!(HiloCardEvent { game_id: game.game_id, card, guess, correct, ladder_value: game.ladder_value });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[init_computation_definition_accounts("shuffle_hilo_deck", payer)]
+#[derive(Accounts)]
+pub struct InitShuffleHiloDeckCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("draw_hilo_card", payer)]
+#[derive(Accounts)]
+pub struct InitDrawHiloCardCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetHiloConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = HiloConfig::DISCRIMINATOR.len() + HiloConfig::INIT_SPACE,
+        seeds = [b"hilo_config"],
+        bump
+    )]
+    pub hilo_config: Box<Account<'info, HiloConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("shuffle_hilo_deck", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct StartHilo<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SHUFFLE_HILO_DECK))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = HiloGame::DISCRIMINATOR.len() + HiloGame::INIT_SPACE,
+        seeds = [b"hilo_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub hilo_game: Box<Account<'info, HiloGame>>,
+    // Must exist before a game starts, or the ladder could never be cashed out
+    #[account(seeds = [b"hilo_config"], bump = hilo_config.bump)]
+    pub hilo_config: Box<Account<'info, HiloConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("shuffle_hilo_deck")]
+#[derive(Accounts)]
+pub struct ShuffleHiloDeckCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SHUFFLE_HILO_DECK))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub hilo_game: Box<Account<'info, HiloGame>>,
+}
+
+#[queue_computation_accounts("draw_hilo_card", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct GuessHilo<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_HILO_CARD))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"hilo_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = hilo_game.bump,
+    )]
+    pub hilo_game: Box<Account<'info, HiloGame>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[callback_accounts("draw_hilo_card")]
+#[derive(Accounts)]
+pub struct DrawHiloCardCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_HILO_CARD))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub hilo_game: Box<Account<'info, HiloGame>>,
+    #[account(mut, seeds = [b"user_stats", hilo_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[derive(Accounts)]
+#[instruction(_game_id: u64)]
+pub struct CashOutHilo<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"hilo_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = hilo_game.bump,
+    )]
+    pub hilo_game: Box<Account<'info, HiloGame>>,
+    #[account(seeds = [b"hilo_config"], bump = hilo_config.bump)]
+    pub hilo_config: Box<Account<'info, HiloConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
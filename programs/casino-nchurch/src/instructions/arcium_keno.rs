@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_keno_payout, calculate_keno_rtp_bps, count_keno_hits, keno_drawn_numbers, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_DRAW_KENO;
+
+pub fn init_draw_keno_comp_def(ctx: Context<InitDrawKenoCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+#[event]
+pub struct KenoPaytableSetEvent { pub pick_count: u8, pub multipliers_bps: [u32; 11], pub rtp_bps: u16 }
+
+/// Sets the paytable row for one pick count. The row's exact return over every possible
+/// draw is computed here and must sit inside the casino's RTP bounds; an all-zero row
+/// disables that pick count.
+pub fn set_keno_paytable(ctx: Context<SetKenoPaytable>, pick_count: u8, multipliers_bps: [u32; 11]) -> Result<()> {
+    require!(pick_count >= 1 && pick_count as usize <= KENO_MAX_PICKS, CasinoError::InvalidKenoPicks);
+    // Hit counts above the pick count cannot happen and must not carry a prize
+    for (hits, &multiplier) in multipliers_bps.iter().enumerate() {
+        require!(multiplier <= KENO_MAX_MULTIPLIER_BPS, CasinoError::InvalidPaytable);
+        require!(hits <= pick_count as usize || multiplier == 0, CasinoError::InvalidPaytable);
+    }
+
+    let rtp_bps = calculate_keno_rtp_bps(pick_count, &multipliers_bps);
+    if rtp_bps > 0 {
+        require!(rtp_bps <= 10000, CasinoError::InvalidPaytable);
+        validate_rtp_config(rtp_bps as u16)?;
+    }
+
+    let config = &mut ctx.accounts.keno_config;
+    config.authority = ctx.accounts.authority.key();
+    config.paytable[pick_count as usize - 1] = multipliers_bps;
+    config.rtp_bps[pick_count as usize - 1] = rtp_bps as u16;
+    config.bump = ctx.bumps.keno_config;
+
+    emit!(KenoPaytableSetEvent { pick_count, multipliers_bps, rtp_bps: rtp_bps as u16 });
+    Ok(())
+}
+
+/// Places a keno ticket on 1-10 distinct numbers from 1 to 80. The circuit shuffles all 80
+/// numbers and reveals only the 20 drawn; hits are counted against the stored picks.
+pub fn play_keno(
+    ctx: Context<PlayKeno>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    picks: Vec<u8>,
+    nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    require!(!picks.is_empty() && picks.len() <= KENO_MAX_PICKS, CasinoError::InvalidKenoPicks);
+    for (i, &number) in picks.iter().enumerate() {
+        require!(number >= 1 && number <= KENO_NUMBERS, CasinoError::InvalidKenoPicks);
+        require!(!picks[..i].contains(&number), CasinoError::InvalidKenoPicks);
+    }
+    let pick_count = picks.len() as u8;
+    require!(ctx.accounts.keno_config.rtp_bps[picks.len() - 1] > 0, CasinoError::InvalidPaytable);
+
+    // Escrow the stake in the vault until the draw is revealed
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.keno_game;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bet_amount = bet_amount;
+    game.picks = [0; KENO_MAX_PICKS];
+    game.picks[..picks.len()].copy_from_slice(&picks);
+    game.pick_count = pick_count;
+    game.multipliers_bps = ctx.accounts.keno_config.paytable[picks.len() - 1];
+    game.drawn = [0; KENO_DRAWN];
+    game.hits = 0;
+    game.payout = 0;
+    game.is_complete = false;
+    game.bump = ctx.bumps.keno_game;
+
+    let args = vec![Argument::PlaintextU128(nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DrawKenoCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.keno_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct KenoResultEvent { pub game_id: u64, pub picks: [u8; 10], pub pick_count: u8, pub drawn: [u8; 20], pub hits: u8, pub payout: u64 }
+
+pub fn draw_keno_callback(
+    ctx: Context<DrawKenoCallback>,
+    output: ComputationOutputs<DrawKenoOutput>,
+) -> Result<()> {
+    let drawn_mask = match output {
+        ComputationOutputs::Success(DrawKenoOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.keno_game;
+    require!(!game.is_complete, CasinoError::GameAlreadyCompleted);
+    let bet_amount = game.bet_amount;
+    let hits = count_keno_hits(&game.picks[..game.pick_count as usize], drawn_mask);
+    let payout = calculate_keno_payout(bet_amount, game.multipliers_bps[hits as usize]);
+    game.drawn = keno_drawn_numbers(drawn_mask);
+    game.hits = hits;
+    game.payout = payout;
+    game.is_complete = true;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    let game = &ctx.accounts.keno_game;
+    emit!(KenoResultEvent { game_id: game.game_id, picks: game.picks, pick_count: game.pick_count, drawn: game.drawn, hits, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetKenoPaytable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = KenoConfig::DISCRIMINATOR.len() + KenoConfig::INIT_SPACE,
+        seeds = [b"keno_config"],
+        bump
+    )]
+    pub keno_config: Box<Account<'info, KenoConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("draw_keno", payer)]
+#[derive(Accounts)]
+pub struct InitDrawKenoCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("draw_keno", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct PlayKeno<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_KENO))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = KenoGame::DISCRIMINATOR.len() + KenoGame::INIT_SPACE,
+        seeds = [b"keno_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub keno_game: Box<Account<'info, KenoGame>>,
+    #[account(seeds = [b"keno_config"], bump = keno_config.bump)]
+    pub keno_config: Box<Account<'info, KenoConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("draw_keno")]
+#[derive(Accounts)]
+pub struct DrawKenoCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_KENO))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub keno_game: Box<Account<'info, KenoGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == keno_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", keno_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,502 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_lottery_tier_prizes, lottery_matches, lottery_quick_pick, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_DRAW_LOTTERY;
+
+pub fn init_draw_lottery_comp_def(ctx: Context<InitDrawLotteryCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Sets the prize pool's share of each ticket. Rounds already open keep the share they
+/// opened with.
+pub fn set_lottery_config(ctx: Context<SetLotteryConfig>, rtp_bps: u16) -> Result<()> {
+    validate_rtp_config(rtp_bps)?;
+
+    let config = &mut ctx.accounts.lottery_config;
+    config.authority = ctx.accounts.authority.key();
+    config.rtp_bps = rtp_bps;
+    config.bump = ctx.bumps.lottery_config;
+    Ok(())
+}
+
+/// Opens a round with its own prize vault. `tier_shares_bps[m]` is the share of the prize
+/// pool split among tickets matching `m` numbers; whatever no tier pays out rolls over.
+pub fn create_lottery_round(
+    ctx: Context<CreateLotteryRound>,
+    round_id: u64,
+    ticket_price: u64,
+    pick_count: u8,
+    max_number: u8,
+    sales_end: i64,
+    tier_shares_bps: [u16; 7],
+) -> Result<()> {
+    require!(ticket_price > 0, CasinoError::InvalidLotteryConfig);
+    require!((LOTTERY_MIN_PICKS..=LOTTERY_MAX_PICKS).contains(&pick_count), CasinoError::InvalidLotteryConfig);
+    require!(max_number > pick_count && max_number <= LOTTERY_MAX_NUMBER, CasinoError::InvalidLotteryConfig);
+    require!(sales_end > Clock::get()?.unix_timestamp, CasinoError::InvalidLotteryConfig);
+    // No prize for matching nothing, nor for more matches than there are numbers
+    require!(tier_shares_bps[0] == 0, CasinoError::InvalidLotteryConfig);
+    require!(tier_shares_bps[pick_count as usize + 1..].iter().all(|&share| share == 0), CasinoError::InvalidLotteryConfig);
+    let total_share: u32 = tier_shares_bps.iter().map(|&share| share as u32).sum();
+    require!(total_share > 0 && total_share <= 10000, CasinoError::InvalidLotteryConfig);
+
+    let round = &mut ctx.accounts.lottery_round;
+    round.round_id = round_id;
+    round.authority = ctx.accounts.authority.key();
+    round.ticket_price = ticket_price;
+    round.pick_count = pick_count;
+    round.max_number = max_number;
+    round.sales_end = sales_end;
+    round.tier_shares_bps = tier_shares_bps;
+    round.prize_share_bps = ctx.accounts.lottery_config.rtp_bps;
+    round.round_state = LotteryRoundState::Open;
+    round.draw_queued_at = 0;
+    round.prize_pool = 0;
+    round.rollover_in = 0;
+    round.winning_mask = 0;
+    round.winners = [0; 7];
+    round.tier_prizes = [0; 7];
+    round.claimed_total = 0;
+    round.claim_deadline = 0;
+    round.ticket_masks = Vec::new();
+    round.bump = ctx.bumps.lottery_round;
+
+    emit!(LotteryRoundOpenedEvent { round_id, ticket_price, pick_count, max_number, sales_end });
+    Ok(())
+}
+
+/// Buys the next ticket of a round. `numbers` are `pick_count` distinct numbers from
+/// `1..=max_number`; an empty list quick-picks them. The prize share of the price goes to the
+/// round vault and the house share to the casino vault.
+pub fn buy_lottery_ticket(ctx: Context<BuyLotteryTicket>, _round_id: u64, ticket_index: u32, numbers: Vec<u8>) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    let round = &ctx.accounts.lottery_round;
+    require!(round.round_state == LotteryRoundState::Open, CasinoError::InvalidGameState);
+    require!(Clock::get()?.unix_timestamp < round.sales_end, CasinoError::InvalidGameState);
+    require!(round.ticket_masks.len() < LOTTERY_MAX_TICKETS, CasinoError::LotteryRoundFull);
+    require!(ticket_index as usize == round.ticket_masks.len(), CasinoError::InvalidGameState);
+
+    let player = ctx.accounts.payer.key();
+    let quick_pick = numbers.is_empty();
+    let numbers_mask = if quick_pick {
+        let seed = [
+            round.key().as_ref(),
+            player.as_ref(),
+            &ticket_index.to_le_bytes(),
+            &Clock::get()?.slot.to_le_bytes(),
+        ]
+        .concat();
+        lottery_quick_pick(&seed, round.pick_count, round.max_number)
+    } else {
+        require!(numbers.len() == round.pick_count as usize, CasinoError::InvalidLotteryNumbers);
+        let mut mask = 0u64;
+        for &number in &numbers {
+            require!(number >= 1 && number <= round.max_number, CasinoError::InvalidLotteryNumbers);
+            require!(mask >> (number - 1) & 1 == 0, CasinoError::InvalidLotteryNumbers);
+            mask |= 1 << (number - 1);
+        }
+        mask
+    };
+
+    let ticket_price = round.ticket_price;
+    let prize_share = (ticket_price as u128 * round.prize_share_bps as u128 / 10000) as u64;
+    let house_share = ticket_price - prize_share;
+
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.lottery_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, prize_share, ctx.accounts.usdc_mint.decimals)?;
+    if house_share > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            to: ctx.accounts.casino_vault.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+        transfer_checked(cpi_ctx, house_share, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += ticket_price;
+    ctx.accounts.user_stats.user = player;
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += ticket_price;
+    ctx.accounts.user_stats.games_played += 1;
+    // The ticket is booked as a loss now; a claimed prize is booked as a win
+    ctx.accounts.user_stats.total_losses += ticket_price;
+
+    let round = &mut ctx.accounts.lottery_round;
+    round.prize_pool += prize_share;
+    round.ticket_masks.push(numbers_mask);
+
+    let ticket = &mut ctx.accounts.lottery_ticket;
+    ticket.round_id = round.round_id;
+    ticket.ticket_index = ticket_index;
+    ticket.owner = player;
+    ticket.numbers_mask = numbers_mask;
+    ticket.quick_pick = quick_pick;
+    ticket.claimed = false;
+    ticket.prize = 0;
+    ticket.bump = ctx.bumps.lottery_ticket;
+
+    emit!(LotteryTicketBoughtEvent { round_id: round.round_id, ticket_index, owner: player, numbers_mask, quick_pick });
+    Ok(())
+}
+
+/// Permissionless crank once sales have closed: queues the encrypted draw. A draw that was
+/// aborted or never came back can be queued again after `LOTTERY_REDRAW_TIMEOUT_SECS`;
+/// whichever draw lands first settles the round.
+pub fn draw_lottery(ctx: Context<DrawLottery>, computation_offset: u64, _round_id: u64, mxe_nonce: u128) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let round = &ctx.accounts.lottery_round;
+    match round.round_state {
+        LotteryRoundState::Open => require!(now >= round.sales_end, CasinoError::InvalidGameState),
+        LotteryRoundState::Drawing => require!(
+            now >= round.draw_queued_at + LOTTERY_REDRAW_TIMEOUT_SECS,
+            CasinoError::GameNotTimedOut
+        ),
+        _ => return Err(CasinoError::InvalidGameState.into()),
+    }
+
+    let args = vec![
+        Argument::PlaintextU128(mxe_nonce),
+        Argument::PlaintextU8(round.pick_count),
+        Argument::PlaintextU8(round.max_number),
+    ];
+
+    ctx.accounts.lottery_round.round_state = LotteryRoundState::Drawing;
+    ctx.accounts.lottery_round.draw_queued_at = now;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DrawLotteryCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.lottery_round.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+/// Pays a winning ticket its tier prize from the round vault. Only the ticket owner can
+/// claim, and only until the claim deadline.
+pub fn claim_lottery_prize(ctx: Context<ClaimLotteryPrize>, round_id: u64, _ticket_index: u32) -> Result<()> {
+    let round = &ctx.accounts.lottery_round;
+    require!(round.round_state == LotteryRoundState::Drawn, CasinoError::InvalidGameState);
+    require!(Clock::get()?.unix_timestamp <= round.claim_deadline, CasinoError::InvalidGameState);
+    let ticket = &ctx.accounts.lottery_ticket;
+    require!(!ticket.claimed, CasinoError::GameAlreadyCompleted);
+    let matches = lottery_matches(ticket.numbers_mask, round.winning_mask);
+    let prize = round.tier_prizes[matches as usize];
+    require!(prize > 0, CasinoError::LotteryTicketNotWinning);
+
+    let round_id_bytes = round_id.to_le_bytes();
+    let seeds: &[&[&[u8]]] = &[&[b"lottery_round", round_id_bytes.as_ref(), &[round.bump]]];
+    let ix = TransferChecked {
+        from: ctx.accounts.lottery_vault.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.lottery_round.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+    transfer_checked(cpi_ctx, prize, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_payouts += prize;
+    ctx.accounts.user_stats.total_wins += prize;
+    ctx.accounts.user_stats.loyalty_points += ctx.accounts.lottery_round.ticket_price / 100;
+    ctx.accounts.lottery_round.claimed_total += prize;
+    let ticket = &mut ctx.accounts.lottery_ticket;
+    ticket.claimed = true;
+    ticket.prize = prize;
+
+    emit!(LotteryPrizeClaimedEvent { round_id, ticket_index: ticket.ticket_index, owner: ticket.owner, matches, prize });
+    Ok(())
+}
+
+/// Permissionless crank: once a drawn round's claim window has passed (or it sold no
+/// tickets), moves everything left in its vault into the prize pool of an open round.
+pub fn rollover_lottery_round(ctx: Context<RolloverLotteryRound>, from_round_id: u64, to_round_id: u64) -> Result<()> {
+    require!(from_round_id != to_round_id, CasinoError::InvalidGameState);
+    let now = Clock::get()?.unix_timestamp;
+    let from = &ctx.accounts.from_round;
+    require!(from.round_state == LotteryRoundState::Drawn, CasinoError::InvalidGameState);
+    require!(from.ticket_masks.is_empty() || now > from.claim_deadline, CasinoError::InvalidGameState);
+    let to = &ctx.accounts.to_round;
+    require!(to.round_state == LotteryRoundState::Open && now < to.sales_end, CasinoError::InvalidGameState);
+
+    let amount = ctx.accounts.from_vault.amount;
+    if amount > 0 {
+        let round_id_bytes = from_round_id.to_le_bytes();
+        let seeds: &[&[&[u8]]] = &[&[b"lottery_round", round_id_bytes.as_ref(), &[from.bump]]];
+        let ix = TransferChecked {
+            from: ctx.accounts.from_vault.to_account_info(),
+            to: ctx.accounts.to_vault.to_account_info(),
+            authority: ctx.accounts.from_round.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    ctx.accounts.from_round.round_state = LotteryRoundState::Closed;
+    let to = &mut ctx.accounts.to_round;
+    to.prize_pool += amount;
+    to.rollover_in += amount;
+
+    emit!(LotteryRolloverEvent { from_round_id, to_round_id, amount });
+    Ok(())
+}
+
+#[event]
+pub struct LotteryRoundOpenedEvent { pub round_id: u64, pub ticket_price: u64, pub pick_count: u8, pub max_number: u8, pub sales_end: i64 }
+#[event]
+pub struct LotteryTicketBoughtEvent { pub round_id: u64, pub ticket_index: u32, pub owner: Pubkey, pub numbers_mask: u64, pub quick_pick: bool }
+#[event]
+pub struct LotteryDrawnEvent { pub round_id: u64, pub winning_mask: u64, pub winners: [u32; 7], pub tier_prizes: [u64; 7], pub claim_deadline: i64 }
+#[event]
+pub struct LotteryPrizeClaimedEvent { pub round_id: u64, pub ticket_index: u32, pub owner: Pubkey, pub matches: u8, pub prize: u64 }
+#[event]
+pub struct LotteryRolloverEvent { pub from_round_id: u64, pub to_round_id: u64, pub amount: u64 }
+
+pub fn draw_lottery_callback(
+    ctx: Context<DrawLotteryCallback>,
+    output: ComputationOutputs<DrawLotteryOutput>,
+) -> Result<()> {
+    let winning_mask = match output {
+        ComputationOutputs::Success(DrawLotteryOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let round = &mut ctx.accounts.lottery_round;
+    require!(round.round_state == LotteryRoundState::Drawing, CasinoError::InvalidGameState);
+    let mut winners = [0u32; 7];
+    for &numbers_mask in &round.ticket_masks {
+        winners[lottery_matches(numbers_mask, winning_mask) as usize] += 1;
+    }
+    round.winning_mask = winning_mask;
+    round.winners = winners;
+    round.tier_prizes = calculate_lottery_tier_prizes(round.prize_pool, &round.tier_shares_bps, &winners);
+    round.claim_deadline = Clock::get()?.unix_timestamp + LOTTERY_CLAIM_WINDOW_SECS;
+    round.round_state = LotteryRoundState::Drawn;
+
+    emit!(LotteryDrawnEvent {
+        round_id: round.round_id,
+        winning_mask,
+        winners,
+        tier_prizes: round.tier_prizes,
+        claim_deadline: round.claim_deadline,
+    });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[init_computation_definition_accounts("draw_lottery", payer)]
+#[derive(Accounts)]
+pub struct InitDrawLotteryCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetLotteryConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = LotteryConfig::DISCRIMINATOR.len() + LotteryConfig::INIT_SPACE,
+        seeds = [b"lottery_config"],
+        bump
+    )]
+    pub lottery_config: Box<Account<'info, LotteryConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct CreateLotteryRound<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(seeds = [b"lottery_config"], bump = lottery_config.bump)]
+    pub lottery_config: Box<Account<'info, LotteryConfig>>,
+    #[account(
+        init,
+        payer = authority,
+        space = LotteryRound::DISCRIMINATOR.len() + LotteryRound::INIT_SPACE,
+        seeds = [b"lottery_round".as_ref(), round_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub lottery_round: Box<Account<'info, LotteryRound>>,
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = lottery_round,
+        token::token_program = token_program,
+        seeds = [b"lottery_vault".as_ref(), round_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub lottery_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64, ticket_index: u32)]
+pub struct BuyLotteryTicket<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"lottery_round".as_ref(), round_id.to_le_bytes().as_ref()], bump = lottery_round.bump)]
+    pub lottery_round: Box<Account<'info, LotteryRound>>,
+    #[account(
+        init,
+        payer = payer,
+        space = LotteryTicket::DISCRIMINATOR.len() + LotteryTicket::INIT_SPACE,
+        seeds = [b"lottery_ticket".as_ref(), round_id.to_le_bytes().as_ref(), ticket_index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub lottery_ticket: Box<Account<'info, LotteryTicket>>,
+    #[account(mut, seeds = [b"lottery_vault".as_ref(), round_id.to_le_bytes().as_ref()], bump)]
+    pub lottery_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("draw_lottery", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, round_id: u64)]
+pub struct DrawLottery<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_LOTTERY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, seeds = [b"lottery_round".as_ref(), round_id.to_le_bytes().as_ref()], bump = lottery_round.bump)]
+    pub lottery_round: Box<Account<'info, LotteryRound>>,
+}
+
+#[callback_accounts("draw_lottery")]
+#[derive(Accounts)]
+pub struct DrawLotteryCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_LOTTERY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub lottery_round: Box<Account<'info, LotteryRound>>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64, ticket_index: u32)]
+pub struct ClaimLotteryPrize<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"lottery_round".as_ref(), round_id.to_le_bytes().as_ref()], bump = lottery_round.bump)]
+    pub lottery_round: Box<Account<'info, LotteryRound>>,
+    #[account(
+        mut,
+        seeds = [b"lottery_ticket".as_ref(), round_id.to_le_bytes().as_ref(), ticket_index.to_le_bytes().as_ref()],
+        bump = lottery_ticket.bump,
+        constraint = lottery_ticket.owner == payer.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub lottery_ticket: Box<Account<'info, LotteryTicket>>,
+    #[account(mut, seeds = [b"lottery_vault".as_ref(), round_id.to_le_bytes().as_ref()], bump)]
+    pub lottery_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(from_round_id: u64, to_round_id: u64)]
+pub struct RolloverLotteryRound<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"lottery_round".as_ref(), from_round_id.to_le_bytes().as_ref()], bump = from_round.bump)]
+    pub from_round: Box<Account<'info, LotteryRound>>,
+    #[account(mut, seeds = [b"lottery_vault".as_ref(), from_round_id.to_le_bytes().as_ref()], bump)]
+    pub from_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"lottery_round".as_ref(), to_round_id.to_le_bytes().as_ref()], bump = to_round.bump)]
+    pub to_round: Box<Account<'info, LotteryRound>>,
+    #[account(mut, seeds = [b"lottery_vault".as_ref(), to_round_id.to_le_bytes().as_ref()], bump)]
+    pub to_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
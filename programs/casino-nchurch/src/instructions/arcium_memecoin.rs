@@ -0,0 +1,397 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_memecoin_payout, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_GENERATE_PRICE_PATH;
+use crate::COMP_DEF_OFFSET_REVEAL_PRICE_STEP;
+
+pub fn init_generate_price_path_comp_def(ctx: Context<InitGeneratePricePathCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_reveal_price_step_comp_def(ctx: Context<InitRevealPriceStepCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn set_memecoin_config(ctx: Context<SetMemecoinConfig>, rtp_bps: u16) -> Result<()> {
+    validate_rtp_config(rtp_bps)?;
+
+    let config = &mut ctx.accounts.memecoin_config;
+    config.authority = ctx.accounts.authority.key();
+    config.rtp_bps = rtp_bps;
+    config.bump = ctx.bumps.memecoin_config;
+    Ok(())
+}
+
+/// Buys into a simulated token. The whole price path is generated up front in MPC and
+/// stays encrypted; `advance_memecoin` reveals it one step at a time.
+pub fn buy_memecoin(
+    ctx: Context<BuyMemecoin>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    mxe_nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+
+    // Escrow the stake in the vault until the player sells or is rugged
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.memecoin_game;
+    game.price_path = [[0; 32]; 2];
+    game.path_nonce = 0;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bet_amount = bet_amount;
+    game.step = 0;
+    game.current_price = MEMECOIN_START_PRICE;
+    game.game_state = MemecoinGameState::Generating;
+    game.payout = 0;
+    game.bump = ctx.bumps.memecoin_game;
+
+    let args = vec![Argument::PlaintextU128(mxe_nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![GeneratePricePathCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.memecoin_game.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+pub fn advance_memecoin(ctx: Context<AdvanceMemecoin>, computation_offset: u64, _game_id: u64) -> Result<()> {
+    let game = &ctx.accounts.memecoin_game;
+    require!(game.game_state == MemecoinGameState::Trading, CasinoError::InvalidGameState);
+    require!(game.step < MEMECOIN_STEPS, CasinoError::InvalidMove);
+
+    let args = vec![
+        Argument::PlaintextU128(game.path_nonce),
+        Argument::Account(game.key(), 8, 32 * 2),
+        Argument::PlaintextU8(game.step),
+    ];
+
+    ctx.accounts.memecoin_game.game_state = MemecoinGameState::Revealing;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RevealPriceStepCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.memecoin_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+/// Sells at the latest revealed price; no MPC is needed since that price is already public.
+pub fn sell_memecoin(ctx: Context<SellMemecoin>, _game_id: u64) -> Result<()> {
+    let game = &ctx.accounts.memecoin_game;
+    require!(game.game_state == MemecoinGameState::Trading, CasinoError::InvalidGameState);
+    let bet_amount = game.bet_amount;
+    let payout = calculate_memecoin_payout(bet_amount, game.current_price, ctx.accounts.memecoin_config.rtp_bps);
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    let game = &mut ctx.accounts.memecoin_game;
+    game.payout = payout;
+    game.game_state = MemecoinGameState::Sold;
+
+    emit!(MemecoinSoldEvent { game_id: game.game_id, step: game.step, price: game.current_price, payout });
+    Ok(())
+}
+
+#[event]
+pub struct MemecoinBoughtEvent { pub game_id: u64, pub player: Pubkey, pub bet_amount: u64 }
+#[event]
+pub struct MemecoinPriceEvent { pub game_id: u64, pub step: u8, pub price: u64 }
+#[event]
+pub struct MemecoinRuggedEvent { pub game_id: u64, pub step: u8 }
+#[event]
+pub struct MemecoinSoldEvent { pub game_id: u64, pub step: u8, pub price: u64, pub payout: u64 }
+
+pub fn generate_price_path_callback(
+    ctx: Context<GeneratePricePathCallback>,
+    output: ComputationOutputs<GeneratePricePathOutput>,
+) -> Result<()> {
+    let path = match output {
+        ComputationOutputs::Success(GeneratePricePathOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.memecoin_game;
+    game.price_path = path.ciphertexts;
+    game.path_nonce = path.nonce;
+    game.game_state = MemecoinGameState::Trading;
+
+    emit!(MemecoinBoughtEvent { game_id: game.game_id, player: game.player, bet_amount: game.bet_amount });
+    Ok(())
+}
+
+pub fn reveal_price_step_callback(
+    ctx: Context<RevealPriceStepCallback>,
+    output: ComputationOutputs<RevealPriceStepOutput>,
+) -> Result<()> {
+    let price = match output {
+        ComputationOutputs::Success(RevealPriceStepOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.memecoin_game;
+    game.step += 1;
+    game.current_price = price as u64;
+
+    if price == 0 {
+        game.game_state = MemecoinGameState::Rugged;
+        ctx.accounts.user_stats.total_losses += game.bet_amount;
+        emit!(MemecoinRuggedEvent { game_id: game.game_id, step: game.step });
+    } else {
+        game.game_state = MemecoinGameState::Trading;
+        emit!(MemecoinPriceEvent { game_id: game.game_id, step: game.step, price: game.current_price });
+    }
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[init_computation_definition_accounts("generate_price_path", payer)]
+#[derive(Accounts)]
+pub struct InitGeneratePricePathCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_price_step", payer)]
+#[derive(Accounts)]
+pub struct InitRevealPriceStepCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("generate_price_path", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct BuyMemecoin<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_GENERATE_PRICE_PATH))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MemecoinGame::DISCRIMINATOR.len() + MemecoinGame::INIT_SPACE,
+        seeds = [b"memecoin_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub memecoin_game: Box<Account<'info, MemecoinGame>>,
+    // Must exist before anyone buys in, or the position could never be sold
+    #[account(seeds = [b"memecoin_config"], bump = memecoin_config.bump)]
+    pub memecoin_config: Box<Account<'info, MemecoinConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("generate_price_path")]
+#[derive(Accounts)]
+pub struct GeneratePricePathCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_GENERATE_PRICE_PATH))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub memecoin_game: Box<Account<'info, MemecoinGame>>,
+}
+
+#[queue_computation_accounts("reveal_price_step", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct AdvanceMemecoin<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_PRICE_STEP))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"memecoin_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = memecoin_game.bump,
+    )]
+    pub memecoin_game: Box<Account<'info, MemecoinGame>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[callback_accounts("reveal_price_step")]
+#[derive(Accounts)]
+pub struct RevealPriceStepCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_PRICE_STEP))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub memecoin_game: Box<Account<'info, MemecoinGame>>,
+    #[account(mut, seeds = [b"user_stats", memecoin_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[derive(Accounts)]
+pub struct SetMemecoinConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MemecoinConfig::DISCRIMINATOR.len() + MemecoinConfig::INIT_SPACE,
+        seeds = [b"memecoin_config"],
+        bump
+    )]
+    pub memecoin_config: Box<Account<'info, MemecoinConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_game_id: u64)]
+pub struct SellMemecoin<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"memecoin_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = memecoin_game.bump,
+    )]
+    pub memecoin_game: Box<Account<'info, MemecoinGame>>,
+    #[account(seeds = [b"memecoin_config"], bump = memecoin_config.bump)]
+    pub memecoin_config: Box<Account<'info, MemecoinConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,524 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_mines_payout, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_GENERATE_MINES;
+use crate::COMP_DEF_OFFSET_REVEAL_TILE;
+use crate::COMP_DEF_OFFSET_REVEAL_MINES;
+
+pub fn init_generate_mines_comp_def(ctx: Context<InitGenerateMinesCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_reveal_tile_comp_def(ctx: Context<InitRevealTileCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_reveal_mines_comp_def(ctx: Context<InitRevealMinesCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn set_mines_config(ctx: Context<SetMinesConfig>, rtp_bps: u16) -> Result<()> {
+    validate_rtp_config(rtp_bps)?;
+
+    let config = &mut ctx.accounts.mines_config;
+    config.authority = ctx.accounts.authority.key();
+    config.rtp_bps = rtp_bps;
+    config.bump = ctx.bumps.mines_config;
+    Ok(())
+}
+
+/// Starts a mines game on a `grid_size` x `grid_size` board. The layout is placed in MPC
+/// and stays encrypted; each reveal only says whether that tile was safe.
+pub fn start_mines(
+    ctx: Context<StartMines>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    grid_size: u8,
+    mine_count: u8,
+    mxe_nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    require!((MINES_MIN_GRID_SIZE..=MINES_MAX_GRID_SIZE).contains(&grid_size), CasinoError::InvalidMinesConfig);
+    let tile_count = grid_size * grid_size;
+    require!(mine_count >= 1 && mine_count < tile_count, CasinoError::InvalidMinesConfig);
+
+    // Escrow the stake in the vault until the game ends
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.mines_game;
+    game.layout_ciphertext = [0; 32];
+    game.layout_nonce = 0;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bet_amount = bet_amount;
+    game.tile_count = tile_count;
+    game.mine_count = mine_count;
+    game.revealed_mask = 0;
+    game.safe_reveals = 0;
+    game.pending_tile = 0;
+    game.game_state = MinesGameState::Generating;
+    game.mine_layout = 0;
+    game.payout = 0;
+    game.bump = ctx.bumps.mines_game;
+
+    let args = vec![
+        Argument::PlaintextU128(mxe_nonce),
+        Argument::PlaintextU8(tile_count),
+        Argument::PlaintextU8(mine_count),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![GenerateMinesCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.mines_game.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+pub fn reveal_tile(ctx: Context<RevealTile>, computation_offset: u64, _game_id: u64, tile: u8) -> Result<()> {
+    let game = &ctx.accounts.mines_game;
+    require!(game.game_state == MinesGameState::Playing, CasinoError::InvalidGameState);
+    require!(tile < game.tile_count, CasinoError::InvalidMove);
+    require!(game.revealed_mask & (1 << tile) == 0, CasinoError::TileAlreadyRevealed);
+
+    let args = vec![
+        Argument::PlaintextU128(game.layout_nonce),
+        Argument::Account(game.key(), 8, 32),
+        Argument::PlaintextU8(tile),
+    ];
+
+    let game = &mut ctx.accounts.mines_game;
+    game.pending_tile = tile;
+    game.game_state = MinesGameState::Revealing;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RevealTileCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.mines_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+/// Takes the current multiplier after at least one safe reveal. The payout lands with the
+/// layout reveal, so the settled game always shows where the mines were.
+pub fn cash_out_mines(ctx: Context<CashOutMines>, computation_offset: u64, _game_id: u64) -> Result<()> {
+    let game = &ctx.accounts.mines_game;
+    require!(game.game_state == MinesGameState::Playing, CasinoError::InvalidGameState);
+    require!(game.safe_reveals > 0, CasinoError::InvalidMove);
+
+    let args = vec![
+        Argument::PlaintextU128(game.layout_nonce),
+        Argument::Account(game.key(), 8, 32),
+    ];
+
+    ctx.accounts.mines_game.game_state = MinesGameState::CashingOut;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RevealMinesCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.mines_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.mines_config.key(), is_writable: false },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct MinesStartedEvent { pub game_id: u64, pub player: Pubkey, pub tile_count: u8, pub mine_count: u8 }
+#[event]
+pub struct MinesTileSafeEvent { pub game_id: u64, pub tile: u8, pub safe_reveals: u8 }
+#[event]
+pub struct MinesLostEvent { pub game_id: u64, pub tile: u8, pub mine_layout: u32 }
+#[event]
+pub struct MinesCashedOutEvent { pub game_id: u64, pub safe_reveals: u8, pub payout: u64, pub mine_layout: u32 }
+
+pub fn generate_mines_callback(
+    ctx: Context<GenerateMinesCallback>,
+    output: ComputationOutputs<GenerateMinesOutput>,
+) -> Result<()> {
+    let layout = match output {
+        ComputationOutputs::Success(GenerateMinesOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.mines_game;
+    game.layout_ciphertext = layout.ciphertexts[0];
+    game.layout_nonce = layout.nonce;
+    game.game_state = MinesGameState::Playing;
+
+    emit!(MinesStartedEvent { game_id: game.game_id, player: game.player, tile_count: game.tile_count, mine_count: game.mine_count });
+    Ok(())
+}
+
+pub fn reveal_tile_callback(
+    ctx: Context<RevealTileCallback>,
+    output: ComputationOutputs<RevealTileOutput>,
+) -> Result<()> {
+    let (hit, mine_layout) = match output {
+        ComputationOutputs::Success(RevealTileOutput { field_0: RevealTileOutputStruct0 { field_0: hit, field_1: mine_layout } }) => (hit, mine_layout),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.mines_game;
+    let tile = game.pending_tile;
+    if hit {
+        game.game_state = MinesGameState::Lost;
+        game.mine_layout = mine_layout;
+        ctx.accounts.user_stats.total_losses += game.bet_amount;
+        emit!(MinesLostEvent { game_id: game.game_id, tile, mine_layout });
+    } else {
+        game.revealed_mask |= 1 << tile;
+        game.safe_reveals += 1;
+        game.game_state = MinesGameState::Playing;
+        emit!(MinesTileSafeEvent { game_id: game.game_id, tile, safe_reveals: game.safe_reveals });
+    }
+    Ok(())
+}
+
+pub fn reveal_mines_callback(
+    ctx: Context<RevealMinesCallback>,
+    output: ComputationOutputs<RevealMinesOutput>,
+) -> Result<()> {
+    let mine_layout = match output {
+        ComputationOutputs::Success(RevealMinesOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &ctx.accounts.mines_game;
+    let bet_amount = game.bet_amount;
+    let payout = calculate_mines_payout(
+        bet_amount,
+        game.tile_count,
+        game.mine_count,
+        game.safe_reveals,
+        ctx.accounts.mines_config.rtp_bps,
+    );
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    let game = &mut ctx.accounts.mines_game;
+    game.mine_layout = mine_layout;
+    game.payout = payout;
+    game.game_state = MinesGameState::CashedOut;
+
+    emit!(MinesCashedOutEvent { game_id: game.game_id, safe_reveals: game.safe_reveals, payout, mine_layout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[init_computation_definition_accounts("generate_mines", payer)]
+#[derive(Accounts)]
+pub struct InitGenerateMinesCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_tile", payer)]
+#[derive(Accounts)]
+pub struct InitRevealTileCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_mines", payer)]
+#[derive(Accounts)]
+pub struct InitRevealMinesCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMinesConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MinesConfig::DISCRIMINATOR.len() + MinesConfig::INIT_SPACE,
+        seeds = [b"mines_config"],
+        bump
+    )]
+    pub mines_config: Box<Account<'info, MinesConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("generate_mines", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct StartMines<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_GENERATE_MINES))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MinesGame::DISCRIMINATOR.len() + MinesGame::INIT_SPACE,
+        seeds = [b"mines_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub mines_game: Box<Account<'info, MinesGame>>,
+    // Must exist before a game starts, or it could never be cashed out
+    #[account(seeds = [b"mines_config"], bump = mines_config.bump)]
+    pub mines_config: Box<Account<'info, MinesConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("generate_mines")]
+#[derive(Accounts)]
+pub struct GenerateMinesCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_GENERATE_MINES))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub mines_game: Box<Account<'info, MinesGame>>,
+}
+
+#[queue_computation_accounts("reveal_tile", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct RevealTile<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_TILE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"mines_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = mines_game.bump,
+    )]
+    pub mines_game: Box<Account<'info, MinesGame>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[callback_accounts("reveal_tile")]
+#[derive(Accounts)]
+pub struct RevealTileCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_TILE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub mines_game: Box<Account<'info, MinesGame>>,
+    #[account(mut, seeds = [b"user_stats", mines_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[queue_computation_accounts("reveal_mines", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct CashOutMines<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MINES))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"mines_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = mines_game.bump,
+    )]
+    pub mines_game: Box<Account<'info, MinesGame>>,
+    #[account(seeds = [b"mines_config"], bump = mines_config.bump)]
+    pub mines_config: Box<Account<'info, MinesConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("reveal_mines")]
+#[derive(Accounts)]
+pub struct RevealMinesCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MINES))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub mines_game: Box<Account<'info, MinesGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == mines_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", mines_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    #[account(seeds = [b"mines_config"], bump = mines_config.bump)]
+    pub mines_config: Box<Account<'info, MinesConfig>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,317 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_plinko_payout, calculate_plinko_rtp_bps, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_DROP_PLINKO;
+
+pub fn init_drop_plinko_comp_def(ctx: Context<InitDropPlinkoCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Sets the return every multiplier table must hit. Tables validated against an earlier value
+/// can't be played until they are set again.
+pub fn set_plinko_config(ctx: Context<SetPlinkoConfig>, rtp_bps: u16) -> Result<()> {
+    validate_rtp_config(rtp_bps)?;
+
+    let config = &mut ctx.accounts.plinko_config;
+    config.authority = ctx.accounts.authority.key();
+    config.rtp_bps = rtp_bps;
+    config.bump = ctx.bumps.plinko_config;
+    Ok(())
+}
+
+/// Creates or replaces the multiplier table for a row count and risk level. The table is
+/// only accepted when its binomial-weighted return equals `PlinkoConfig::rtp_bps` to the basis point.
+pub fn set_plinko_table(ctx: Context<SetPlinkoTable>, rows: u8, risk: PlinkoRisk, multipliers_bps: [u32; 17]) -> Result<()> {
+    require!(rows >= PLINKO_MIN_ROWS && rows <= PLINKO_MAX_ROWS, CasinoError::InvalidPlinkoRows);
+    for (slot, &multiplier) in multipliers_bps.iter().enumerate() {
+        require!(multiplier <= PLINKO_MAX_MULTIPLIER_BPS, CasinoError::InvalidPaytable);
+        require!(slot <= rows as usize || multiplier == 0, CasinoError::InvalidPaytable);
+    }
+    let rtp_bps = calculate_plinko_rtp_bps(rows, &multipliers_bps);
+    require!(
+        rtp_bps == ctx.accounts.plinko_config.rtp_bps as u64,
+        CasinoError::PlinkoRtpMismatch
+    );
+
+    let table = &mut ctx.accounts.plinko_table;
+    table.authority = ctx.accounts.authority.key();
+    table.rows = rows;
+    table.risk = risk;
+    table.multipliers_bps = multipliers_bps;
+    table.rtp_bps = rtp_bps as u16;
+    table.bump = ctx.bumps.plinko_table;
+    Ok(())
+}
+
+/// Drops a ball through `rows` rows of pegs. Each bounce is a random bit drawn in MPC and
+/// only the landing slot is revealed; the payout comes from the matching table.
+pub fn play_plinko(
+    ctx: Context<PlayPlinko>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    rows: u8,
+    risk: PlinkoRisk,
+    nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    require!(rows >= PLINKO_MIN_ROWS && rows <= PLINKO_MAX_ROWS, CasinoError::InvalidPlinkoRows);
+    require!(ctx.accounts.plinko_table.rtp_bps == ctx.accounts.plinko_config.rtp_bps, CasinoError::PlinkoRtpMismatch);
+
+    // Escrow the stake in the vault until the slot is revealed
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.plinko_game;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bet_amount = bet_amount;
+    game.rows = rows;
+    game.risk = risk;
+    game.multipliers_bps = ctx.accounts.plinko_table.multipliers_bps;
+    game.slot = 0;
+    game.payout = 0;
+    game.is_complete = false;
+    game.bump = ctx.bumps.plinko_game;
+
+    let args = vec![Argument::PlaintextU128(nonce), Argument::PlaintextU8(rows)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DropPlinkoCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.plinko_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct PlinkoResultEvent { pub game_id: u64, pub rows: u8, pub risk: PlinkoRisk, pub slot: u8, pub multiplier_bps: u32, pub payout: u64 }
+
+pub fn drop_plinko_callback(
+    ctx: Context<DropPlinkoCallback>,
+    output: ComputationOutputs<DropPlinkoOutput>,
+) -> Result<()> {
+    let slot = match output {
+        ComputationOutputs::Success(DropPlinkoOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.plinko_game;
+    require!(!game.is_complete, CasinoError::GameAlreadyCompleted);
+    let bet_amount = game.bet_amount;
+    let multiplier_bps = game.multipliers_bps[slot as usize];
+    let payout = calculate_plinko_payout(bet_amount, multiplier_bps);
+    game.slot = slot;
+    game.payout = payout;
+    game.is_complete = true;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    let game = &ctx.accounts.plinko_game;
+    emit!(PlinkoResultEvent { game_id: game.game_id, rows: game.rows, risk: game.risk, slot, multiplier_bps, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetPlinkoConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlinkoConfig::DISCRIMINATOR.len() + PlinkoConfig::INIT_SPACE,
+        seeds = [b"plinko_config"],
+        bump
+    )]
+    pub plinko_config: Box<Account<'info, PlinkoConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(rows: u8, risk: PlinkoRisk)]
+pub struct SetPlinkoTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(seeds = [b"plinko_config"], bump = plinko_config.bump)]
+    pub plinko_config: Box<Account<'info, PlinkoConfig>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlinkoTable::DISCRIMINATOR.len() + PlinkoTable::INIT_SPACE,
+        seeds = [b"plinko_table".as_ref(), &[rows], &[risk as u8]],
+        bump
+    )]
+    pub plinko_table: Box<Account<'info, PlinkoTable>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("drop_plinko", payer)]
+#[derive(Accounts)]
+pub struct InitDropPlinkoCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("drop_plinko", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64, bet_amount: u64, rows: u8, risk: PlinkoRisk)]
+pub struct PlayPlinko<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DROP_PLINKO))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PlinkoGame::DISCRIMINATOR.len() + PlinkoGame::INIT_SPACE,
+        seeds = [b"plinko_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub plinko_game: Box<Account<'info, PlinkoGame>>,
+    #[account(seeds = [b"plinko_table".as_ref(), &[rows], &[risk as u8]], bump = plinko_table.bump)]
+    pub plinko_table: Box<Account<'info, PlinkoTable>>,
+    #[account(seeds = [b"plinko_config"], bump = plinko_config.bump)]
+    pub plinko_config: Box<Account<'info, PlinkoConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("drop_plinko")]
+#[derive(Accounts)]
+pub struct DropPlinkoCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DROP_PLINKO))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub plinko_game: Box<Account<'info, PlinkoGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == plinko_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", plinko_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
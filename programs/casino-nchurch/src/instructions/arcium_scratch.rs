@@ -0,0 +1,497 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_scratch_rtp_bps, scratch_card_symbols, scratch_winning_symbol, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_CREATE_SCRATCH_CARD;
+use crate::COMP_DEF_OFFSET_SCRATCH_TILE;
+use crate::COMP_DEF_OFFSET_REVEAL_SCRATCH_CARD;
+
+pub fn init_create_scratch_card_comp_def(ctx: Context<InitCreateScratchCardCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_scratch_tile_comp_def(ctx: Context<InitScratchTileCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_reveal_scratch_card_comp_def(ctx: Context<InitRevealScratchCardCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn set_scratch_paytable(ctx: Context<SetScratchPaytable>, paytable: [u32; 8]) -> Result<()> {
+    require!(paytable.iter().all(|&m| m <= SCRATCH_MAX_MULTIPLIER_BPS), CasinoError::InvalidPaytable);
+    require!(paytable.windows(2).all(|pair| pair[0] <= pair[1]), CasinoError::InvalidPaytable);
+
+    let rtp_bps = calculate_scratch_rtp_bps(&paytable);
+    if rtp_bps > 0 {
+        require!(rtp_bps <= 10000, CasinoError::InvalidPaytable);
+        validate_rtp_config(rtp_bps as u16)?;
+    }
+
+    let config = &mut ctx.accounts.scratch_config;
+    config.authority = ctx.accounts.authority.key();
+    config.paytable = paytable;
+    config.bump = ctx.bumps.scratch_config;
+    Ok(())
+}
+
+/// Buys a card. Its nine symbols are drawn in MPC and kept encrypted to the MXE; the player
+/// learns them one scratch at a time.
+pub fn buy_scratch_card(
+    ctx: Context<BuyScratchCard>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    mxe_nonce: u128,
+    client_pubkey: [u8; 32],
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    // The game is only offered once a paytable has been set
+    require!(ctx.accounts.scratch_config.paytable[SCRATCH_SYMBOLS - 1] > 0, CasinoError::InvalidPaytable);
+
+    // Escrow the price in the vault until the card is claimed
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let card = &mut ctx.accounts.scratch_card;
+    card.card_ciphertext = [0; 32];
+    card.card_nonce = 0;
+    card.player_enc_pubkey = client_pubkey;
+    card.game_id = game_id;
+    card.player = ctx.accounts.payer.key();
+    card.bet_amount = bet_amount;
+    card.paytable = ctx.accounts.scratch_config.paytable;
+    card.scratched_mask = 0;
+    card.card_state = ScratchCardState::Creating;
+    card.symbols = [0; 9];
+    card.winning_symbol = SCRATCH_NO_WIN;
+    card.payout = 0;
+    card.bump = ctx.bumps.scratch_card;
+
+    let args = vec![Argument::PlaintextU128(mxe_nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![CreateScratchCardCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.scratch_card.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+/// Scratches one tile. Its symbol comes back encrypted to the player's key.
+pub fn scratch_tile(ctx: Context<ScratchTile>, computation_offset: u64, _game_id: u64, tile: u8, client_nonce: u128) -> Result<()> {
+    let card = &ctx.accounts.scratch_card;
+    require!(card.card_state == ScratchCardState::Scratching, CasinoError::InvalidGameState);
+    require!(tile < SCRATCH_TILES, CasinoError::InvalidMove);
+    require!(card.scratched_mask & (1 << tile) == 0, CasinoError::TileAlreadyRevealed);
+
+    let args = vec![
+        Argument::PlaintextU128(card.card_nonce),
+        Argument::Account(card.key(), 8, 32),
+        Argument::ArcisPubkey(card.player_enc_pubkey),
+        Argument::PlaintextU128(client_nonce),
+        Argument::PlaintextU8(tile),
+    ];
+
+    ctx.accounts.scratch_card.scratched_mask |= 1 << tile;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ScratchTileCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.scratch_card.key(), is_writable: false }])],
+    )?;
+    Ok(())
+}
+
+/// Claims the card. The whole card is revealed and the prize computed from it on-chain, so
+/// every claim can be audited whatever was scratched.
+pub fn claim_scratch_card(ctx: Context<ClaimScratchCard>, computation_offset: u64, _game_id: u64) -> Result<()> {
+    let card = &ctx.accounts.scratch_card;
+    require!(card.card_state == ScratchCardState::Scratching, CasinoError::InvalidGameState);
+
+    let args = vec![
+        Argument::PlaintextU128(card.card_nonce),
+        Argument::Account(card.key(), 8, 32),
+    ];
+
+    ctx.accounts.scratch_card.card_state = ScratchCardState::Claiming;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RevealScratchCardCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.scratch_card.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct ScratchCardCreatedEvent { pub game_id: u64, pub player: Pubkey }
+#[event]
+pub struct ScratchTileEvent { pub game_id: u64, pub symbol_ciphertext: [u8; 32], pub nonce: u128 }
+#[event]
+pub struct ScratchCardClaimedEvent { pub game_id: u64, pub symbols: [u8; 9], pub winning_symbol: u8, pub payout: u64 }
+
+pub fn create_scratch_card_callback(
+    ctx: Context<CreateScratchCardCallback>,
+    output: ComputationOutputs<CreateScratchCardOutput>,
+) -> Result<()> {
+    let card_ct = match output {
+        ComputationOutputs::Success(CreateScratchCardOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let card = &mut ctx.accounts.scratch_card;
+    card.card_ciphertext = card_ct.ciphertexts[0];
+    card.card_nonce = card_ct.nonce;
+    card.card_state = ScratchCardState::Scratching;
+
+    emit!(ScratchCardCreatedEvent { game_id: card.game_id, player: card.player });
+    Ok(())
+}
+
+pub fn scratch_tile_callback(
+    ctx: Context<ScratchTileCallback>,
+    output: ComputationOutputs<ScratchTileOutput>,
+) -> Result<()> {
+    let symbol = match output {
+        ComputationOutputs::Success(ScratchTileOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    emit!(ScratchTileEvent { game_id: ctx.accounts.scratch_card.game_id, symbol_ciphertext: symbol.ciphertexts[0], nonce: symbol.nonce });
+    Ok(())
+}
+
+pub fn reveal_scratch_card_callback(
+    ctx: Context<RevealScratchCardCallback>,
+    output: ComputationOutputs<RevealScratchCardOutput>,
+) -> Result<()> {
+    let revealed = match output {
+        ComputationOutputs::Success(RevealScratchCardOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let card = &mut ctx.accounts.scratch_card;
+    require!(card.card_state == ScratchCardState::Claiming, CasinoError::InvalidGameState);
+    let bet_amount = card.bet_amount;
+    let symbols = scratch_card_symbols(revealed);
+    let winning_symbol = scratch_winning_symbol(&symbols);
+    let payout = if winning_symbol == SCRATCH_NO_WIN {
+        0
+    } else {
+        (bet_amount as u128 * card.paytable[winning_symbol as usize] as u128 / 10000) as u64
+    };
+    card.symbols = symbols;
+    card.winning_symbol = winning_symbol;
+    card.payout = payout;
+    card.card_state = ScratchCardState::Claimed;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    emit!(ScratchCardClaimedEvent { game_id: ctx.accounts.scratch_card.game_id, symbols, winning_symbol, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetScratchPaytable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ScratchConfig::DISCRIMINATOR.len() + ScratchConfig::INIT_SPACE,
+        seeds = [b"scratch_config"],
+        bump
+    )]
+    pub scratch_config: Box<Account<'info, ScratchConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("create_scratch_card", payer)]
+#[derive(Accounts)]
+pub struct InitCreateScratchCardCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("scratch_tile", payer)]
+#[derive(Accounts)]
+pub struct InitScratchTileCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_scratch_card", payer)]
+#[derive(Accounts)]
+pub struct InitRevealScratchCardCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("create_scratch_card", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct BuyScratchCard<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREATE_SCRATCH_CARD))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ScratchCard::DISCRIMINATOR.len() + ScratchCard::INIT_SPACE,
+        seeds = [b"scratch_card".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub scratch_card: Box<Account<'info, ScratchCard>>,
+    #[account(seeds = [b"scratch_config"], bump = scratch_config.bump)]
+    pub scratch_config: Box<Account<'info, ScratchConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("create_scratch_card")]
+#[derive(Accounts)]
+pub struct CreateScratchCardCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREATE_SCRATCH_CARD))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub scratch_card: Box<Account<'info, ScratchCard>>,
+}
+
+#[queue_computation_accounts("scratch_tile", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct ScratchTile<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SCRATCH_TILE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"scratch_card".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = scratch_card.bump,
+    )]
+    pub scratch_card: Box<Account<'info, ScratchCard>>,
+}
+
+#[callback_accounts("scratch_tile")]
+#[derive(Accounts)]
+pub struct ScratchTileCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SCRATCH_TILE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub scratch_card: Box<Account<'info, ScratchCard>>,
+}
+
+#[queue_computation_accounts("reveal_scratch_card", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct ClaimScratchCard<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_SCRATCH_CARD))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"scratch_card".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = scratch_card.bump,
+    )]
+    pub scratch_card: Box<Account<'info, ScratchCard>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("reveal_scratch_card")]
+#[derive(Accounts)]
+pub struct RevealScratchCardCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_SCRATCH_CARD))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub scratch_card: Box<Account<'info, ScratchCard>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == scratch_card.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", scratch_card.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
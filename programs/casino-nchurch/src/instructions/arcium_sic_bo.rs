@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::{calculate_sic_bo_payout, calculate_sic_bo_rtp_bps, sic_bo_winning_mask, validate_rtp_config};
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_ROLL_THREE_DICE;
+
+pub fn init_roll_three_dice_comp_def(ctx: Context<InitRollThreeDiceCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+/// Sets the payout of every position on the bet grid, x-to-1. Each position's RTP is computed
+/// from the 216 possible rolls and must be within the casino's RTP bounds; a position paying
+/// nothing is closed.
+pub fn set_sic_bo_config(ctx: Context<SetSicBoConfig>, payouts: [u16; 44]) -> Result<()> {
+    require!(payouts.iter().all(|&payout| payout <= SIC_BO_MAX_PAYOUT), CasinoError::InvalidSicBoConfig);
+
+    let rtp_bps = calculate_sic_bo_rtp_bps(&payouts);
+    for &rtp in rtp_bps.iter().filter(|&&rtp| rtp > 0) {
+        require!(rtp <= 10000, CasinoError::InvalidSicBoConfig);
+        validate_rtp_config(rtp as u16)?;
+    }
+    require!(rtp_bps.iter().any(|&rtp| rtp > 0), CasinoError::InvalidSicBoConfig);
+
+    let config = &mut ctx.accounts.sic_bo_config;
+    config.authority = ctx.accounts.authority.key();
+    config.payouts = payouts;
+    for (position, &rtp) in rtp_bps.iter().enumerate() {
+        config.rtp_bps[position] = rtp as u16;
+    }
+    config.bump = ctx.bumps.sic_bo_config;
+    Ok(())
+}
+
+/// Stakes `bets[p]` on each grid position `p` for a single roll of three dice, drawn in MPC;
+/// every bet is settled in the callback.
+pub fn play_sic_bo(
+    ctx: Context<PlaySicBo>,
+    computation_offset: u64,
+    game_id: u64,
+    bets: [u64; 44],
+    nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    let config = &ctx.accounts.sic_bo_config;
+    let mut total_bet = 0u64;
+    for (position, &amount) in bets.iter().enumerate() {
+        if amount > 0 {
+            require!(config.payouts[position] > 0, CasinoError::InvalidSicBoBet);
+            total_bet += amount;
+        }
+    }
+    require!(total_bet > 0, CasinoError::InvalidBetAmount);
+
+    // Escrow every stake in the vault until the roll is revealed
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, total_bet, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += total_bet;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += total_bet;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.sic_bo_game;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bets = bets;
+    game.total_bet = total_bet;
+    game.payouts = ctx.accounts.sic_bo_config.payouts;
+    game.dice = [0; 3];
+    game.winning_mask = 0;
+    game.payout = 0;
+    game.is_complete = false;
+    game.bump = ctx.bumps.sic_bo_game;
+
+    let args = vec![Argument::PlaintextU128(nonce)];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RollThreeDiceCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.sic_bo_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct SicBoResultEvent { pub game_id: u64, pub dice: [u8; 3], pub winning_mask: u64, pub payout: u64 }
+
+pub fn roll_three_dice_callback(
+    ctx: Context<RollThreeDiceCallback>,
+    output: ComputationOutputs<RollThreeDiceOutput>,
+) -> Result<()> {
+    let dice = match output {
+        ComputationOutputs::Success(RollThreeDiceOutput { field_0: RollThreeDiceOutputStruct0 { field_0, field_1, field_2 } }) => [field_0, field_1, field_2],
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.sic_bo_game;
+    require!(!game.is_complete, CasinoError::GameAlreadyCompleted);
+    let total_bet = game.total_bet;
+    let winning_mask = sic_bo_winning_mask(dice);
+    let payout = calculate_sic_bo_payout(&game.bets, &game.payouts, winning_mask);
+    game.dice = dice;
+    game.winning_mask = winning_mask;
+    game.payout = payout;
+    game.is_complete = true;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > total_bet {
+        ctx.accounts.user_stats.total_wins += payout - total_bet;
+        ctx.accounts.user_stats.loyalty_points += total_bet / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += total_bet - payout;
+    }
+
+    emit!(SicBoResultEvent { game_id: ctx.accounts.sic_bo_game.game_id, dice, winning_mask, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetSicBoConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SicBoConfig::DISCRIMINATOR.len() + SicBoConfig::INIT_SPACE,
+        seeds = [b"sic_bo_config"],
+        bump
+    )]
+    pub sic_bo_config: Box<Account<'info, SicBoConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("roll_three_dice", payer)]
+#[derive(Accounts)]
+pub struct InitRollThreeDiceCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("roll_three_dice", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct PlaySicBo<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROLL_THREE_DICE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = SicBoGame::DISCRIMINATOR.len() + SicBoGame::INIT_SPACE,
+        seeds = [b"sic_bo_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub sic_bo_game: Box<Account<'info, SicBoGame>>,
+    #[account(seeds = [b"sic_bo_config"], bump = sic_bo_config.bump)]
+    pub sic_bo_config: Box<Account<'info, SicBoConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("roll_three_dice")]
+#[derive(Accounts)]
+pub struct RollThreeDiceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROLL_THREE_DICE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub sic_bo_game: Box<Account<'info, SicBoGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == sic_bo_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", sic_bo_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,450 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+use crate::utils::calculate_three_card_poker_payout;
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_DEAL_THREE_CARD_POKER;
+use crate::COMP_DEF_OFFSET_RESOLVE_THREE_CARD_POKER;
+
+pub fn init_deal_three_card_poker_comp_def(ctx: Context<InitDealThreeCardPokerCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_resolve_three_card_poker_comp_def(ctx: Context<InitResolveThreeCardPokerCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn set_three_card_poker_config(ctx: Context<SetThreeCardPokerConfig>, paytable: ThreeCardPokerPaytable) -> Result<()> {
+    // High card never pays on either table
+    require!(paytable.pair_plus[0] == 0 && paytable.ante_bonus[0] == 0, CasinoError::InvalidPaytable);
+    require!(
+        paytable.pair_plus.iter().chain(paytable.ante_bonus.iter()).all(|&m| m <= THREE_CARD_POKER_MAX_MULTIPLIER),
+        CasinoError::InvalidPaytable
+    );
+
+    let config = &mut ctx.accounts.three_card_poker_config;
+    config.authority = ctx.accounts.authority.key();
+    config.paytable = paytable;
+    config.bump = ctx.bumps.three_card_poker_config;
+    Ok(())
+}
+
+/// Takes the ante and optional Pair Plus wager and deals three cards to the player and three
+/// to the dealer. The dealer's hand stays encrypted to the MXE until the player decides.
+pub fn deal_three_card_poker(
+    ctx: Context<DealThreeCardPoker>,
+    computation_offset: u64,
+    game_id: u64,
+    ante: u64,
+    pair_plus_bet: u64,
+    mxe_nonce: u128,
+    client_pubkey: [u8; 32],
+    client_nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(ante > 0, CasinoError::InvalidBetAmount);
+    // Pair Plus is capped at the ante and only offered while its paytable pays
+    let paytable = ctx.accounts.three_card_poker_config.paytable;
+    require!(pair_plus_bet <= ante, CasinoError::InvalidSideBet);
+    require!(pair_plus_bet == 0 || paytable.pair_plus[5] > 0, CasinoError::InvalidSideBet);
+    let total_stake = ante + pair_plus_bet;
+
+    // Escrow the ante and Pair Plus in the vault until the showdown
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, total_stake, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += total_stake;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += total_stake;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.three_card_poker_game;
+    game.player_hand = [0; 32];
+    game.dealer_hand = [0; 32];
+    game.player_nonce = 0;
+    game.dealer_nonce = 0;
+    game.player_enc_pubkey = client_pubkey;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.ante = ante;
+    game.play_bet = 0;
+    game.pair_plus_bet = pair_plus_bet;
+    game.paytable = paytable;
+    game.player_rank = 0;
+    game.dealer_rank = 0;
+    game.outcome = 0;
+    game.dealer_cards = [0; 3];
+    game.game_state = ThreeCardPokerGameState::Dealing;
+    game.payout = 0;
+    game.bump = ctx.bumps.three_card_poker_game;
+
+    let args = vec![
+        Argument::PlaintextU128(mxe_nonce),
+        Argument::ArcisPubkey(client_pubkey),
+        Argument::PlaintextU128(client_nonce),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DealThreeCardPokerCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.three_card_poker_game.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+/// Plays (escrowing a play wager equal to the ante) or folds, then reveals the showdown.
+/// Pair Plus settles either way.
+pub fn decide_three_card_poker(
+    ctx: Context<DecideThreeCardPoker>,
+    computation_offset: u64,
+    _game_id: u64,
+    play: bool,
+) -> Result<()> {
+    require!(ctx.accounts.three_card_poker_game.game_state == ThreeCardPokerGameState::Deciding, CasinoError::InvalidGameState);
+
+    if play {
+        let play_bet = ctx.accounts.three_card_poker_game.ante;
+        let ix = TransferChecked {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            to: ctx.accounts.casino_vault.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+        transfer_checked(cpi_ctx, play_bet, ctx.accounts.usdc_mint.decimals)?;
+
+        ctx.accounts.casino_state.total_volume += play_bet;
+        ctx.accounts.user_stats.total_bets += play_bet;
+        ctx.accounts.three_card_poker_game.play_bet = play_bet;
+    }
+
+    let game = &ctx.accounts.three_card_poker_game;
+    let args = vec![
+        // Player hand
+        Argument::ArcisPubkey(game.player_enc_pubkey),
+        Argument::PlaintextU128(game.player_nonce),
+        Argument::Account(game.key(), 8, 32),
+        // Dealer hand
+        Argument::PlaintextU128(game.dealer_nonce),
+        Argument::Account(game.key(), 8 + 32, 32),
+    ];
+
+    ctx.accounts.three_card_poker_game.game_state = ThreeCardPokerGameState::Resolving;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ResolveThreeCardPokerCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.three_card_poker_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct ThreeCardPokerDealtEvent { pub game_id: u64, pub player_hand: [u8; 32], pub player_nonce: u128 }
+#[event]
+pub struct ThreeCardPokerResultEvent {
+    pub game_id: u64,
+    pub played: bool,
+    pub player_rank: u8,
+    pub dealer_rank: u8,
+    pub dealer_cards: [u8; 3],
+    pub outcome: u8,
+    pub payout: u64,
+}
+
+pub fn deal_three_card_poker_callback(
+    ctx: Context<DealThreeCardPokerCallback>,
+    output: ComputationOutputs<DealThreeCardPokerOutput>,
+) -> Result<()> {
+    let (dealer_hand, player_hand) = match output {
+        ComputationOutputs::Success(DealThreeCardPokerOutput { field_0: DealThreeCardPokerOutputStruct0 { field_0: dealer_hand, field_1: player_hand } }) => (dealer_hand, player_hand),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.three_card_poker_game;
+    game.player_hand = player_hand.ciphertexts[0];
+    game.player_nonce = player_hand.nonce;
+    game.dealer_hand = dealer_hand.ciphertexts[0];
+    game.dealer_nonce = dealer_hand.nonce;
+    game.game_state = ThreeCardPokerGameState::Deciding;
+
+    emit!(ThreeCardPokerDealtEvent { game_id: game.game_id, player_hand: game.player_hand, player_nonce: game.player_nonce });
+    Ok(())
+}
+
+pub fn resolve_three_card_poker_callback(
+    ctx: Context<ResolveThreeCardPokerCallback>,
+    output: ComputationOutputs<ResolveThreeCardPokerOutput>,
+) -> Result<()> {
+    let (player_rank, dealer_rank, outcome, dealer_cards) = match output {
+        ComputationOutputs::Success(ResolveThreeCardPokerOutput { field_0: ResolveThreeCardPokerOutputStruct0 { field_0, field_1, field_2, field_3, field_4, field_5 } }) => (field_0, field_1, field_2, [field_3, field_4, field_5]),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &ctx.accounts.three_card_poker_game;
+    require!(game.game_state == ThreeCardPokerGameState::Resolving, CasinoError::InvalidGameState);
+    let total_stake = game.ante + game.play_bet + game.pair_plus_bet;
+    let payout = calculate_three_card_poker_payout(game.ante, game.play_bet, game.pair_plus_bet, player_rank, outcome, &game.paytable);
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > total_stake {
+        ctx.accounts.user_stats.total_wins += payout - total_stake;
+        ctx.accounts.user_stats.loyalty_points += total_stake / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += total_stake - payout;
+    }
+
+    let game = &mut ctx.accounts.three_card_poker_game;
+    game.player_rank = player_rank;
+    game.dealer_rank = dealer_rank;
+    game.outcome = outcome;
+    game.dealer_cards = dealer_cards;
+    game.payout = payout;
+    game.game_state = ThreeCardPokerGameState::Resolved;
+
+    emit!(ThreeCardPokerResultEvent {
+        game_id: game.game_id,
+        played: game.play_bet > 0,
+        player_rank,
+        dealer_rank,
+        dealer_cards,
+        outcome,
+        payout,
+    });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetThreeCardPokerConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ThreeCardPokerConfig::DISCRIMINATOR.len() + ThreeCardPokerConfig::INIT_SPACE,
+        seeds = [b"three_card_poker_config"],
+        bump
+    )]
+    pub three_card_poker_config: Box<Account<'info, ThreeCardPokerConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("deal_three_card_poker", payer)]
+#[derive(Accounts)]
+pub struct InitDealThreeCardPokerCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("resolve_three_card_poker", payer)]
+#[derive(Accounts)]
+pub struct InitResolveThreeCardPokerCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("deal_three_card_poker", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct DealThreeCardPoker<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_THREE_CARD_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ThreeCardPokerGame::DISCRIMINATOR.len() + ThreeCardPokerGame::INIT_SPACE,
+        seeds = [b"three_card_poker_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub three_card_poker_game: Box<Account<'info, ThreeCardPokerGame>>,
+    #[account(seeds = [b"three_card_poker_config"], bump = three_card_poker_config.bump)]
+    pub three_card_poker_config: Box<Account<'info, ThreeCardPokerConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("deal_three_card_poker")]
+#[derive(Accounts)]
+pub struct DealThreeCardPokerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_THREE_CARD_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub three_card_poker_game: Box<Account<'info, ThreeCardPokerGame>>,
+}
+
+#[queue_computation_accounts("resolve_three_card_poker", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct DecideThreeCardPoker<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_THREE_CARD_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"three_card_poker_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = three_card_poker_game.bump,
+    )]
+    pub three_card_poker_game: Box<Account<'info, ThreeCardPokerGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", payer.key().as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("resolve_three_card_poker")]
+#[derive(Accounts)]
+pub struct ResolveThreeCardPokerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_THREE_CARD_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub three_card_poker_game: Box<Account<'info, ThreeCardPokerGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == three_card_poker_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", three_card_poker_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
@@ -0,0 +1,505 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::casino::*;
+use crate::errors::*;
+
+use arcium_client::idl::arcium::*;
+use crate::SignerAccount;
+
+use crate::COMP_DEF_OFFSET_DEAL_VIDEO_POKER;
+use crate::COMP_DEF_OFFSET_DRAW_VIDEO_POKER;
+use crate::COMP_DEF_OFFSET_RESOLVE_VIDEO_POKER;
+
+pub fn init_deal_video_poker_comp_def(ctx: Context<InitDealVideoPokerCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_draw_video_poker_comp_def(ctx: Context<InitDrawVideoPokerCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn init_resolve_video_poker_comp_def(ctx: Context<InitResolveVideoPokerCompDef>) -> Result<()> {
+    init_comp_def(ctx.accounts,true, 0, None, None)?;
+    Ok(())
+}
+
+pub fn set_video_poker_paytable(ctx: Context<SetVideoPokerPaytable>, paytable: [u16; 10]) -> Result<()> {
+    // A losing hand pays nothing and a better hand never pays less
+    require!(paytable[0] == 0, CasinoError::InvalidPaytable);
+    require!(paytable.windows(2).all(|w| w[0] <= w[1]), CasinoError::InvalidPaytable);
+    require!(paytable[9] <= VIDEO_POKER_MAX_MULTIPLIER, CasinoError::InvalidPaytable);
+
+    let config = &mut ctx.accounts.video_poker_config;
+    config.authority = ctx.accounts.authority.key();
+    config.paytable = paytable;
+    config.bump = ctx.bumps.video_poker_config;
+    Ok(())
+}
+
+/// Deals five cards encrypted to the player from an MXE-held deck. The paytable in force
+/// is snapshotted into the game.
+pub fn deal_video_poker(
+    ctx: Context<DealVideoPoker>,
+    computation_offset: u64,
+    game_id: u64,
+    bet_amount: u64,
+    mxe_nonce: u128,
+    client_pubkey: [u8; 32],
+    client_nonce: u128,
+) -> Result<()> {
+    require!(ctx.accounts.casino_state.is_active, CasinoError::CasinoNotActive);
+    require!(bet_amount > 0, CasinoError::InvalidBetAmount);
+    // The game is only offered once a paytable has been set
+    require!(ctx.accounts.video_poker_config.paytable[9] > 0, CasinoError::InvalidPaytable);
+
+    // Escrow the stake in the vault until the hand is ranked
+    let ix = TransferChecked {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.casino_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), ix);
+    transfer_checked(cpi_ctx, bet_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.casino_state.total_games_played += 1;
+    ctx.accounts.casino_state.total_volume += bet_amount;
+    ctx.accounts.user_stats.user = ctx.accounts.payer.key();
+    ctx.accounts.user_stats.bump = ctx.bumps.user_stats;
+    ctx.accounts.user_stats.total_bets += bet_amount;
+    ctx.accounts.user_stats.games_played += 1;
+
+    let game = &mut ctx.accounts.video_poker_game;
+    game.deck = [[0; 32]; 3];
+    game.hand = [0; 32];
+    game.deck_nonce = 0;
+    game.hand_nonce = 0;
+    game.player_enc_pubkey = client_pubkey;
+    game.game_id = game_id;
+    game.player = ctx.accounts.payer.key();
+    game.bet_amount = bet_amount;
+    game.hold_mask = 0;
+    game.hand_rank = 0;
+    game.paytable = ctx.accounts.video_poker_config.paytable;
+    game.game_state = VideoPokerGameState::Dealing;
+    game.payout = 0;
+    game.bump = ctx.bumps.video_poker_game;
+
+    let args = vec![
+        Argument::PlaintextU128(mxe_nonce),
+        Argument::ArcisPubkey(client_pubkey),
+        Argument::PlaintextU128(client_nonce),
+    ];
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DealVideoPokerCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.video_poker_game.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+/// Keeps the cards whose bit is set in `hold_mask` and replaces the rest from the hidden deck.
+pub fn draw_video_poker(ctx: Context<DrawVideoPoker>, computation_offset: u64, _game_id: u64, hold_mask: u8) -> Result<()> {
+    let game = &ctx.accounts.video_poker_game;
+    require!(game.game_state == VideoPokerGameState::Holding, CasinoError::InvalidGameState);
+    require!(hold_mask < 1 << VIDEO_POKER_HAND_SIZE, CasinoError::InvalidMove);
+
+    let args = vec![
+        // Deck
+        Argument::PlaintextU128(game.deck_nonce),
+        Argument::Account(game.key(), 8, 32 * 3),
+        // Player hand
+        Argument::ArcisPubkey(game.player_enc_pubkey),
+        Argument::PlaintextU128(game.hand_nonce),
+        Argument::Account(game.key(), 8 + 32 * 3, 32),
+        Argument::PlaintextU8(hold_mask),
+    ];
+
+    let game = &mut ctx.accounts.video_poker_game;
+    game.hold_mask = hold_mask;
+    game.game_state = VideoPokerGameState::Drawing;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![DrawVideoPokerCallback::callback_ix(&[CallbackAccount { pubkey: ctx.accounts.video_poker_game.key(), is_writable: true }])],
+    )?;
+    Ok(())
+}
+
+/// Reveals the rank of the final hand and pays it from the snapshotted paytable.
+/// Permissionless, like `resolve_game`: the player has no further decisions to make.
+pub fn resolve_video_poker(ctx: Context<ResolveVideoPoker>, computation_offset: u64, _game_id: u64) -> Result<()> {
+    let game = &ctx.accounts.video_poker_game;
+    require!(game.game_state == VideoPokerGameState::Drawn, CasinoError::InvalidGameState);
+
+    let args = vec![
+        Argument::ArcisPubkey(game.player_enc_pubkey),
+        Argument::PlaintextU128(game.hand_nonce),
+        Argument::Account(game.key(), 8 + 32 * 3, 32),
+    ];
+
+    ctx.accounts.video_poker_game.game_state = VideoPokerGameState::Resolving;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ResolveVideoPokerCallback::callback_ix(&[
+            CallbackAccount { pubkey: ctx.accounts.video_poker_game.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_state.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.casino_vault.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.user_token_account.key(), is_writable: true },
+            CallbackAccount { pubkey: ctx.accounts.usdc_mint.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.token_program.key(), is_writable: false },
+            CallbackAccount { pubkey: ctx.accounts.user_stats.key(), is_writable: true },
+        ])],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct VideoPokerDealtEvent { pub game_id: u64, pub hand: [u8; 32], pub hand_nonce: u128 }
+#[event]
+pub struct VideoPokerDrawnEvent { pub game_id: u64, pub hold_mask: u8, pub hand: [u8; 32], pub hand_nonce: u128 }
+#[event]
+pub struct VideoPokerResultEvent { pub game_id: u64, pub hand_rank: u8, pub payout: u64 }
+
+pub fn deal_video_poker_callback(
+    ctx: Context<DealVideoPokerCallback>,
+    output: ComputationOutputs<DealVideoPokerOutput>,
+) -> Result<()> {
+    let (deck, hand) = match output {
+        ComputationOutputs::Success(DealVideoPokerOutput { field_0: DealVideoPokerOutputStruct0 { field_0: deck, field_1: hand } }) => (deck, hand),
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.video_poker_game;
+    game.deck = deck.ciphertexts;
+    game.deck_nonce = deck.nonce;
+    game.hand = hand.ciphertexts[0];
+    game.hand_nonce = hand.nonce;
+    game.game_state = VideoPokerGameState::Holding;
+
+    emit!(VideoPokerDealtEvent { game_id: game.game_id, hand: game.hand, hand_nonce: game.hand_nonce });
+    Ok(())
+}
+
+pub fn draw_video_poker_callback(
+    ctx: Context<DrawVideoPokerCallback>,
+    output: ComputationOutputs<DrawVideoPokerOutput>,
+) -> Result<()> {
+    let hand = match output {
+        ComputationOutputs::Success(DrawVideoPokerOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &mut ctx.accounts.video_poker_game;
+    game.hand = hand.ciphertexts[0];
+    game.hand_nonce = hand.nonce;
+    game.game_state = VideoPokerGameState::Drawn;
+
+    emit!(VideoPokerDrawnEvent { game_id: game.game_id, hold_mask: game.hold_mask, hand: game.hand, hand_nonce: game.hand_nonce });
+    Ok(())
+}
+
+pub fn resolve_video_poker_callback(
+    ctx: Context<ResolveVideoPokerCallback>,
+    output: ComputationOutputs<ResolveVideoPokerOutput>,
+) -> Result<()> {
+    let hand_rank = match output {
+        ComputationOutputs::Success(ResolveVideoPokerOutput { field_0 }) => field_0,
+        _ => return Err(ErrorCode::AbortedComputation.into()),
+    };
+
+    let game = &ctx.accounts.video_poker_game;
+    require!(game.game_state == VideoPokerGameState::Resolving, CasinoError::InvalidGameState);
+    let bet_amount = game.bet_amount;
+    let payout = bet_amount * game.paytable[hand_rank as usize] as u64;
+
+    if payout > 0 {
+        let ix = TransferChecked {
+            from: ctx.accounts.casino_vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.casino_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let seeds: &[&[&[u8]]] = &[&[b"casino_state", &[ctx.accounts.casino_state.casino_state_bump]]];
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), ix, seeds);
+        transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+        ctx.accounts.casino_state.total_payouts += payout;
+    }
+    if payout > bet_amount {
+        ctx.accounts.user_stats.total_wins += payout - bet_amount;
+        ctx.accounts.user_stats.loyalty_points += bet_amount / 100;
+    } else {
+        ctx.accounts.user_stats.total_losses += bet_amount - payout;
+    }
+
+    let game = &mut ctx.accounts.video_poker_game;
+    game.hand_rank = hand_rank;
+    game.payout = payout;
+    game.game_state = VideoPokerGameState::Resolved;
+
+    emit!(VideoPokerResultEvent { game_id: game.game_id, hand_rank, payout });
+    Ok(())
+}
+
+// --- Accounts ---
+
+#[derive(Accounts)]
+pub struct SetVideoPokerPaytable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"casino_state"],
+        bump = casino_state.casino_state_bump,
+        constraint = casino_state.authority == authority.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = VideoPokerConfig::DISCRIMINATOR.len() + VideoPokerConfig::INIT_SPACE,
+        seeds = [b"video_poker_config"],
+        bump
+    )]
+    pub video_poker_config: Box<Account<'info, VideoPokerConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("deal_video_poker", payer)]
+#[derive(Accounts)]
+pub struct InitDealVideoPokerCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("draw_video_poker", payer)]
+#[derive(Accounts)]
+pub struct InitDrawVideoPokerCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("resolve_video_poker", payer)]
+#[derive(Accounts)]
+pub struct InitResolveVideoPokerCompDef<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())] pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)] /// CHECK
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("deal_video_poker", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, game_id: u64)]
+pub struct DealVideoPoker<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_VIDEO_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = VideoPokerGame::DISCRIMINATOR.len() + VideoPokerGame::INIT_SPACE,
+        seeds = [b"video_poker_game".as_ref(), payer.key().as_ref(), game_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub video_poker_game: Box<Account<'info, VideoPokerGame>>,
+    #[account(seeds = [b"video_poker_config"], bump = video_poker_config.bump)]
+    pub video_poker_config: Box<Account<'info, VideoPokerConfig>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == payer.key() @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", payer.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("deal_video_poker")]
+#[derive(Accounts)]
+pub struct DealVideoPokerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEAL_VIDEO_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub video_poker_game: Box<Account<'info, VideoPokerGame>>,
+}
+
+#[queue_computation_accounts("draw_video_poker", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct DrawVideoPoker<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_VIDEO_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"video_poker_game".as_ref(), payer.key().as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = video_poker_game.bump,
+    )]
+    pub video_poker_game: Box<Account<'info, VideoPokerGame>>,
+}
+
+#[callback_accounts("draw_video_poker")]
+#[derive(Accounts)]
+pub struct DrawVideoPokerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DRAW_VIDEO_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub video_poker_game: Box<Account<'info, VideoPokerGame>>,
+}
+
+#[queue_computation_accounts("resolve_video_poker", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _game_id: u64)]
+pub struct ResolveVideoPoker<'info> {
+    #[account(mut)] pub payer: Signer<'info>,
+    #[account(init_if_needed, space = 9, payer = payer, seeds = [&SIGN_PDA_SEED], bump, address = derive_sign_pda!())]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(address = derive_mxe_pda!())] pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!())] /// CHECK
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!())] /// CHECK
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset))] /// CHECK
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_VIDEO_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)] pub pool_account: Account<'info, FeePool>,
+    #[account(address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)] pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"video_poker_game".as_ref(), video_poker_game.player.as_ref(), _game_id.to_le_bytes().as_ref()],
+        bump = video_poker_game.bump,
+    )]
+    pub video_poker_game: Box<Account<'info, VideoPokerGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, token::mint = usdc_mint, constraint = user_token_account.owner == video_poker_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut, seeds = [b"user_stats", video_poker_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[callback_accounts("resolve_video_poker")]
+#[derive(Accounts)]
+pub struct ResolveVideoPokerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_VIDEO_POKER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)] /// CHECK
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)] pub video_poker_game: Box<Account<'info, VideoPokerGame>>,
+    #[account(mut, seeds = [b"casino_state"], bump = casino_state.casino_state_bump)]
+    pub casino_state: Box<Account<'info, CasinoState>>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = casino_state,
+        associated_token::token_program = token_program,
+    )]
+    pub casino_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = user_token_account.owner == video_poker_game.player @ ErrorCode::NotAuthorized)]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut, seeds = [b"user_stats", video_poker_game.player.as_ref()], bump = user_stats.bump)]
+    pub user_stats: Box<Account<'info, UserStats>>,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The computation was aborted")]
+    AbortedComputation,
+    #[msg("Not authorized")]
+    NotAuthorized,
+    #[msg("Cluster not set")]
+    ClusterNotSet,
+}
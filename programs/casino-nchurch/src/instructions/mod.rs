@@ -6,7 +6,26 @@ pub mod arcium_slots;
 pub mod arcium_roulette;
 pub mod arcium_coinflip;
 pub mod arcium_blackjack;
+pub mod arcium_blackjack_shoe;
+pub mod arcium_blackjack_table;
+pub mod arcium_blackjack_crank;
+pub mod arcium_blackjack_audit;
 pub mod arcium_dice;
+pub mod arcium_aviator;
+pub mod arcium_crash_round;
+pub mod arcium_memecoin;
+pub mod arcium_mines;
+pub mod arcium_hilo;
+pub mod arcium_video_poker;
+pub mod arcium_baccarat;
+pub mod arcium_three_card_poker;
+pub mod arcium_keno;
+pub mod arcium_plinko;
+pub mod arcium_big_six;
+pub mod arcium_lottery;
+pub mod arcium_scratch;
+pub mod arcium_craps;
+pub mod arcium_sic_bo;
 
 // pub use casino::*;
 pub use quest::*;
@@ -15,5 +34,24 @@ pub use arcium_slots::*;
 pub use arcium_roulette::*;
 pub use arcium_coinflip::*;
 pub use arcium_blackjack::*;
+pub use arcium_blackjack_shoe::*;
+pub use arcium_blackjack_table::*;
+pub use arcium_blackjack_crank::*;
+pub use arcium_blackjack_audit::*;
 pub use arcium_dice::*;
+pub use arcium_aviator::*;
+pub use arcium_crash_round::*;
+pub use arcium_memecoin::*;
+pub use arcium_mines::*;
+pub use arcium_hilo::*;
+pub use arcium_video_poker::*;
+pub use arcium_baccarat::*;
+pub use arcium_three_card_poker::*;
+pub use arcium_keno::*;
+pub use arcium_plinko::*;
+pub use arcium_big_six::*;
+pub use arcium_lottery::*;
+pub use arcium_scratch::*;
+pub use arcium_craps::*;
+pub use arcium_sic_bo::*;
 pub use init_casino::*;
\ No newline at end of file
@@ -15,13 +15,48 @@ const COMP_DEF_OFFSET_SHUFFLE_AND_DEAL_CARDS: u32 = comp_def_offset("shuffle_and
 const COMP_DEF_OFFSET_PLAYER_HIT: u32 = comp_def_offset("player_hit");
 const COMP_DEF_OFFSET_PLAYER_DOUBLE_DOWN: u32 = comp_def_offset("player_double_down");
 const COMP_DEF_OFFSET_PLAYER_STAND: u32 = comp_def_offset("player_stand");
+const COMP_DEF_OFFSET_PLAYER_SURRENDER: u32 = comp_def_offset("player_surrender");
 const COMP_DEF_OFFSET_DEALER_PLAY: u32 = comp_def_offset("dealer_play");
 const COMP_DEF_OFFSET_RESOLVE_GAME: u32 = comp_def_offset("resolve_game");
+const COMP_DEF_OFFSET_SHUFFLE_SHOE: u32 = comp_def_offset("shuffle_shoe");
+const COMP_DEF_OFFSET_DEAL_FROM_SHOE: u32 = comp_def_offset("deal_from_shoe");
+const COMP_DEF_OFFSET_DEAL_TABLE: u32 = comp_def_offset("deal_table");
+const COMP_DEF_OFFSET_TABLE_PLAYER_HIT: u32 = comp_def_offset("table_player_hit");
+const COMP_DEF_OFFSET_TABLE_DEALER_PLAY: u32 = comp_def_offset("table_dealer_play");
+const COMP_DEF_OFFSET_RESOLVE_TABLE: u32 = comp_def_offset("resolve_table");
+const COMP_DEF_OFFSET_REVEAL_DECK: u32 = comp_def_offset("reveal_deck");
 
 const COMP_DEF_OFFSET_FLIP: u32 = comp_def_offset("flip");
 
 const COMP_DEF_OFFSET_ROLL_DICE: u32 = comp_def_offset("roll_dice");
 
+const COMP_DEF_OFFSET_CRASH_AVIATOR: u32 = comp_def_offset("crash_aviator");
+const COMP_DEF_OFFSET_START_CRASH_ROUND: u32 = comp_def_offset("start_crash_round");
+const COMP_DEF_OFFSET_CHECK_CRASH_ROUND: u32 = comp_def_offset("check_crash_round");
+
+const COMP_DEF_OFFSET_GENERATE_PRICE_PATH: u32 = comp_def_offset("generate_price_path");
+const COMP_DEF_OFFSET_REVEAL_PRICE_STEP: u32 = comp_def_offset("reveal_price_step");
+const COMP_DEF_OFFSET_GENERATE_MINES: u32 = comp_def_offset("generate_mines");
+const COMP_DEF_OFFSET_REVEAL_TILE: u32 = comp_def_offset("reveal_tile");
+const COMP_DEF_OFFSET_REVEAL_MINES: u32 = comp_def_offset("reveal_mines");
+const COMP_DEF_OFFSET_SHUFFLE_HILO_DECK: u32 = comp_def_offset("shuffle_hilo_deck");
+const COMP_DEF_OFFSET_DRAW_HILO_CARD: u32 = comp_def_offset("draw_hilo_card");
+const COMP_DEF_OFFSET_DEAL_VIDEO_POKER: u32 = comp_def_offset("deal_video_poker");
+const COMP_DEF_OFFSET_DRAW_VIDEO_POKER: u32 = comp_def_offset("draw_video_poker");
+const COMP_DEF_OFFSET_RESOLVE_VIDEO_POKER: u32 = comp_def_offset("resolve_video_poker");
+const COMP_DEF_OFFSET_PLAY_BACCARAT: u32 = comp_def_offset("play_baccarat");
+const COMP_DEF_OFFSET_DEAL_THREE_CARD_POKER: u32 = comp_def_offset("deal_three_card_poker");
+const COMP_DEF_OFFSET_RESOLVE_THREE_CARD_POKER: u32 = comp_def_offset("resolve_three_card_poker");
+const COMP_DEF_OFFSET_DRAW_KENO: u32 = comp_def_offset("draw_keno");
+const COMP_DEF_OFFSET_DROP_PLINKO: u32 = comp_def_offset("drop_plinko");
+const COMP_DEF_OFFSET_SPIN_WHEEL: u32 = comp_def_offset("spin_wheel");
+const COMP_DEF_OFFSET_DRAW_LOTTERY: u32 = comp_def_offset("draw_lottery");
+const COMP_DEF_OFFSET_CREATE_SCRATCH_CARD: u32 = comp_def_offset("create_scratch_card");
+const COMP_DEF_OFFSET_SCRATCH_TILE: u32 = comp_def_offset("scratch_tile");
+const COMP_DEF_OFFSET_REVEAL_SCRATCH_CARD: u32 = comp_def_offset("reveal_scratch_card");
+const COMP_DEF_OFFSET_ROLL_TWO_DICE: u32 = comp_def_offset("roll_two_dice");
+const COMP_DEF_OFFSET_ROLL_THREE_DICE: u32 = comp_def_offset("roll_three_dice");
+
 const COMP_DEF_OFFSET_ROLL_ROULETTE: u32 = comp_def_offset("roll_roulette");
 
 const COMP_DEF_OFFSET_SPIN_SLOTS: u32 = comp_def_offset("spin_slots");
@@ -95,6 +130,8 @@ pub mod casino_nchurch {
         client_nonce: u128,
         client_again_nonce: u128,
         bet_amount: u64,
+        perfect_pairs_bet: u64,
+        twenty_one_plus_three_bet: u64,
     ) -> Result<()> {
         instructions::arcium_blackjack::initialize_blackjack_game(
             ctx,
@@ -106,6 +143,8 @@ pub mod casino_nchurch {
             client_nonce,
             client_again_nonce,
             bet_amount,
+            perfect_pairs_bet,
+            twenty_one_plus_three_bet,
         )
     }
 
@@ -115,8 +154,9 @@ pub mod casino_nchurch {
     pub fn player_hit(
         ctx: Context<PlayerHit>,
         computation_offset: u64,
+        game_id: u64,
     ) -> Result<()> {
-        instructions::arcium_blackjack::player_hit(ctx, computation_offset)
+        instructions::arcium_blackjack::player_hit(ctx, computation_offset, game_id)
     }
 
     pub fn init_player_double_down_comp_def(
@@ -127,8 +167,9 @@ pub mod casino_nchurch {
     pub fn player_double_down(
         ctx: Context<PlayerDoubleDown>,
         computation_offset: u64,
+        game_id: u64,
     ) -> Result<()> {
-        instructions::arcium_blackjack::player_double_down(ctx, computation_offset)
+        instructions::arcium_blackjack::player_double_down(ctx, computation_offset, game_id)
     }
 
     pub fn init_player_stand_comp_def(ctx: Context<InitPlayerStandCompDef>) -> Result<()> {
@@ -137,8 +178,20 @@ pub mod casino_nchurch {
     pub fn player_stand(
         ctx: Context<PlayerStand>,
         computation_offset: u64,
+        game_id: u64,
     ) -> Result<()> {
-        instructions::arcium_blackjack::player_stand(ctx, computation_offset)
+        instructions::arcium_blackjack::player_stand(ctx, computation_offset, game_id)
+    }
+
+    pub fn init_player_surrender_comp_def(ctx: Context<InitPlayerSurrenderCompDef>) -> Result<()> {
+        instructions::arcium_blackjack::init_player_surrender_comp_def(ctx)
+    }
+    pub fn player_surrender(
+        ctx: Context<PlayerSurrender>,
+        computation_offset: u64,
+        game_id: u64,
+    ) -> Result<()> {
+        instructions::arcium_blackjack::player_surrender(ctx, computation_offset, game_id)
     }
 
     pub fn init_dealer_play_comp_def(ctx: Context<InitDealerPlayCompDef>) -> Result<()> {
@@ -147,9 +200,14 @@ pub mod casino_nchurch {
     pub fn dealer_play(
         ctx: Context<DealerPlay>,
         computation_offset: u64,
+        game_id: u64,
         client_nonce: u128,
     ) -> Result<()> {
-        instructions::arcium_blackjack::dealer_play(ctx, computation_offset, client_nonce)
+        instructions::arcium_blackjack::dealer_play(ctx, computation_offset, game_id, client_nonce)
+    }
+
+    pub fn close_blackjack_game(ctx: Context<CloseBlackjackGame>, game_id: u64) -> Result<()> {
+        instructions::arcium_blackjack::close_blackjack_game(ctx, game_id)
     }
 
     pub fn init_resolve_game_comp_def(ctx: Context<InitResolveGameCompDef>) -> Result<()> {
@@ -158,8 +216,175 @@ pub mod casino_nchurch {
     pub fn resolve_game(
         ctx: Context<ResolveGame>,
         computation_offset: u64,
+        game_id: u64,
+    ) -> Result<()> {
+        instructions::arcium_blackjack::resolve_game(ctx, computation_offset, game_id)
+    }
+
+    // Arcium Blackjack shoe
+    pub fn init_shuffle_shoe_comp_def(ctx: Context<InitShuffleShoeCompDef>) -> Result<()> {
+        instructions::arcium_blackjack_shoe::init_shuffle_shoe_comp_def(ctx)
+    }
+
+    pub fn init_deal_from_shoe_comp_def(ctx: Context<InitDealFromShoeCompDef>) -> Result<()> {
+        instructions::arcium_blackjack_shoe::init_deal_from_shoe_comp_def(ctx)
+    }
+
+    pub fn create_blackjack_shoe(
+        ctx: Context<CreateBlackjackShoe>,
+        computation_offset: u64,
+        shoe_id: u64,
+        cut_card_position: u16,
+        mxe_nonce: u128,
+    ) -> Result<()> {
+        instructions::arcium_blackjack_shoe::create_blackjack_shoe(ctx, computation_offset, shoe_id, cut_card_position, mxe_nonce)
+    }
+
+    pub fn reshuffle_blackjack_shoe(
+        ctx: Context<ReshuffleBlackjackShoe>,
+        computation_offset: u64,
+        shoe_id: u64,
+        mxe_nonce: u128,
+    ) -> Result<()> {
+        instructions::arcium_blackjack_shoe::reshuffle_blackjack_shoe(ctx, computation_offset, shoe_id, mxe_nonce)
+    }
+
+    pub fn deal_blackjack_from_shoe(
+        ctx: Context<DealBlackjackFromShoe>,
+        computation_offset: u64,
+        game_id: u64,
+        shoe_id: u64,
+        mxe_nonce: u128,
+        mxe_again_nonce: u128,
+        client_pubkey: [u8; 32],
+        client_nonce: u128,
+        client_again_nonce: u128,
+        bet_amount: u64,
+    ) -> Result<()> {
+        instructions::arcium_blackjack_shoe::deal_blackjack_from_shoe(
+            ctx,
+            computation_offset,
+            game_id,
+            shoe_id,
+            mxe_nonce,
+            mxe_again_nonce,
+            client_pubkey,
+            client_nonce,
+            client_again_nonce,
+            bet_amount,
+        )
+    }
+
+    pub fn release_blackjack_shoe(ctx: Context<ReleaseBlackjackShoe>, shoe_id: u64, game_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_shoe::release_blackjack_shoe(ctx, shoe_id, game_id)
+    }
+
+    // Arcium Blackjack inactivity crank
+    pub fn set_blackjack_config(
+        ctx: Context<SetBlackjackConfig>,
+        timeout_slots: u64,
+        crank_fee_bps: u16,
+        rules: BlackjackRules,
+    ) -> Result<()> {
+        instructions::arcium_blackjack_crank::set_blackjack_config(ctx, timeout_slots, crank_fee_bps, rules)
+    }
+
+    pub fn set_blackjack_side_bet_paytable(
+        ctx: Context<SetBlackjackSideBetPaytable>,
+        paytable: BlackjackSideBetPaytable,
     ) -> Result<()> {
-        instructions::arcium_blackjack::resolve_game(ctx, computation_offset)
+        instructions::arcium_blackjack_crank::set_blackjack_side_bet_paytable(ctx, paytable)
+    }
+
+    pub fn crank_blackjack_stand(ctx: Context<CrankBlackjackStand>, computation_offset: u64, game_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_crank::crank_blackjack_stand(ctx, computation_offset, game_id)
+    }
+
+    pub fn recover_blackjack_game(ctx: Context<RecoverBlackjackGame>, game_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_crank::recover_blackjack_game(ctx, game_id)
+    }
+
+    // Arcium Blackjack post-game deck audit
+    pub fn init_reveal_deck_comp_def(ctx: Context<InitRevealDeckCompDef>) -> Result<()> {
+        instructions::arcium_blackjack_audit::init_reveal_deck_comp_def(ctx)
+    }
+
+    pub fn reveal_blackjack_deck(ctx: Context<RevealBlackjackDeck>, computation_offset: u64, game_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_audit::reveal_blackjack_deck(ctx, computation_offset, game_id)
+    }
+
+    // Arcium Blackjack multi-seat tables
+    pub fn init_deal_table_comp_def(ctx: Context<InitDealTableCompDef>) -> Result<()> {
+        instructions::arcium_blackjack_table::init_deal_table_comp_def(ctx)
+    }
+
+    pub fn init_table_player_hit_comp_def(ctx: Context<InitTablePlayerHitCompDef>) -> Result<()> {
+        instructions::arcium_blackjack_table::init_table_player_hit_comp_def(ctx)
+    }
+
+    pub fn init_table_dealer_play_comp_def(ctx: Context<InitTableDealerPlayCompDef>) -> Result<()> {
+        instructions::arcium_blackjack_table::init_table_dealer_play_comp_def(ctx)
+    }
+
+    pub fn init_resolve_table_comp_def(ctx: Context<InitResolveTableCompDef>) -> Result<()> {
+        instructions::arcium_blackjack_table::init_resolve_table_comp_def(ctx)
+    }
+
+    pub fn create_blackjack_table(ctx: Context<CreateBlackjackTable>, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::create_blackjack_table(ctx, table_id)
+    }
+
+    pub fn join_blackjack_table(
+        ctx: Context<JoinBlackjackTable>,
+        table_id: u64,
+        seat_index: u8,
+        client_pubkey: [u8; 32],
+        bet_amount: u64,
+    ) -> Result<()> {
+        instructions::arcium_blackjack_table::join_blackjack_table(ctx, table_id, seat_index, client_pubkey, bet_amount)
+    }
+
+    pub fn deal_blackjack_table(
+        ctx: Context<DealBlackjackTable>,
+        computation_offset: u64,
+        table_id: u64,
+        mxe_nonce: u128,
+        mxe_again_nonce: u128,
+        seat_nonces: [u128; 5],
+    ) -> Result<()> {
+        instructions::arcium_blackjack_table::deal_blackjack_table(ctx, computation_offset, table_id, mxe_nonce, mxe_again_nonce, seat_nonces)
+    }
+
+    pub fn table_player_hit(ctx: Context<TablePlayerHit>, computation_offset: u64, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::table_player_hit(ctx, computation_offset, table_id)
+    }
+
+    pub fn table_player_stand(ctx: Context<TablePlayerStand>, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::table_player_stand(ctx, table_id)
+    }
+
+    pub fn crank_blackjack_table_stand(ctx: Context<CrankBlackjackTableStand>, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::crank_blackjack_table_stand(ctx, table_id)
+    }
+
+    pub fn leave_blackjack_table(ctx: Context<LeaveBlackjackTable>, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::leave_blackjack_table(ctx, table_id)
+    }
+
+    pub fn recover_blackjack_table(ctx: Context<RecoverBlackjackTable>, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::recover_blackjack_table(ctx, table_id)
+    }
+
+    pub fn table_dealer_play(ctx: Context<TableDealerPlay>, computation_offset: u64, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::table_dealer_play(ctx, computation_offset, table_id)
+    }
+
+    pub fn resolve_blackjack_table(ctx: Context<ResolveBlackjackTable>, computation_offset: u64, table_id: u64) -> Result<()> {
+        instructions::arcium_blackjack_table::resolve_blackjack_table(ctx, computation_offset, table_id)
+    }
+
+    pub fn claim_blackjack_table_seat(ctx: Context<ClaimBlackjackTableSeat>, table_id: u64, seat_index: u8) -> Result<()> {
+        instructions::arcium_blackjack_table::claim_blackjack_table_seat(ctx, table_id, seat_index)
     }
 
     // Arcium Dice
@@ -178,6 +403,322 @@ pub mod casino_nchurch {
         instructions::arcium_dice::roll_dice(ctx, computation_offset, bet_amount, bet_type, param, nonce)
     }
 
+    // Arcium Aviator
+    pub fn init_crash_aviator_comp_def(ctx: Context<InitCrashAviatorCompDef>) -> Result<()> {
+        instructions::arcium_aviator::init_crash_aviator_comp_def(ctx)
+    }
+
+    pub fn play_aviator(
+        ctx: Context<PlayAviator>,
+        computation_offset: u64,
+        game_id: u64,
+        bet_amount: u64,
+        cashout_multiplier: u64,
+        nonce: u128,
+    ) -> Result<()> {
+        instructions::arcium_aviator::play_aviator(ctx, computation_offset, game_id, bet_amount, cashout_multiplier, nonce)
+    }
+
+    // Arcium multiplayer crash rounds
+    pub fn init_start_crash_round_comp_def(ctx: Context<InitStartCrashRoundCompDef>) -> Result<()> {
+        instructions::arcium_crash_round::init_start_crash_round_comp_def(ctx)
+    }
+
+    pub fn init_check_crash_round_comp_def(ctx: Context<InitCheckCrashRoundCompDef>) -> Result<()> {
+        instructions::arcium_crash_round::init_check_crash_round_comp_def(ctx)
+    }
+
+    pub fn create_crash_round(
+        ctx: Context<CreateCrashRound>,
+        computation_offset: u64,
+        round_id: u64,
+        betting_slots: u64,
+        mxe_nonce: u128,
+    ) -> Result<()> {
+        instructions::arcium_crash_round::create_crash_round(ctx, computation_offset, round_id, betting_slots, mxe_nonce)
+    }
+
+    pub fn join_crash_round(ctx: Context<JoinCrashRound>, round_id: u64, bet_amount: u64) -> Result<()> {
+        instructions::arcium_crash_round::join_crash_round(ctx, round_id, bet_amount)
+    }
+
+    pub fn cash_out(ctx: Context<CashOut>, round_id: u64) -> Result<()> {
+        instructions::arcium_crash_round::cash_out(ctx, round_id)
+    }
+
+    pub fn check_crash_round(ctx: Context<CheckCrashRound>, computation_offset: u64, round_id: u64) -> Result<()> {
+        instructions::arcium_crash_round::check_crash_round(ctx, computation_offset, round_id)
+    }
+
+    pub fn settle_crash_bet(ctx: Context<SettleCrashBet>, round_id: u64, bet_index: u8) -> Result<()> {
+        instructions::arcium_crash_round::settle_crash_bet(ctx, round_id, bet_index)
+    }
+
+    // Arcium Memecoin simulator
+    pub fn init_generate_price_path_comp_def(ctx: Context<InitGeneratePricePathCompDef>) -> Result<()> {
+        instructions::arcium_memecoin::init_generate_price_path_comp_def(ctx)
+    }
+
+    pub fn init_reveal_price_step_comp_def(ctx: Context<InitRevealPriceStepCompDef>) -> Result<()> {
+        instructions::arcium_memecoin::init_reveal_price_step_comp_def(ctx)
+    }
+
+    pub fn set_memecoin_config(ctx: Context<SetMemecoinConfig>, rtp_bps: u16) -> Result<()> {
+        instructions::arcium_memecoin::set_memecoin_config(ctx, rtp_bps)
+    }
+
+    pub fn buy_memecoin(ctx: Context<BuyMemecoin>, computation_offset: u64, game_id: u64, bet_amount: u64, mxe_nonce: u128) -> Result<()> {
+        instructions::arcium_memecoin::buy_memecoin(ctx, computation_offset, game_id, bet_amount, mxe_nonce)
+    }
+
+    pub fn advance_memecoin(ctx: Context<AdvanceMemecoin>, computation_offset: u64, game_id: u64) -> Result<()> {
+        instructions::arcium_memecoin::advance_memecoin(ctx, computation_offset, game_id)
+    }
+
+    pub fn sell_memecoin(ctx: Context<SellMemecoin>, game_id: u64) -> Result<()> {
+        instructions::arcium_memecoin::sell_memecoin(ctx, game_id)
+    }
+
+    pub fn init_generate_mines_comp_def(ctx: Context<InitGenerateMinesCompDef>) -> Result<()> {
+        instructions::arcium_mines::init_generate_mines_comp_def(ctx)
+    }
+
+    pub fn init_reveal_tile_comp_def(ctx: Context<InitRevealTileCompDef>) -> Result<()> {
+        instructions::arcium_mines::init_reveal_tile_comp_def(ctx)
+    }
+
+    pub fn init_reveal_mines_comp_def(ctx: Context<InitRevealMinesCompDef>) -> Result<()> {
+        instructions::arcium_mines::init_reveal_mines_comp_def(ctx)
+    }
+
+    pub fn set_mines_config(ctx: Context<SetMinesConfig>, rtp_bps: u16) -> Result<()> {
+        instructions::arcium_mines::set_mines_config(ctx, rtp_bps)
+    }
+
+    pub fn start_mines(ctx: Context<StartMines>, computation_offset: u64, game_id: u64, bet_amount: u64, grid_size: u8, mine_count: u8, mxe_nonce: u128) -> Result<()> {
+        instructions::arcium_mines::start_mines(ctx, computation_offset, game_id, bet_amount, grid_size, mine_count, mxe_nonce)
+    }
+
+    pub fn reveal_tile(ctx: Context<RevealTile>, computation_offset: u64, game_id: u64, tile: u8) -> Result<()> {
+        instructions::arcium_mines::reveal_tile(ctx, computation_offset, game_id, tile)
+    }
+
+    pub fn cash_out_mines(ctx: Context<CashOutMines>, computation_offset: u64, game_id: u64) -> Result<()> {
+        instructions::arcium_mines::cash_out_mines(ctx, computation_offset, game_id)
+    }
+
+    pub fn init_shuffle_hilo_deck_comp_def(ctx: Context<InitShuffleHiloDeckCompDef>) -> Result<()> {
+        instructions::arcium_hilo::init_shuffle_hilo_deck_comp_def(ctx)
+    }
+
+    pub fn init_draw_hilo_card_comp_def(ctx: Context<InitDrawHiloCardCompDef>) -> Result<()> {
+        instructions::arcium_hilo::init_draw_hilo_card_comp_def(ctx)
+    }
+
+    pub fn set_hilo_config(ctx: Context<SetHiloConfig>, rtp_bps: u16) -> Result<()> {
+        instructions::arcium_hilo::set_hilo_config(ctx, rtp_bps)
+    }
+
+    pub fn start_hilo(ctx: Context<StartHilo>, computation_offset: u64, game_id: u64, bet_amount: u64, mxe_nonce: u128) -> Result<()> {
+        instructions::arcium_hilo::start_hilo(ctx, computation_offset, game_id, bet_amount, mxe_nonce)
+    }
+
+    pub fn guess_hilo(ctx: Context<GuessHilo>, computation_offset: u64, game_id: u64, guess: HiloGuess) -> Result<()> {
+        instructions::arcium_hilo::guess_hilo(ctx, computation_offset, game_id, guess)
+    }
+
+    pub fn cash_out_hilo(ctx: Context<CashOutHilo>, game_id: u64) -> Result<()> {
+        instructions::arcium_hilo::cash_out_hilo(ctx, game_id)
+    }
+
+    pub fn init_deal_video_poker_comp_def(ctx: Context<InitDealVideoPokerCompDef>) -> Result<()> {
+        instructions::arcium_video_poker::init_deal_video_poker_comp_def(ctx)
+    }
+
+    pub fn init_draw_video_poker_comp_def(ctx: Context<InitDrawVideoPokerCompDef>) -> Result<()> {
+        instructions::arcium_video_poker::init_draw_video_poker_comp_def(ctx)
+    }
+
+    pub fn init_resolve_video_poker_comp_def(ctx: Context<InitResolveVideoPokerCompDef>) -> Result<()> {
+        instructions::arcium_video_poker::init_resolve_video_poker_comp_def(ctx)
+    }
+
+    pub fn set_video_poker_paytable(ctx: Context<SetVideoPokerPaytable>, paytable: [u16; 10]) -> Result<()> {
+        instructions::arcium_video_poker::set_video_poker_paytable(ctx, paytable)
+    }
+
+    pub fn deal_video_poker(ctx: Context<DealVideoPoker>, computation_offset: u64, game_id: u64, bet_amount: u64, mxe_nonce: u128, client_pubkey: [u8; 32], client_nonce: u128) -> Result<()> {
+        instructions::arcium_video_poker::deal_video_poker(ctx, computation_offset, game_id, bet_amount, mxe_nonce, client_pubkey, client_nonce)
+    }
+
+    pub fn draw_video_poker(ctx: Context<DrawVideoPoker>, computation_offset: u64, game_id: u64, hold_mask: u8) -> Result<()> {
+        instructions::arcium_video_poker::draw_video_poker(ctx, computation_offset, game_id, hold_mask)
+    }
+
+    pub fn resolve_video_poker(ctx: Context<ResolveVideoPoker>, computation_offset: u64, game_id: u64) -> Result<()> {
+        instructions::arcium_video_poker::resolve_video_poker(ctx, computation_offset, game_id)
+    }
+
+    pub fn init_play_baccarat_comp_def(ctx: Context<InitPlayBaccaratCompDef>) -> Result<()> {
+        instructions::arcium_baccarat::init_play_baccarat_comp_def(ctx)
+    }
+
+    pub fn set_baccarat_config(ctx: Context<SetBaccaratConfig>, banker_commission_bps: u16, tie_payout: u8) -> Result<()> {
+        instructions::arcium_baccarat::set_baccarat_config(ctx, banker_commission_bps, tie_payout)
+    }
+
+    pub fn play_baccarat(ctx: Context<PlayBaccarat>, computation_offset: u64, game_id: u64, bet_amount: u64, bet: BaccaratBet, nonce: u128) -> Result<()> {
+        instructions::arcium_baccarat::play_baccarat(ctx, computation_offset, game_id, bet_amount, bet, nonce)
+    }
+
+    pub fn init_deal_three_card_poker_comp_def(ctx: Context<InitDealThreeCardPokerCompDef>) -> Result<()> {
+        instructions::arcium_three_card_poker::init_deal_three_card_poker_comp_def(ctx)
+    }
+
+    pub fn init_resolve_three_card_poker_comp_def(ctx: Context<InitResolveThreeCardPokerCompDef>) -> Result<()> {
+        instructions::arcium_three_card_poker::init_resolve_three_card_poker_comp_def(ctx)
+    }
+
+    pub fn set_three_card_poker_config(ctx: Context<SetThreeCardPokerConfig>, paytable: ThreeCardPokerPaytable) -> Result<()> {
+        instructions::arcium_three_card_poker::set_three_card_poker_config(ctx, paytable)
+    }
+
+    pub fn deal_three_card_poker(ctx: Context<DealThreeCardPoker>, computation_offset: u64, game_id: u64, ante: u64, pair_plus_bet: u64, mxe_nonce: u128, client_pubkey: [u8; 32], client_nonce: u128) -> Result<()> {
+        instructions::arcium_three_card_poker::deal_three_card_poker(ctx, computation_offset, game_id, ante, pair_plus_bet, mxe_nonce, client_pubkey, client_nonce)
+    }
+
+    pub fn decide_three_card_poker(ctx: Context<DecideThreeCardPoker>, computation_offset: u64, _game_id: u64, play: bool) -> Result<()> {
+        instructions::arcium_three_card_poker::decide_three_card_poker(ctx, computation_offset, _game_id, play)
+    }
+
+    pub fn init_draw_keno_comp_def(ctx: Context<InitDrawKenoCompDef>) -> Result<()> {
+        instructions::arcium_keno::init_draw_keno_comp_def(ctx)
+    }
+
+    pub fn set_keno_paytable(ctx: Context<SetKenoPaytable>, pick_count: u8, multipliers_bps: [u32; 11]) -> Result<()> {
+        instructions::arcium_keno::set_keno_paytable(ctx, pick_count, multipliers_bps)
+    }
+
+    pub fn play_keno(ctx: Context<PlayKeno>, computation_offset: u64, game_id: u64, bet_amount: u64, picks: Vec<u8>, nonce: u128) -> Result<()> {
+        instructions::arcium_keno::play_keno(ctx, computation_offset, game_id, bet_amount, picks, nonce)
+    }
+
+    pub fn init_drop_plinko_comp_def(ctx: Context<InitDropPlinkoCompDef>) -> Result<()> {
+        instructions::arcium_plinko::init_drop_plinko_comp_def(ctx)
+    }
+
+    pub fn set_plinko_config(ctx: Context<SetPlinkoConfig>, rtp_bps: u16) -> Result<()> {
+        instructions::arcium_plinko::set_plinko_config(ctx, rtp_bps)
+    }
+
+    pub fn set_plinko_table(ctx: Context<SetPlinkoTable>, rows: u8, risk: PlinkoRisk, multipliers_bps: [u32; 17]) -> Result<()> {
+        instructions::arcium_plinko::set_plinko_table(ctx, rows, risk, multipliers_bps)
+    }
+
+    pub fn play_plinko(ctx: Context<PlayPlinko>, computation_offset: u64, game_id: u64, bet_amount: u64, rows: u8, risk: PlinkoRisk, nonce: u128) -> Result<()> {
+        instructions::arcium_plinko::play_plinko(ctx, computation_offset, game_id, bet_amount, rows, risk, nonce)
+    }
+
+    pub fn init_spin_wheel_comp_def(ctx: Context<InitSpinWheelCompDef>) -> Result<()> {
+        instructions::arcium_big_six::init_spin_wheel_comp_def(ctx)
+    }
+
+    pub fn set_big_six_config(ctx: Context<SetBigSixConfig>, segment_count: u8, segments: [u8; 64], payouts: [u16; 8]) -> Result<()> {
+        instructions::arcium_big_six::set_big_six_config(ctx, segment_count, segments, payouts)
+    }
+
+    pub fn play_big_six(ctx: Context<PlayBigSix>, computation_offset: u64, game_id: u64, bets: [u64; 8], nonce: u128) -> Result<()> {
+        instructions::arcium_big_six::play_big_six(ctx, computation_offset, game_id, bets, nonce)
+    }
+
+    pub fn init_draw_lottery_comp_def(ctx: Context<InitDrawLotteryCompDef>) -> Result<()> {
+        instructions::arcium_lottery::init_draw_lottery_comp_def(ctx)
+    }
+
+    pub fn set_lottery_config(ctx: Context<SetLotteryConfig>, rtp_bps: u16) -> Result<()> {
+        instructions::arcium_lottery::set_lottery_config(ctx, rtp_bps)
+    }
+
+    pub fn create_lottery_round(ctx: Context<CreateLotteryRound>, round_id: u64, ticket_price: u64, pick_count: u8, max_number: u8, sales_end: i64, tier_shares_bps: [u16; 7]) -> Result<()> {
+        instructions::arcium_lottery::create_lottery_round(ctx, round_id, ticket_price, pick_count, max_number, sales_end, tier_shares_bps)
+    }
+
+    pub fn buy_lottery_ticket(ctx: Context<BuyLotteryTicket>, round_id: u64, ticket_index: u32, numbers: Vec<u8>) -> Result<()> {
+        instructions::arcium_lottery::buy_lottery_ticket(ctx, round_id, ticket_index, numbers)
+    }
+
+    pub fn draw_lottery(ctx: Context<DrawLottery>, computation_offset: u64, round_id: u64, mxe_nonce: u128) -> Result<()> {
+        instructions::arcium_lottery::draw_lottery(ctx, computation_offset, round_id, mxe_nonce)
+    }
+
+    pub fn claim_lottery_prize(ctx: Context<ClaimLotteryPrize>, round_id: u64, ticket_index: u32) -> Result<()> {
+        instructions::arcium_lottery::claim_lottery_prize(ctx, round_id, ticket_index)
+    }
+
+    pub fn rollover_lottery_round(ctx: Context<RolloverLotteryRound>, from_round_id: u64, to_round_id: u64) -> Result<()> {
+        instructions::arcium_lottery::rollover_lottery_round(ctx, from_round_id, to_round_id)
+    }
+
+    pub fn init_create_scratch_card_comp_def(ctx: Context<InitCreateScratchCardCompDef>) -> Result<()> {
+        instructions::arcium_scratch::init_create_scratch_card_comp_def(ctx)
+    }
+
+    pub fn init_scratch_tile_comp_def(ctx: Context<InitScratchTileCompDef>) -> Result<()> {
+        instructions::arcium_scratch::init_scratch_tile_comp_def(ctx)
+    }
+
+    pub fn init_reveal_scratch_card_comp_def(ctx: Context<InitRevealScratchCardCompDef>) -> Result<()> {
+        instructions::arcium_scratch::init_reveal_scratch_card_comp_def(ctx)
+    }
+
+    pub fn set_scratch_paytable(ctx: Context<SetScratchPaytable>, paytable: [u32; 8]) -> Result<()> {
+        instructions::arcium_scratch::set_scratch_paytable(ctx, paytable)
+    }
+
+    pub fn buy_scratch_card(ctx: Context<BuyScratchCard>, computation_offset: u64, game_id: u64, bet_amount: u64, mxe_nonce: u128, client_pubkey: [u8; 32]) -> Result<()> {
+        instructions::arcium_scratch::buy_scratch_card(ctx, computation_offset, game_id, bet_amount, mxe_nonce, client_pubkey)
+    }
+
+    pub fn scratch_tile(ctx: Context<ScratchTile>, computation_offset: u64, _game_id: u64, tile: u8, client_nonce: u128) -> Result<()> {
+        instructions::arcium_scratch::scratch_tile(ctx, computation_offset, _game_id, tile, client_nonce)
+    }
+
+    pub fn claim_scratch_card(ctx: Context<ClaimScratchCard>, computation_offset: u64, _game_id: u64) -> Result<()> {
+        instructions::arcium_scratch::claim_scratch_card(ctx, computation_offset, _game_id)
+    }
+
+    pub fn init_roll_two_dice_comp_def(ctx: Context<InitRollTwoDiceCompDef>) -> Result<()> {
+        instructions::arcium_craps::init_roll_two_dice_comp_def(ctx)
+    }
+
+    pub fn open_craps_game(ctx: Context<OpenCrapsGame>, game_id: u64) -> Result<()> {
+        instructions::arcium_craps::open_craps_game(ctx, game_id)
+    }
+
+    pub fn place_craps_bet(ctx: Context<PlaceCrapsBet>, _game_id: u64, bet: CrapsBet, number: u8, amount: u64) -> Result<()> {
+        instructions::arcium_craps::place_craps_bet(ctx, _game_id, bet, number, amount)
+    }
+
+    pub fn roll_craps(ctx: Context<RollCraps>, computation_offset: u64, _game_id: u64, nonce: u128) -> Result<()> {
+        instructions::arcium_craps::roll_craps(ctx, computation_offset, _game_id, nonce)
+    }
+
+    pub fn close_craps_game(ctx: Context<CloseCrapsGame>, _game_id: u64) -> Result<()> {
+        instructions::arcium_craps::close_craps_game(ctx, _game_id)
+    }
+
+    pub fn init_roll_three_dice_comp_def(ctx: Context<InitRollThreeDiceCompDef>) -> Result<()> {
+        instructions::arcium_sic_bo::init_roll_three_dice_comp_def(ctx)
+    }
+
+    pub fn set_sic_bo_config(ctx: Context<SetSicBoConfig>, payouts: [u16; 44]) -> Result<()> {
+        instructions::arcium_sic_bo::set_sic_bo_config(ctx, payouts)
+    }
+
+    pub fn play_sic_bo(ctx: Context<PlaySicBo>, computation_offset: u64, game_id: u64, bets: [u64; 44], nonce: u128) -> Result<()> {
+        instructions::arcium_sic_bo::play_sic_bo(ctx, computation_offset, game_id, bets, nonce)
+    }
+
     pub fn spin_slots(ctx: Context<SpinSlots>, computation_offset: u64, bet_amount: u64, nonce: u128) -> Result<()> {
         instructions::arcium_slots::spin_slots(ctx, computation_offset, bet_amount, nonce)
     }
@@ -217,6 +758,11 @@ pub mod casino_nchurch {
         instructions::arcium_blackjack::player_stand_callback(ctx, output)
     }
 
+    #[arcium_callback(encrypted_ix = "player_surrender")]
+    pub fn player_surrender_callback(ctx: Context<PlayerSurrenderCallback>, output: ComputationOutputs<PlayerSurrenderOutput>) -> Result<()> {
+        instructions::arcium_blackjack::player_surrender_callback(ctx, output)
+    }
+
     #[arcium_callback(encrypted_ix = "dealer_play")]
     pub fn dealer_play_callback(ctx: Context<DealerPlayCallback>, output: ComputationOutputs<DealerPlayOutput>) -> Result<()> {
         instructions::arcium_blackjack::dealer_play_callback(ctx, output)
@@ -227,11 +773,171 @@ pub mod casino_nchurch {
         instructions::arcium_blackjack::resolve_game_callback(ctx, output)
     }
 
+    #[arcium_callback(encrypted_ix = "shuffle_shoe")]
+    pub fn shuffle_shoe_callback(ctx: Context<ShuffleShoeCallback>, output: ComputationOutputs<ShuffleShoeOutput>) -> Result<()> {
+        instructions::arcium_blackjack_shoe::shuffle_shoe_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "deal_from_shoe")]
+    pub fn deal_from_shoe_callback(ctx: Context<DealFromShoeCallback>, output: ComputationOutputs<DealFromShoeOutput>) -> Result<()> {
+        instructions::arcium_blackjack_shoe::deal_from_shoe_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "deal_table")]
+    pub fn deal_table_callback(ctx: Context<DealTableCallback>, output: ComputationOutputs<DealTableOutput>) -> Result<()> {
+        instructions::arcium_blackjack_table::deal_table_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "table_player_hit")]
+    pub fn table_player_hit_callback(ctx: Context<TablePlayerHitCallback>, output: ComputationOutputs<TablePlayerHitOutput>) -> Result<()> {
+        instructions::arcium_blackjack_table::table_player_hit_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "table_dealer_play")]
+    pub fn table_dealer_play_callback(ctx: Context<TableDealerPlayCallback>, output: ComputationOutputs<TableDealerPlayOutput>) -> Result<()> {
+        instructions::arcium_blackjack_table::table_dealer_play_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "resolve_table")]
+    pub fn resolve_table_callback(ctx: Context<ResolveTableCallback>, output: ComputationOutputs<ResolveTableOutput>) -> Result<()> {
+        instructions::arcium_blackjack_table::resolve_table_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_deck")]
+    pub fn reveal_deck_callback(ctx: Context<RevealDeckCallback>, output: ComputationOutputs<RevealDeckOutput>) -> Result<()> {
+        instructions::arcium_blackjack_audit::reveal_deck_callback(ctx, output)
+    }
+
     #[arcium_callback(encrypted_ix = "roll_dice")]
     pub fn roll_dice_callback(ctx: Context<RollDiceCallback>, output: ComputationOutputs<RollDiceOutput>) -> Result<()> {
         instructions::arcium_dice::roll_dice_callback(ctx, output)
     }
 
+    #[arcium_callback(encrypted_ix = "crash_aviator")]
+    pub fn crash_aviator_callback(ctx: Context<CrashAviatorCallback>, output: ComputationOutputs<CrashAviatorOutput>) -> Result<()> {
+        instructions::arcium_aviator::crash_aviator_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "start_crash_round")]
+    pub fn start_crash_round_callback(ctx: Context<StartCrashRoundCallback>, output: ComputationOutputs<StartCrashRoundOutput>) -> Result<()> {
+        instructions::arcium_crash_round::start_crash_round_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "check_crash_round")]
+    pub fn check_crash_round_callback(ctx: Context<CheckCrashRoundCallback>, output: ComputationOutputs<CheckCrashRoundOutput>) -> Result<()> {
+        instructions::arcium_crash_round::check_crash_round_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "generate_price_path")]
+    pub fn generate_price_path_callback(ctx: Context<GeneratePricePathCallback>, output: ComputationOutputs<GeneratePricePathOutput>) -> Result<()> {
+        instructions::arcium_memecoin::generate_price_path_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_price_step")]
+    pub fn reveal_price_step_callback(ctx: Context<RevealPriceStepCallback>, output: ComputationOutputs<RevealPriceStepOutput>) -> Result<()> {
+        instructions::arcium_memecoin::reveal_price_step_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "generate_mines")]
+    pub fn generate_mines_callback(ctx: Context<GenerateMinesCallback>, output: ComputationOutputs<GenerateMinesOutput>) -> Result<()> {
+        instructions::arcium_mines::generate_mines_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_tile")]
+    pub fn reveal_tile_callback(ctx: Context<RevealTileCallback>, output: ComputationOutputs<RevealTileOutput>) -> Result<()> {
+        instructions::arcium_mines::reveal_tile_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_mines")]
+    pub fn reveal_mines_callback(ctx: Context<RevealMinesCallback>, output: ComputationOutputs<RevealMinesOutput>) -> Result<()> {
+        instructions::arcium_mines::reveal_mines_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "shuffle_hilo_deck")]
+    pub fn shuffle_hilo_deck_callback(ctx: Context<ShuffleHiloDeckCallback>, output: ComputationOutputs<ShuffleHiloDeckOutput>) -> Result<()> {
+        instructions::arcium_hilo::shuffle_hilo_deck_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "draw_hilo_card")]
+    pub fn draw_hilo_card_callback(ctx: Context<DrawHiloCardCallback>, output: ComputationOutputs<DrawHiloCardOutput>) -> Result<()> {
+        instructions::arcium_hilo::draw_hilo_card_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "deal_video_poker")]
+    pub fn deal_video_poker_callback(ctx: Context<DealVideoPokerCallback>, output: ComputationOutputs<DealVideoPokerOutput>) -> Result<()> {
+        instructions::arcium_video_poker::deal_video_poker_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "draw_video_poker")]
+    pub fn draw_video_poker_callback(ctx: Context<DrawVideoPokerCallback>, output: ComputationOutputs<DrawVideoPokerOutput>) -> Result<()> {
+        instructions::arcium_video_poker::draw_video_poker_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "resolve_video_poker")]
+    pub fn resolve_video_poker_callback(ctx: Context<ResolveVideoPokerCallback>, output: ComputationOutputs<ResolveVideoPokerOutput>) -> Result<()> {
+        instructions::arcium_video_poker::resolve_video_poker_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "play_baccarat")]
+    pub fn play_baccarat_callback(ctx: Context<PlayBaccaratCallback>, output: ComputationOutputs<PlayBaccaratOutput>) -> Result<()> {
+        instructions::arcium_baccarat::play_baccarat_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "deal_three_card_poker")]
+    pub fn deal_three_card_poker_callback(ctx: Context<DealThreeCardPokerCallback>, output: ComputationOutputs<DealThreeCardPokerOutput>) -> Result<()> {
+        instructions::arcium_three_card_poker::deal_three_card_poker_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "resolve_three_card_poker")]
+    pub fn resolve_three_card_poker_callback(ctx: Context<ResolveThreeCardPokerCallback>, output: ComputationOutputs<ResolveThreeCardPokerOutput>) -> Result<()> {
+        instructions::arcium_three_card_poker::resolve_three_card_poker_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "draw_keno")]
+    pub fn draw_keno_callback(ctx: Context<DrawKenoCallback>, output: ComputationOutputs<DrawKenoOutput>) -> Result<()> {
+        instructions::arcium_keno::draw_keno_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "drop_plinko")]
+    pub fn drop_plinko_callback(ctx: Context<DropPlinkoCallback>, output: ComputationOutputs<DropPlinkoOutput>) -> Result<()> {
+        instructions::arcium_plinko::drop_plinko_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "spin_wheel")]
+    pub fn spin_wheel_callback(ctx: Context<SpinWheelCallback>, output: ComputationOutputs<SpinWheelOutput>) -> Result<()> {
+        instructions::arcium_big_six::spin_wheel_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "draw_lottery")]
+    pub fn draw_lottery_callback(ctx: Context<DrawLotteryCallback>, output: ComputationOutputs<DrawLotteryOutput>) -> Result<()> {
+        instructions::arcium_lottery::draw_lottery_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "create_scratch_card")]
+    pub fn create_scratch_card_callback(ctx: Context<CreateScratchCardCallback>, output: ComputationOutputs<CreateScratchCardOutput>) -> Result<()> {
+        instructions::arcium_scratch::create_scratch_card_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "scratch_tile")]
+    pub fn scratch_tile_callback(ctx: Context<ScratchTileCallback>, output: ComputationOutputs<ScratchTileOutput>) -> Result<()> {
+        instructions::arcium_scratch::scratch_tile_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_scratch_card")]
+    pub fn reveal_scratch_card_callback(ctx: Context<RevealScratchCardCallback>, output: ComputationOutputs<RevealScratchCardOutput>) -> Result<()> {
+        instructions::arcium_scratch::reveal_scratch_card_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "roll_two_dice")]
+    pub fn roll_two_dice_callback(ctx: Context<RollTwoDiceCallback>, output: ComputationOutputs<RollTwoDiceOutput>) -> Result<()> {
+        instructions::arcium_craps::roll_two_dice_callback(ctx, output)
+    }
+
+    #[arcium_callback(encrypted_ix = "roll_three_dice")]
+    pub fn roll_three_dice_callback(ctx: Context<RollThreeDiceCallback>, output: ComputationOutputs<RollThreeDiceOutput>) -> Result<()> {
+        instructions::arcium_sic_bo::roll_three_dice_callback(ctx, output)
+    }
+
 
 
 
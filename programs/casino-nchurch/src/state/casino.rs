@@ -143,14 +143,819 @@ pub struct CoinflipGame {
 #[derive(InitSpace)]
 pub struct AviatorGame {
     pub user: Pubkey,
+    pub game_id: u64,
+    pub bet_amount: u64,
+    /// Auto-cashout target in hundredths (150 = 1.50x), fixed at bet time
+    pub cashout_multiplier: u64,
+    /// Crash point revealed by MPC, in hundredths; 0 until the round ends
+    pub crash_multiplier: u64,
+    pub payout: u64,
+    pub is_complete: bool,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MemecoinConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Share of the sale value paid out, e.g. 9600 = 96% RTP
+    pub rtp_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MemecoinGame {
+    /// Encrypted price path, 16 per-mille prices packed into 2 chunks
+    pub price_path: [[u8; 32]; 2],
+    /// Cryptographic nonce for the price path encryption
+    pub path_nonce: u128,
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that bought in
+    pub player: Pubkey,
+    /// Entry stake
+    pub bet_amount: u64,
+    /// Price steps revealed so far
+    pub step: u8,
+    /// Latest revealed price, per-mille of the entry price
+    pub current_price: u64,
+    /// Current phase of the game
+    pub game_state: MemecoinGameState,
+    /// Amount paid on sale
+    pub payout: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MemecoinGameState {
+    /// Waiting for MPC to generate the price path
+    Generating = 0,
+    /// Player may sell at `current_price` or reveal the next step
+    Trading = 1,
+    /// Next price step is being revealed
+    Revealing = 2,
+    /// Player sold
+    Sold = 3,
+    /// Price went to zero
+    Rugged = 4,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MinesConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Share of the fair multiplier paid out, e.g. 9700 = 97% RTP
+    pub rtp_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MinesGame {
+    /// Mine layout bitmask encrypted to the MXE
+    pub layout_ciphertext: [u8; 32],
+    /// Cryptographic nonce for the layout encryption
+    pub layout_nonce: u128,
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bet
+    pub player: Pubkey,
+    /// Stake escrowed in the vault
+    pub bet_amount: u64,
+    /// Tiles in the grid (side length squared)
+    pub tile_count: u8,
+    /// Mines hidden among the tiles
+    pub mine_count: u8,
+    /// Tiles revealed safe so far, one bit per tile
+    pub revealed_mask: u32,
+    /// Number of safe reveals
+    pub safe_reveals: u8,
+    /// Tile whose reveal is in flight
+    pub pending_tile: u8,
+    /// Current phase of the game
+    pub game_state: MinesGameState,
+    /// Full layout, public once the game has ended (0 until then)
+    pub mine_layout: u32,
+    /// Amount paid on cash-out
+    pub payout: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinesGameState {
+    /// Waiting for MPC to place the mines
+    Generating = 0,
+    /// Player may reveal a tile or cash out
+    Playing = 1,
+    /// A tile reveal is in flight
+    Revealing = 2,
+    /// Cash-out is waiting for the layout reveal
+    CashingOut = 3,
+    /// Player hit a mine
+    Lost = 4,
+    /// Player cashed out
+    CashedOut = 5,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct HiloConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Share of the ladder value paid out, e.g. 9700 = 97% RTP
+    pub rtp_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct HiloGame {
+    /// Shuffled deck encrypted to the MXE, in the blackjack `Deck` packing
+    pub deck: [[u8; 32]; 3],
+    /// Cryptographic nonce for the deck encryption
+    pub deck_nonce: u128,
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bet
+    pub player: Pubkey,
+    /// Stake escrowed in the vault
+    pub bet_amount: u64,
+    /// Stake value after the correct guesses so far, before RTP
+    pub ladder_value: u64,
+    /// Card currently showing
+    pub current_card: u8,
+    /// Cards revealed so far, the showing card included
+    pub cards_drawn: u8,
+    /// Unseen cards left in the deck per rank (ace low)
+    pub rank_counts: [u8; 13],
+    /// Correct guesses so far
+    pub rungs: u8,
+    /// Guess waiting on the next card
+    pub pending_guess: HiloGuess,
+    /// Current phase of the game
+    pub game_state: HiloGameState,
+    /// Amount paid on cash-out
+    pub payout: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HiloGuess {
+    /// Next card ranks strictly higher
+    Higher = 0,
+    /// Next card ranks strictly lower
+    Lower = 1,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HiloGameState {
+    /// Waiting for MPC to shuffle the deck
+    Shuffling = 0,
+    /// Player may guess or cash out
+    Playing = 1,
+    /// A guess is waiting on the next card
+    Drawing = 2,
+    /// A guess missed
+    Lost = 3,
+    /// Player cashed out
+    CashedOut = 4,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VideoPokerGame {
+    /// Shuffled deck encrypted to the MXE
+    pub deck: [[u8; 32]; 3],
+    /// Player's five cards encrypted to the player
+    pub hand: [u8; 32],
+    /// Cryptographic nonce for the deck encryption
+    pub deck_nonce: u128,
+    /// Cryptographic nonce for the hand encryption
+    pub hand_nonce: u128,
+    /// Player's encryption public key
+    pub player_enc_pubkey: [u8; 32],
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bet
+    pub player: Pubkey,
+    /// Stake escrowed in the vault
+    pub bet_amount: u64,
+    /// Cards kept on the draw, bit i for slot i
+    pub hold_mask: u8,
+    /// Rank revealed by `resolve_video_poker`
+    pub hand_rank: u8,
+    /// Paytable snapshotted from the config at the deal
+    pub paytable: [u16; 10],
+    /// Current phase of the game
+    pub game_state: VideoPokerGameState,
+    /// Amount paid at resolution
+    pub payout: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VideoPokerGameState {
+    /// Waiting for MPC to shuffle and deal
+    Dealing = 0,
+    /// Player chooses which cards to hold
+    Holding = 1,
+    /// Replacement cards are being drawn
+    Drawing = 2,
+    /// Final hand is ready to be ranked
+    Drawn = 3,
+    /// Hand rank is being revealed
+    Resolving = 4,
+    /// Hand ranked and paid
+    Resolved = 5,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BaccaratGame {
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bet
+    pub player: Pubkey,
+    /// Stake escrowed in the vault
     pub bet_amount: u64,
-    pub cashout_multiplier: f64,
-    pub crash_multiplier: f64,
+    /// Side the player backed
+    pub bet: BaccaratBet,
+    /// Commission on winning banker bets, snapshotted from the config
+    pub banker_commission_bps: u16,
+    /// Tie payout, x-to-1, snapshotted from the config
+    pub tie_payout: u8,
+    /// Final player total (revealed)
+    pub player_total: u8,
+    /// Final banker total (revealed)
+    pub banker_total: u8,
+    /// Winning side (revealed)
+    pub winner: BaccaratBet,
+    /// Amount returned to the player, stake included
     pub payout: u64,
+    /// Whether the coup has been played and settled
     pub is_complete: bool,
+    /// PDA bump seed
     pub bump: u8,
 }
 
+/// Bet sides, which double as the winner codes revealed by `play_baccarat`
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BaccaratBet {
+    Player = 0,
+    Banker = 1,
+    Tie = 2,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BaccaratConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Commission taken from winning banker bets, e.g. 500 = 5%
+    pub banker_commission_bps: u16,
+    /// Tie bet payout, x-to-1, e.g. 8
+    pub tie_payout: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ThreeCardPokerGame {
+    /// Player's three cards encrypted to the player
+    pub player_hand: [u8; 32],
+    /// Dealer's three cards encrypted to the MXE until the player decides
+    pub dealer_hand: [u8; 32],
+    /// Cryptographic nonce for the player hand encryption
+    pub player_nonce: u128,
+    /// Cryptographic nonce for the dealer hand encryption
+    pub dealer_nonce: u128,
+    /// Player's encryption public key
+    pub player_enc_pubkey: [u8; 32],
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bets
+    pub player: Pubkey,
+    /// Ante wager
+    pub ante: u64,
+    /// Play wager, equal to the ante once the player plays (0 on a fold)
+    pub play_bet: u64,
+    /// Optional Pair Plus wager
+    pub pair_plus_bet: u64,
+    /// Paytables snapshotted from the config at the deal
+    pub paytable: ThreeCardPokerPaytable,
+    /// Player hand category (revealed)
+    pub player_rank: u8,
+    /// Dealer hand category (revealed)
+    pub dealer_rank: u8,
+    /// 0 dealer does not qualify, 1 player wins, 2 dealer wins, 3 tie (revealed)
+    pub outcome: u8,
+    /// Dealer's cards, revealed once the player plays or folds
+    pub dealer_cards: [u8; 3],
+    /// Current phase of the game
+    pub game_state: ThreeCardPokerGameState,
+    /// Amount returned to the player, stakes included
+    pub payout: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ThreeCardPokerGameState {
+    /// Waiting for MPC to deal
+    Dealing = 0,
+    /// Player chooses to play or fold
+    Deciding = 1,
+    /// Showdown is being revealed
+    Resolving = 2,
+    /// Hands revealed and paid
+    Resolved = 3,
+}
+
+/// Three Card Poker multipliers, x-to-1, indexed by hand category: high card, pair, flush,
+/// straight, three of a kind, straight flush.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub struct ThreeCardPokerPaytable {
+    /// Pair Plus, paid on the player's hand alone
+    pub pair_plus: [u16; 6],
+    /// Ante bonus, paid on a played hand whatever the dealer holds
+    pub ante_bonus: [u16; 6],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ThreeCardPokerConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Paytables snapshotted into every new game
+    pub paytable: ThreeCardPokerPaytable,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct KenoGame {
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bet
+    pub player: Pubkey,
+    /// Amount wagered
+    pub bet_amount: u64,
+    /// Numbers picked by the player, `1..=80`, only the first `pick_count` are used
+    pub picks: [u8; 10],
+    /// How many spots the player picked
+    pub pick_count: u8,
+    /// Multipliers in basis points indexed by hit count, snapshotted from the paytable row
+    pub multipliers_bps: [u32; 11],
+    /// The 20 drawn numbers, `1..=80` in ascending order (revealed)
+    pub drawn: [u8; 20],
+    /// How many picks were drawn
+    pub hits: u8,
+    /// Amount returned to the player, stake included
+    pub payout: u64,
+    /// Whether the draw has been revealed and settled
+    pub is_complete: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct KenoConfig {
+    /// Casino authority allowed to update the paytable
+    pub authority: Pubkey,
+    /// Multipliers in basis points, stake included, indexed by `[pick_count - 1][hits]`
+    pub paytable: [[u32; 11]; 10],
+    /// Exact return of each paytable row in basis points; 0 disables that pick count
+    pub rtp_bps: [u16; 10],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlinkoGame {
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bet
+    pub player: Pubkey,
+    /// Amount wagered
+    pub bet_amount: u64,
+    /// Number of peg rows, 8 to 16
+    pub rows: u8,
+    /// Risk level of the multiplier table
+    pub risk: PlinkoRisk,
+    /// Multipliers in basis points indexed by slot, snapshotted from the table
+    pub multipliers_bps: [u32; 17],
+    /// Slot the ball landed in, `0..=rows` from the left (revealed)
+    pub slot: u8,
+    /// Amount returned to the player, stake included
+    pub payout: u64,
+    /// Whether the drop has been revealed and settled
+    pub is_complete: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlinkoRisk {
+    Low = 0,
+    Medium = 1,
+    High = 2,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlinkoConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Return every multiplier table must hit exactly, e.g. 9900 = 99% RTP
+    pub rtp_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlinkoTable {
+    /// Casino authority allowed to update the table
+    pub authority: Pubkey,
+    /// Number of peg rows this table is for
+    pub rows: u8,
+    /// Risk level this table is for
+    pub risk: PlinkoRisk,
+    /// Multipliers in basis points, stake included, indexed by slot; slots past `rows` are 0
+    pub multipliers_bps: [u32; 17],
+    /// `PlinkoConfig::rtp_bps` the table was validated against
+    pub rtp_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BigSixGame {
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bets
+    pub player: Pubkey,
+    /// Amount staked on each segment type
+    pub bets: [u64; 8],
+    /// Sum of all bets
+    pub total_bet: u64,
+    /// Number of segments on the wheel, snapshotted from the config
+    pub segment_count: u8,
+    /// Segment type at each wheel position, snapshotted from the config
+    pub segments: [u8; 64],
+    /// Payout per segment type, x-to-1, snapshotted from the config
+    pub payouts: [u16; 8],
+    /// Wheel position the spin landed on (revealed)
+    pub landed_segment: u8,
+    /// Segment type at the landed position
+    pub landed_type: u8,
+    /// Amount returned to the player, stakes included
+    pub payout: u64,
+    /// Whether the spin has been revealed and settled
+    pub is_complete: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BigSixConfig {
+    /// Casino authority allowed to update the wheel
+    pub authority: Pubkey,
+    /// Number of segments on the wheel
+    pub segment_count: u8,
+    /// Segment type at each wheel position; positions past `segment_count` are unused
+    pub segments: [u8; 64],
+    /// Payout per segment type, x-to-1; 0 disables that type
+    pub payouts: [u16; 8],
+    /// Return of a bet on each segment type in basis points, derived from the segment weights
+    pub rtp_bps: [u16; 8],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LotteryConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Share of each ticket price that goes to the prize pool, e.g. 9000 = 90%
+    pub rtp_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LotteryRound {
+    /// Unique identifier for this round
+    pub round_id: u64,
+    /// Casino authority that opened the round
+    pub authority: Pubkey,
+    /// Price of one ticket
+    pub ticket_price: u64,
+    /// Numbers on a ticket and in the draw
+    pub pick_count: u8,
+    /// Numbers are drawn from `1..=max_number`
+    pub max_number: u8,
+    /// Unix time after which no tickets are sold and the draw can be cranked
+    pub sales_end: i64,
+    /// Share of the prize pool for each match count, in basis points
+    pub tier_shares_bps: [u16; 7],
+    /// Share of each ticket price paid into the prize pool, fixed when the round opens
+    pub prize_share_bps: u16,
+    /// Current phase of the round
+    pub round_state: LotteryRoundState,
+    /// Unix time the draw was last queued
+    pub draw_queued_at: i64,
+    /// Ticket sales net of the house share, plus any rollover
+    pub prize_pool: u64,
+    /// Part of the prize pool rolled over from earlier rounds
+    pub rollover_in: u64,
+    /// Winning numbers, bit `n` set for number `n + 1` (revealed)
+    pub winning_mask: u64,
+    /// Winning tickets per match count
+    pub winners: [u32; 7],
+    /// Prize per winning ticket for each match count
+    pub tier_prizes: [u64; 7],
+    /// Prizes paid out so far
+    pub claimed_total: u64,
+    /// Unix time after which unclaimed prizes can be rolled over
+    pub claim_deadline: i64,
+    /// Number masks of the tickets sold, indexed by ticket number
+    #[max_len(500)]
+    pub ticket_masks: Vec<u64>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LotteryRoundState {
+    /// Selling tickets until `sales_end`
+    Open = 0,
+    /// Waiting for MPC to draw the numbers; queued again if it doesn't land in time
+    Drawing = 1,
+    /// Numbers drawn; winners claim until `claim_deadline`
+    Drawn = 2,
+    /// Leftover prize pool rolled into a later round
+    Closed = 3,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LotteryTicket {
+    /// Round the ticket was bought in
+    pub round_id: u64,
+    /// Position of the ticket in the round
+    pub ticket_index: u32,
+    /// Wallet that bought the ticket
+    pub owner: Pubkey,
+    /// Picked numbers, bit `n` set for number `n + 1`
+    pub numbers_mask: u64,
+    /// Whether the numbers were quick-picked
+    pub quick_pick: bool,
+    /// Whether the prize has been claimed
+    pub claimed: bool,
+    /// Prize paid on claim
+    pub prize: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ScratchCard {
+    /// Nine 3-bit symbols encrypted to the MXE
+    pub card_ciphertext: [u8; 32],
+    /// Cryptographic nonce for the card encryption
+    pub card_nonce: u128,
+    /// Player's encryption public key, scratched symbols are encrypted to it
+    pub player_enc_pubkey: [u8; 32],
+    /// Unique identifier for this card
+    pub game_id: u64,
+    /// Wallet that bought the card
+    pub player: Pubkey,
+    /// Price paid for the card
+    pub bet_amount: u64,
+    /// Match-three multipliers in basis points, snapshotted from the paytable
+    pub paytable: [u32; 8],
+    /// Tiles scratched so far, one bit per tile
+    pub scratched_mask: u16,
+    /// Current phase of the card
+    pub card_state: ScratchCardState,
+    /// Every symbol on the card, public once it is claimed
+    pub symbols: [u8; 9],
+    /// Symbol that paid, or `SCRATCH_NO_WIN`
+    pub winning_symbol: u8,
+    /// Amount paid on claim
+    pub payout: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScratchCardState {
+    /// Waiting for MPC to print the card
+    Creating = 0,
+    /// Player may scratch tiles or claim
+    Scratching = 1,
+    /// Claim is waiting for the card reveal
+    Claiming = 2,
+    /// Card revealed and paid
+    Claimed = 3,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ScratchConfig {
+    /// Casino authority allowed to update the paytable
+    pub authority: Pubkey,
+    /// Multiplier in basis points, stake included, for three or more of each symbol;
+    /// non-decreasing so the highest matched symbol is the best prize
+    pub paytable: [u32; 8],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CrapsGame {
+    /// Unique identifier for this table
+    pub game_id: u64,
+    /// Wallet playing the table
+    pub player: Pubkey,
+    /// Current point, or 0 on the come-out roll
+    pub point: u8,
+    /// Pass line bet
+    pub pass_bet: u64,
+    /// Odds taken behind the pass line
+    pub pass_odds: u64,
+    /// Don't pass bet
+    pub dont_pass_bet: u64,
+    /// Odds laid behind the don't pass bet
+    pub dont_pass_odds: u64,
+    /// Come bet waiting for its first roll
+    pub pending_come: u64,
+    /// Come bets that have moved to a number, indexed 4, 5, 6, 8, 9, 10
+    pub come_bets: [u64; 6],
+    /// Odds taken on each come number
+    pub come_odds: [u64; 6],
+    /// Whether a roll is waiting on MPC; bets are locked until it lands
+    pub rolling: bool,
+    /// Dice of the last roll (revealed)
+    pub last_dice: [u8; 2],
+    /// Rolls made at this table
+    pub rolls: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrapsBet {
+    /// Only on the come-out roll
+    Pass = 0,
+    /// Only on the come-out roll
+    DontPass = 1,
+    /// Only while a point is on
+    Come = 2,
+    /// Behind a pass line bet once the point is set
+    PassOdds = 3,
+    /// Behind a don't pass bet once the point is set
+    DontPassOdds = 4,
+    /// Behind a come bet that has moved to `number`
+    ComeOdds = 5,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SicBoGame {
+    /// Unique identifier for this game
+    pub game_id: u64,
+    /// Wallet that placed the bets
+    pub player: Pubkey,
+    /// Amount staked on each grid position (see the `SIC_BO_*` layout)
+    pub bets: [u64; 44],
+    /// Sum of all bets
+    pub total_bet: u64,
+    /// Payout per grid position, x-to-1, snapshotted from the config
+    pub payouts: [u16; 44],
+    /// The three dice (revealed)
+    pub dice: [u8; 3],
+    /// Grid positions the dice won, bit `p` for position `p`
+    pub winning_mask: u64,
+    /// Amount returned to the player, stakes included
+    pub payout: u64,
+    /// Whether the roll has been revealed and settled
+    pub is_complete: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SicBoConfig {
+    /// Casino authority allowed to update the payouts
+    pub authority: Pubkey,
+    /// Payout per grid position, x-to-1; 0 closes that bet
+    pub payouts: [u16; 44],
+    /// Return of a bet on each grid position in basis points, from the 216 equally likely rolls
+    pub rtp_bps: [u16; 44],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VideoPokerConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Credits returned per credit bet, stake included, indexed by hand rank:
+    /// nothing, jacks or better, two pair, trips, straight, flush, full house, quads,
+    /// straight flush, royal flush
+    pub paytable: [u16; 10],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CrashRound {
+    /// Crash point encrypted to the MXE, drawn when the round opens
+    pub crash_ciphertext: [u8; 32],
+    /// Cryptographic nonce for the crash point encryption
+    pub crash_nonce: u128,
+    /// Unique identifier for this round
+    pub round_id: u64,
+    /// Account that opened the round
+    pub creator: Pubkey,
+    /// Current phase of the round
+    pub round_state: CrashRoundState,
+    /// Length of the betting window, counted from when the crash point is committed
+    pub betting_slots: u64,
+    /// First slot of the flight; bets are accepted before it, cash-outs after it
+    pub start_slot: u64,
+    /// Crash point in hundredths, revealed once the multiplier has passed it (0 until then)
+    pub crash_multiplier: u64,
+    /// Players in join order
+    #[max_len(32)]
+    pub bets: Vec<CrashBet>,
+    /// Bets paid out or marked lost by the settlement crank
+    pub settled_count: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct CrashBet {
+    /// Wallet that placed the bet
+    pub player: Pubkey,
+    /// Stake escrowed in the vault
+    pub bet_amount: u64,
+    /// Slot the player cashed out at (0 if they rode the round to the crash)
+    pub cashout_slot: u64,
+    /// Amount paid by the settlement crank
+    pub payout: u64,
+    /// Whether the settlement crank has processed this bet
+    pub settled: bool,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrashRoundState {
+    /// Waiting for MPC to commit the crash point
+    Committing = 0,
+    /// Betting until `start_slot`, then flying until the crash is revealed
+    Open = 1,
+    /// Crash point revealed; bets can be settled
+    Crashed = 2,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct DiceBetMeta {
@@ -195,6 +1000,196 @@ pub struct BlackjackGame {
     pub game_result: u8,
     /// Bet amount for this game
     pub bet_amount: u64,
+    /// Shoe this hand was dealt from (default pubkey for single-deck games)
+    pub shoe: Pubkey,
+    /// Slot of the last state change, used for the inactivity timeout
+    pub last_action_slot: u64,
+    /// Account that cranked an abandoned hand (default pubkey if never cranked)
+    pub cranker: Pubkey,
+    /// Fee already paid to the cranker, deducted from the settlement
+    pub crank_fee: u64,
+    /// House rules in force when the hand was dealt
+    pub rules: BlackjackRules,
+    /// Perfect Pairs side stake (0 if not placed)
+    pub perfect_pairs_bet: u64,
+    /// 21+3 side stake (0 if not placed)
+    pub twenty_one_plus_three_bet: u64,
+    /// Revealed Perfect Pairs result code
+    pub perfect_pairs_result: u8,
+    /// Revealed 21+3 result code
+    pub twenty_one_plus_three_result: u8,
+    /// Side-bet paytable in force when the hand was dealt
+    pub side_bet_paytable: BlackjackSideBetPaytable,
+    /// Hash of the encrypted deck and its nonce, committed at the single-deck deal (zero for shoe hands)
+    pub deck_commitment: [u8; 32],
+    /// SHA-256 of the deck revealed by `reveal_blackjack_deck`
+    pub deck_hash: [u8; 32],
+    /// Whether the deck has been revealed for audit
+    pub deck_revealed: bool,
+}
+
+/// House rules for a hand. Splitting isn't supported, so double-after-split and resplit limits
+/// have no rule here yet.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct BlackjackRules {
+    /// Dealer hits soft 17 (H17) instead of standing on it (S17)
+    pub dealer_hits_soft_17: bool,
+    /// Lowest hand total the player may double on
+    pub double_min_total: u8,
+    /// Highest hand total the player may double on
+    pub double_max_total: u8,
+}
+
+/// Side-bet multipliers, x-to-1, indexed by the result code revealed by the deal circuit.
+/// Index 0 is the losing code and must stay 0.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug, Default)]
+pub struct BlackjackSideBetPaytable {
+    /// None, mixed pair, coloured pair, perfect pair
+    pub perfect_pairs: [u16; 4],
+    /// None, flush, straight, three of a kind, straight flush, suited trips
+    pub twenty_one_plus_three: [u16; 6],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BlackjackSessions {
+    /// Wallet this index belongs to
+    pub player: Pubkey,
+    /// Ids of the player's games that have not been closed yet
+    #[max_len(8)]
+    pub open_games: Vec<u64>,
+    /// Games ever opened by the player
+    pub games_opened: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BlackjackConfig {
+    /// Casino authority allowed to update the config
+    pub authority: Pubkey,
+    /// Slots a hand may sit idle in `PlayerTurn` before anyone can crank it
+    pub timeout_slots: u64,
+    /// Share of the stake paid to whoever cranks an abandoned hand
+    pub crank_fee_bps: u16,
+    /// House rules snapshotted into every new hand and table
+    pub rules: BlackjackRules,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Side-bet paytable; a bet is only offered while its top multiplier is non-zero
+    pub side_bet_paytable: BlackjackSideBetPaytable,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BlackjackShoe {
+    /// Encrypted shoe, 21 six-bit cards per chunk
+    pub cards: [[u8; 32]; 15],
+    /// Cryptographic nonce for shoe encryption
+    pub shoe_nonce: u128,
+    /// Unique identifier for this shoe
+    pub shoe_id: u64,
+    /// Authority that created the shoe
+    pub authority: Pubkey,
+    /// Number of decks shuffled into the shoe
+    pub num_decks: u8,
+    /// Index of the cut card; reaching it forces a reshuffle before the next hand
+    pub cut_card_position: u16,
+    /// Index of the next undealt card
+    pub position: u16,
+    /// Set once the cut card has been reached
+    pub needs_shuffle: bool,
+    /// Whether a hand dealt from this shoe is still unresolved; a shoe deals one hand at a time
+    pub in_use: bool,
+    /// Game currently holding the shoe
+    pub current_game: Pubkey,
+    /// Hands dealt since creation
+    pub hands_dealt: u64,
+    /// Number of times the shoe has been shuffled
+    pub shuffle_count: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BlackjackTable {
+    /// Encrypted deck shared by every seat
+    pub deck: [[u8; 32]; 3],
+    /// Dealer's encrypted hand (handled by MPC)
+    pub dealer_hand: [u8; 32],
+    /// Seats in turn order; each hand is encrypted for its own player
+    pub seats: [BlackjackSeat; 5],
+    /// Cryptographic nonce for deck encryption
+    pub deck_nonce: u128,
+    /// Cryptographic nonce for dealer's hand encryption
+    pub dealer_nonce: u128,
+    /// Unique identifier for this table
+    pub table_id: u64,
+    /// Account that opened the table
+    pub creator: Pubkey,
+    /// Current phase of the round
+    pub table_state: BlackjackTableState,
+    /// Seat whose turn it is during `PlayerTurns`
+    pub current_seat: u8,
+    /// Cards drawn from the deck so far this round
+    pub cards_dealt: u8,
+    /// Number of cards currently in dealer's hand
+    pub dealer_hand_size: u8,
+    /// Dealer's face-up card, public once dealt
+    pub dealer_up_card: u8,
+    /// Rounds played at this table
+    pub round: u64,
+    /// House rules in force at this table
+    pub rules: BlackjackRules,
+    /// Slot the current seat's turn started; the seat can be cranked to stand after the timeout
+    pub last_action_slot: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct BlackjackSeat {
+    /// Seated player's encrypted hand (only that player can decrypt)
+    pub hand: [u8; 32],
+    /// Cryptographic nonce for the hand encryption
+    pub client_nonce: u128,
+    /// Player's encryption public key for MPC operations
+    pub enc_pubkey: [u8; 32],
+    /// Solana public key of the seated player
+    pub player: Pubkey,
+    /// Stake escrowed for this round
+    pub bet_amount: u64,
+    /// Number of cards in the hand
+    pub hand_size: u8,
+    /// Seat status within the round
+    pub status: BlackjackSeatStatus,
+    /// `resolve_game` result code once the round is resolved
+    pub result: u8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BlackjackTableState {
+    Seating = 0,
+    Dealing = 1,
+    PlayerTurns = 2,
+    DealerTurn = 3,
+    Resolving = 4,
+    Resolved = 5,
+    Hitting = 6,
+    DealerPlaying = 7,
+    Settling = 8,
+}
+
+#[repr(u8)]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BlackjackSeatStatus {
+    Empty = 0,
+    Seated = 1,
+    Stood = 2,
+    Busted = 3,
 }
 
 #[repr(u8)]
@@ -205,6 +1200,11 @@ pub enum BlackjackGameState {
     DealerTurn = 2,
     Resolving = 3,
     Resolved = 4,
+    Settling = 5,
+    Doubling = 6,
+    Surrendering = 7,
+    Hitting = 8,
+    Standing = 9,
 }
 
 // Constants for game logic
@@ -234,7 +1234,104 @@ pub const ROULETTE_BLACK_NUMBERS: [u8; 18] = [
     2, 4, 6, 8, 10, 11, 13, 15, 17, 20, 22, 24, 26, 28, 29, 31, 33, 35
 ];
 
+// Aviator constants (multipliers in hundredths, 100 = 1.00x)
+pub const AVIATOR_MULTIPLIER_SCALE: u64 = 100;
+pub const AVIATOR_MIN_CASHOUT: u64 = 101; // 1.01x
+pub const AVIATOR_MAX_CASHOUT: u64 = 10000; // 100.00x, the crash cap in `crash_aviator`
+pub const CRASH_ROUND_MAX_PLAYERS: usize = 32; // matches `CrashRound::bets` max_len
+pub const CRASH_ROUND_GROWTH_PER_SLOT: u64 = 5; // +0.05x per slot
+pub const CRASH_ROUND_MIN_BETTING_SLOTS: u64 = 10;
+pub const CRASH_ROUND_MAX_BETTING_SLOTS: u64 = 1500; // ~10 minutes
+
+// Memecoin simulator constants (prices per-mille of the entry price)
+pub const MEMECOIN_STEPS: u8 = 16; // price steps generated by `generate_price_path`
+pub const MEMECOIN_START_PRICE: u64 = 1000;
+
+// Mines constants
+pub const MINES_MIN_GRID_SIZE: u8 = 3; // 3x3
+pub const MINES_MAX_GRID_SIZE: u8 = 5; // 5x5, the 25 tiles `generate_mines` can place on
+
+// Hi-Lo constants
+pub const HILO_MAX_MULTIPLIER: u64 = 1000; // no further guesses once the ladder reaches 1000x the stake
+
+// Video poker constants
+pub const VIDEO_POKER_HAND_SIZE: u8 = 5;
+pub const VIDEO_POKER_MAX_MULTIPLIER: u16 = 4000; // royal flush on a max-coin bet
+
+// Baccarat constants
+pub const BACCARAT_MAX_COMMISSION_BPS: u16 = 1000; // 10%
+pub const BACCARAT_MAX_TIE_PAYOUT: u8 = 10; // 10 to 1
+
+// Three Card Poker constants
+pub const THREE_CARD_POKER_MAX_MULTIPLIER: u16 = 200; // 200 to 1
+
+// Keno constants
+pub const KENO_NUMBERS: u8 = 80;
+pub const KENO_DRAWN: usize = 20;
+pub const KENO_MAX_PICKS: usize = 10;
+pub const KENO_MAX_MULTIPLIER_BPS: u32 = 100_000_000; // 10,000x
+
+// Plinko constants
+pub const PLINKO_MIN_ROWS: u8 = 8;
+pub const PLINKO_MAX_ROWS: u8 = 16;
+pub const PLINKO_MAX_MULTIPLIER_BPS: u32 = 10_000_000; // 1,000x
+
+// Big Six constants
+pub const BIG_SIX_MAX_SEGMENTS: usize = 64;
+pub const BIG_SIX_SEGMENT_TYPES: usize = 8;
+pub const BIG_SIX_MAX_PAYOUT: u16 = 100; // 100 to 1
+
+// Lottery constants
+pub const LOTTERY_MIN_PICKS: u8 = 3;
+pub const LOTTERY_MAX_PICKS: u8 = 6;
+pub const LOTTERY_MAX_NUMBER: u8 = 64; // numbers fit a u64 mask
+pub const LOTTERY_MAX_TICKETS: usize = 500; // matches `LotteryRound::ticket_masks` max_len
+pub const LOTTERY_CLAIM_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+pub const LOTTERY_REDRAW_TIMEOUT_SECS: i64 = 10 * 60; // a draw still pending after this can be queued again
+
+// Scratch card constants
+pub const SCRATCH_TILES: u8 = 9;
+pub const SCRATCH_SYMBOLS: usize = 8;
+pub const SCRATCH_NO_WIN: u8 = u8::MAX;
+pub const SCRATCH_MAX_MULTIPLIER_BPS: u32 = 10_000_000; // 1,000x
+
+// Craps constants
+pub const CRAPS_POINTS: [u8; 6] = [4, 5, 6, 8, 9, 10];
+pub const CRAPS_MAX_ODDS_WIN: u64 = 6; // odds win at most 6x the flat bet, i.e. 3-4-5x odds
+
+// Sic Bo constants: the bet grid is laid out as small, big, the six specific triples,
+// any triple, the six specific doubles, totals 4-17 and the 15 two-dice combinations
+// (1-2, 1-3, ..., 5-6)
+pub const SIC_BO_BETS: usize = 44;
+pub const SIC_BO_SMALL: usize = 0;
+pub const SIC_BO_BIG: usize = 1;
+pub const SIC_BO_TRIPLE: usize = 2;
+pub const SIC_BO_ANY_TRIPLE: usize = 8;
+pub const SIC_BO_DOUBLE: usize = 9;
+pub const SIC_BO_TOTAL: usize = 15;
+pub const SIC_BO_COMBINATION: usize = 29;
+pub const SIC_BO_MAX_PAYOUT: u16 = 300; // 300 to 1
+
 // Blackjack constants
 pub const BLACKJACK_VALUE: u8 = 21;
 pub const DEALER_STAND_VALUE: u8 = 17;
 pub const BLACKJACK_PAYOUT: u64 = 150; // 150% for blackjack
+pub const BLACKJACK_SHOE_DECKS: u8 = 6;
+pub const BLACKJACK_SHOE_SIZE: u16 = 312; // 6 * 52 cards
+pub const BLACKJACK_SHOE_RESERVE_CARDS: u16 = 22; // most cards one hand can draw (11 player + 11 dealer)
+pub const BLACKJACK_TABLE_SEATS: usize = 5;
+pub const BLACKJACK_TABLE_DEALER_RESERVE: u8 = 7; // cards kept back so the dealer can always finish
+pub const BLACKJACK_MIN_TIMEOUT_SLOTS: u64 = 150; // ~1 minute
+pub const BLACKJACK_MAX_CRANK_FEE_BPS: u16 = 500; // 5% of the stake
+pub const BLACKJACK_MAX_OPEN_GAMES: usize = 8; // matches `BlackjackSessions::open_games` max_len
+pub const BLACKJACK_MAX_SIDE_BET_MULTIPLIER: u16 = 1000; // 1000 to 1
+pub const BLACKJACK_REVEAL_WINDOW_SLOTS: u64 = 9000; // ~1 hour to reveal the deck before the game can be closed
+
+// `resolve_game` result codes
+pub const BLACKJACK_RESULT_PLAYER_BUST: u8 = 0;
+pub const BLACKJACK_RESULT_DEALER_BUST: u8 = 1;
+pub const BLACKJACK_RESULT_PLAYER_WIN: u8 = 2;
+pub const BLACKJACK_RESULT_DEALER_WIN: u8 = 3;
+pub const BLACKJACK_RESULT_PUSH: u8 = 4;
+pub const BLACKJACK_RESULT_PLAYER_BLACKJACK: u8 = 5;
+pub const BLACKJACK_RESULT_SURRENDER: u8 = 6;
@@ -37,6 +37,19 @@ pub fn calculate_compound_interest(principal: u64, rate_bps: u16, periods: u32)
     (principal as f64 * compound_factor) as u64
 }
 
+/// Binomial coefficient C(n, k), exact for every n the games use.
+pub fn binomial(n: u64, k: u64) -> u128 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result * (n - i) as u128 / (i + 1) as u128;
+    }
+    result
+}
+
 // Probability calculations for games
 pub fn calculate_slots_probability(symbol: u8, count: u8) -> f64 {
     match count {
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use crate::errors::CasinoError;
 use crate::state::casino::*;
 
 pub mod math;
@@ -7,6 +9,9 @@ pub mod vrf;
 pub use math::*;
 pub use vrf::*;
 
+#[cfg(test)]
+mod tests;
+
 // Utility functions for casino games
 pub fn calculate_slots_payout(reels: [u8; 3], bet_amount: u64) -> u64 {
     let mut payout = 0u64;
@@ -110,16 +115,443 @@ pub fn calculate_roulette_payout(bet_type: RouletteBetType, bet_amount: u64, win
     }
 }
 
-pub fn calculate_aviator_payout(cashout_multiplier: f64, crash_multiplier: f64, bet_amount: u64) -> u64 {
+/// Multipliers are fixed-point hundredths. The house edge is already in the crash
+/// distribution, so no RTP is applied here.
+pub fn calculate_aviator_payout(cashout_multiplier: u64, crash_multiplier: u64, bet_amount: u64) -> u64 {
     if cashout_multiplier <= crash_multiplier {
         // Player cashed out before crash
-        (bet_amount as f64 * cashout_multiplier) as u64
+        (bet_amount as u128 * cashout_multiplier as u128 / AVIATOR_MULTIPLIER_SCALE as u128) as u64
     } else {
         // Player didn't cash out in time
         0
     }
 }
 
+/// Live multiplier of a crash round at `slot`, in hundredths: 1.00x at `start_slot`,
+/// growing linearly and capped at the crash cap.
+pub fn crash_round_multiplier(start_slot: u64, slot: u64) -> u64 {
+    let elapsed = slot.saturating_sub(start_slot);
+    AVIATOR_MULTIPLIER_SCALE.saturating_add(elapsed.saturating_mul(CRASH_ROUND_GROWTH_PER_SLOT)).min(AVIATOR_MAX_CASHOUT)
+}
+
+/// Entry stake times the price ratio, scaled by RTP. Prices are per-mille of the entry price.
+pub fn calculate_memecoin_payout(bet_amount: u64, price: u64, rtp_bps: u16) -> u64 {
+    (bet_amount as u128 * price as u128 * rtp_bps as u128 / (MEMECOIN_START_PRICE as u128 * 10000)) as u64
+}
+
+/// Stake times the fair multiplier C(tiles, k) / C(tiles - mines, k) for `safe_reveals` = k,
+/// scaled by RTP.
+pub fn calculate_mines_payout(bet_amount: u64, tile_count: u8, mine_count: u8, safe_reveals: u8, rtp_bps: u16) -> u64 {
+    let safe_ways = binomial((tile_count - mine_count) as u64, safe_reveals as u64);
+    if safe_ways == 0 {
+        return 0;
+    }
+    (bet_amount as u128 * binomial(tile_count as u64, safe_reveals as u64) * rtp_bps as u128 / (safe_ways * 10000)) as u64
+}
+
+/// Unseen cards that win a Hi-Lo guess against `current_rank`. Equal ranks lose both ways.
+pub fn hilo_winning_cards(rank_counts: &[u8; 13], current_rank: u8, guess: crate::state::casino::HiloGuess) -> u8 {
+    let mut winners = 0;
+    for (rank, count) in rank_counts.iter().enumerate() {
+        let wins = match guess {
+            crate::state::casino::HiloGuess::Higher => rank as u8 > current_rank,
+            crate::state::casino::HiloGuess::Lower => (rank as u8) < current_rank,
+        };
+        if wins {
+            winners += count;
+        }
+    }
+    winners
+}
+
+/// Ladder value after a correct guess, paid at the exact odds `remaining / winners`.
+pub fn hilo_rung_value(ladder_value: u64, remaining: u8, winners: u8) -> u64 {
+    (ladder_value as u128 * remaining as u128 / winners as u128) as u64
+}
+
+/// Amount returned on a baccarat bet, stake included. Player and banker bets push on a tie.
+pub fn calculate_baccarat_payout(
+    bet: BaccaratBet,
+    winner: BaccaratBet,
+    bet_amount: u64,
+    banker_commission_bps: u16,
+    tie_payout: u8,
+) -> u64 {
+    match (bet, winner) {
+        (BaccaratBet::Tie, BaccaratBet::Tie) => bet_amount * (1 + tie_payout as u64),
+        (BaccaratBet::Tie, _) => 0,
+        (_, BaccaratBet::Tie) => bet_amount,
+        (BaccaratBet::Player, BaccaratBet::Player) => bet_amount * 2,
+        (BaccaratBet::Banker, BaccaratBet::Banker) => {
+            bet_amount * 2 - bet_amount * banker_commission_bps as u64 / 10000
+        }
+        _ => 0,
+    }
+}
+
+/// Amount returned on a Three Card Poker hand, stakes included. Pair Plus pays on the
+/// player's hand alone; a fold forfeits the ante. A played hand takes the ante bonus, then
+/// pays the ante only when the dealer fails to qualify, and both wagers 1 to 1 on a win.
+pub fn calculate_three_card_poker_payout(
+    ante: u64,
+    play_bet: u64,
+    pair_plus_bet: u64,
+    player_rank: u8,
+    outcome: u8,
+    paytable: &ThreeCardPokerPaytable,
+) -> u64 {
+    let rank = player_rank as usize;
+    let mut payout = 0;
+    if pair_plus_bet > 0 && paytable.pair_plus[rank] > 0 {
+        payout += pair_plus_bet * (1 + paytable.pair_plus[rank] as u64);
+    }
+    if play_bet > 0 {
+        payout += ante * paytable.ante_bonus[rank] as u64;
+        payout += match outcome {
+            0 => ante * 2 + play_bet,
+            1 => ante * 2 + play_bet * 2,
+            3 => ante + play_bet,
+            _ => 0,
+        };
+    }
+    payout
+}
+
+/// Exact return of a keno paytable row in basis points. Each hit count is weighted by
+/// C(picks, hits) * C(80 - picks, 20 - hits) out of the C(80, 20) possible draws.
+pub fn calculate_keno_rtp_bps(pick_count: u8, multipliers_bps: &[u32; 11]) -> u64 {
+    let picks = pick_count as u64;
+    let numbers = KENO_NUMBERS as u64;
+    let drawn = KENO_DRAWN as u64;
+    let mut weighted: u128 = 0;
+    for hits in 0..=picks {
+        let ways = binomial(picks, hits) * binomial(numbers - picks, drawn - hits);
+        weighted += ways * multipliers_bps[hits as usize] as u128;
+    }
+    (weighted / binomial(numbers, drawn)) as u64
+}
+
+/// The drawn numbers, `1..=80` in ascending order, from the circuit's draw mask.
+pub fn keno_drawn_numbers(drawn_mask: u128) -> [u8; KENO_DRAWN] {
+    let mut drawn = [0u8; KENO_DRAWN];
+    let mut count = 0;
+    for number in 0..KENO_NUMBERS {
+        if drawn_mask >> number & 1 == 1 && count < KENO_DRAWN {
+            drawn[count] = number + 1;
+            count += 1;
+        }
+    }
+    drawn
+}
+
+pub fn count_keno_hits(picks: &[u8], drawn_mask: u128) -> u8 {
+    picks.iter().filter(|&&number| drawn_mask >> (number - 1) & 1 == 1).count() as u8
+}
+
+pub fn calculate_keno_payout(bet_amount: u64, multiplier_bps: u32) -> u64 {
+    (bet_amount as u128 * multiplier_bps as u128 / 10000) as u64
+}
+
+/// Exact return of a plinko table in basis points: slot `k` is reached by C(rows, k) of
+/// the 2^rows equally likely paths.
+pub fn calculate_plinko_rtp_bps(rows: u8, multipliers_bps: &[u32; 17]) -> u64 {
+    let mut weighted: u128 = 0;
+    for slot in 0..=rows as u64 {
+        weighted += binomial(rows as u64, slot) * multipliers_bps[slot as usize] as u128;
+    }
+    (weighted >> rows) as u64
+}
+
+pub fn calculate_plinko_payout(bet_amount: u64, multiplier_bps: u32) -> u64 {
+    (bet_amount as u128 * multiplier_bps as u128 / 10000) as u64
+}
+
+/// Return of a bet on each Big Six segment type in basis points: the type's share of the
+/// wheel times what it pays back, stake included. Types with no segments or no payout return 0.
+pub fn calculate_big_six_rtp_bps(segment_count: u8, segments: &[u8; BIG_SIX_MAX_SEGMENTS], payouts: &[u16; BIG_SIX_SEGMENT_TYPES]) -> [u64; BIG_SIX_SEGMENT_TYPES] {
+    let mut weights = [0u64; BIG_SIX_SEGMENT_TYPES];
+    for &segment_type in &segments[..segment_count as usize] {
+        weights[segment_type as usize] += 1;
+    }
+    let mut rtp_bps = [0u64; BIG_SIX_SEGMENT_TYPES];
+    for (segment_type, rtp) in rtp_bps.iter_mut().enumerate() {
+        if payouts[segment_type] > 0 {
+            *rtp = weights[segment_type] * (payouts[segment_type] as u64 + 1) * 10000 / segment_count as u64;
+        }
+    }
+    rtp_bps
+}
+
+/// Settles every bet on a spin: only the landed type wins, at its x-to-1 payout plus the stake.
+pub fn calculate_big_six_payout(bets: &[u64; BIG_SIX_SEGMENT_TYPES], payouts: &[u16; BIG_SIX_SEGMENT_TYPES], landed_type: u8) -> u64 {
+    let landed = landed_type as usize;
+    bets[landed] * (payouts[landed] as u64 + 1)
+}
+
+/// Quick-pick numbers for a lottery ticket as a mask, drawn from a hash of `seed`.
+pub fn lottery_quick_pick(seed: &[u8], pick_count: u8, max_number: u8) -> u64 {
+    let mut digest = hash(seed).to_bytes();
+    let mut mask = 0u64;
+    let mut picked = 0;
+    let mut i = 0;
+    while picked < pick_count {
+        if i == digest.len() {
+            digest = hash(&digest).to_bytes();
+            i = 0;
+        }
+        let number = digest[i] % max_number;
+        i += 1;
+        if mask >> number & 1 == 0 {
+            mask |= 1 << number;
+            picked += 1;
+        }
+    }
+    mask
+}
+
+pub fn lottery_matches(numbers_mask: u64, winning_mask: u64) -> u8 {
+    (numbers_mask & winning_mask).count_ones() as u8
+}
+
+/// Prize per winning ticket in each tier: the tier's share of the pool split evenly among its
+/// winners. Tiers nobody hit pay nothing, so their share stays in the vault for the rollover.
+pub fn calculate_lottery_tier_prizes(prize_pool: u64, tier_shares_bps: &[u16; 7], winners: &[u32; 7]) -> [u64; 7] {
+    let mut prizes = [0u64; 7];
+    for (tier, prize) in prizes.iter_mut().enumerate() {
+        if winners[tier] > 0 {
+            let pot = (prize_pool as u128 * tier_shares_bps[tier] as u128 / 10000) as u64;
+            *prize = pot / winners[tier] as u64;
+        }
+    }
+    prizes
+}
+
+pub fn scratch_card_symbols(card: u32) -> [u8; 9] {
+    let mut symbols = [0u8; 9];
+    for (tile, symbol) in symbols.iter_mut().enumerate() {
+        *symbol = (card >> (3 * tile) & 7) as u8;
+    }
+    symbols
+}
+
+/// Highest symbol showing at least three times, or `SCRATCH_NO_WIN`.
+pub fn scratch_winning_symbol(symbols: &[u8; 9]) -> u8 {
+    let mut counts = [0u8; SCRATCH_SYMBOLS];
+    for &symbol in symbols {
+        counts[symbol as usize] += 1;
+    }
+    (0..SCRATCH_SYMBOLS as u8).rev().find(|&symbol| counts[symbol as usize] >= 3).unwrap_or(SCRATCH_NO_WIN)
+}
+
+/// Exact return of a scratch paytable in basis points. Each symbol is weighted by the cards
+/// it is the highest triple on, out of the 8^9 cards with uniformly drawn tiles.
+pub fn calculate_scratch_rtp_bps(paytable: &[u32; SCRATCH_SYMBOLS]) -> u64 {
+    let tiles = SCRATCH_TILES as u64;
+    let mut weighted: u128 = 0;
+    for symbol in 0..SCRATCH_SYMBOLS {
+        // Ways to fill `j` tiles with the higher symbols, none of them showing three times
+        let mut capped = [0u128; SCRATCH_TILES as usize + 1];
+        capped[0] = 1;
+        for _ in symbol + 1..SCRATCH_SYMBOLS {
+            let mut next = [0u128; SCRATCH_TILES as usize + 1];
+            for j in 0..=tiles {
+                for count in 0..=2 {
+                    if j + count <= tiles {
+                        next[(j + count) as usize] += capped[j as usize] * binomial(j + count, count);
+                    }
+                }
+            }
+            capped = next;
+        }
+        // At least three of `symbol`, the rest split between the higher and lower symbols
+        let mut ways: u128 = 0;
+        for matched in 3..=tiles {
+            let rest = tiles - matched;
+            for higher in 0..=rest {
+                ways += binomial(tiles, matched)
+                    * binomial(rest, higher)
+                    * capped[higher as usize]
+                    * (symbol as u128).pow((rest - higher) as u32);
+            }
+        }
+        weighted += ways * paytable[symbol] as u128;
+    }
+    (weighted / (SCRATCH_SYMBOLS as u128).pow(SCRATCH_TILES as u32)) as u64
+}
+
+pub fn craps_point_index(number: u8) -> Option<usize> {
+    CRAPS_POINTS.iter().position(|&point| point == number)
+}
+
+/// Ways two dice can total `number` out of 36.
+pub fn craps_ways(number: u8) -> u64 {
+    6 - (number as i64 - 7).unsigned_abs()
+}
+
+/// Profit on odds taken behind `point`, paid at true odds: 2:1 on 4/10, 3:2 on 5/9, 6:5 on 6/8.
+pub fn craps_take_odds_win(point: u8, amount: u64) -> u64 {
+    amount * craps_ways(7) / craps_ways(point)
+}
+
+/// Profit on odds laid against `point`, paid at true odds: 1:2 on 4/10, 2:3 on 5/9, 5:6 on 6/8.
+pub fn craps_lay_odds_win(point: u8, amount: u64) -> u64 {
+    amount * craps_ways(point) / craps_ways(7)
+}
+
+/// Everything still on a craps table, odds included.
+pub fn craps_table_total(game: &CrapsGame) -> u64 {
+    game.pass_bet + game.pass_odds + game.dont_pass_bet + game.dont_pass_odds + game.pending_come
+        + game.come_bets.iter().sum::<u64>() + game.come_odds.iter().sum::<u64>()
+}
+
+/// Settles a roll totalling `total` against every bet on the table and advances the point.
+/// Returns what is paid back (winning stakes plus winnings), the winning stakes and the losing
+/// stakes. Settled bets come off the table; odds work on every roll, come-out included.
+pub fn settle_craps_roll(game: &mut CrapsGame, total: u8) -> (u64, u64, u64) {
+    let mut payout = 0u64;
+    let mut won = 0u64;
+    let mut lost = 0u64;
+
+    if game.point == 0 {
+        match total {
+            7 | 11 => {
+                payout += game.pass_bet * 2;
+                won += game.pass_bet;
+                lost += game.dont_pass_bet;
+                game.pass_bet = 0;
+                game.dont_pass_bet = 0;
+            }
+            2 | 3 => {
+                payout += game.dont_pass_bet * 2;
+                won += game.dont_pass_bet;
+                lost += game.pass_bet;
+                game.pass_bet = 0;
+                game.dont_pass_bet = 0;
+            }
+            // Bar 12: the don't pass bet pushes and stays up
+            12 => {
+                lost += game.pass_bet;
+                game.pass_bet = 0;
+            }
+            _ => game.point = total,
+        }
+    } else if total == game.point || total == 7 {
+        let point = game.point;
+        let (pass, dont_pass) = (game.pass_bet + game.pass_odds, game.dont_pass_bet + game.dont_pass_odds);
+        if total == point {
+            payout += pass + game.pass_bet + craps_take_odds_win(point, game.pass_odds);
+            won += pass;
+            lost += dont_pass;
+        } else {
+            payout += dont_pass + game.dont_pass_bet + craps_lay_odds_win(point, game.dont_pass_odds);
+            won += dont_pass;
+            lost += pass;
+        }
+        game.pass_bet = 0;
+        game.pass_odds = 0;
+        game.dont_pass_bet = 0;
+        game.dont_pass_odds = 0;
+        game.point = 0;
+    }
+
+    for (index, &number) in CRAPS_POINTS.iter().enumerate() {
+        let (flat, odds) = (game.come_bets[index], game.come_odds[index]);
+        if total == number {
+            payout += flat * 2 + odds + craps_take_odds_win(number, odds);
+            won += flat + odds;
+        } else if total == 7 {
+            lost += flat + odds;
+        } else {
+            continue;
+        }
+        game.come_bets[index] = 0;
+        game.come_odds[index] = 0;
+    }
+
+    // The pending come bet's first roll, after the numbers so a bet moving to a number is not
+    // settled by the roll that moved it
+    let come = game.pending_come;
+    game.pending_come = 0;
+    match total {
+        7 | 11 => {
+            payout += come * 2;
+            won += come;
+        }
+        2 | 3 | 12 => lost += come,
+        _ => game.come_bets[craps_point_index(total).unwrap()] += come,
+    }
+
+    (payout, won, lost)
+}
+
+/// Sic Bo grid positions that win on `dice`, bit `p` for position `p`. Small and big lose on
+/// any triple.
+pub fn sic_bo_winning_mask(dice: [u8; 3]) -> u64 {
+    let mut counts = [0u8; 7];
+    for &die in &dice {
+        counts[die as usize] += 1;
+    }
+    let total = dice.iter().sum::<u8>() as usize;
+    let triple = counts.contains(&3);
+
+    let mut mask = 0u64;
+    if triple {
+        mask |= 1 << SIC_BO_ANY_TRIPLE;
+    } else {
+        mask |= 1 << if total <= 10 { SIC_BO_SMALL } else { SIC_BO_BIG };
+    }
+    if (4..=17).contains(&total) {
+        mask |= 1 << (SIC_BO_TOTAL + total - 4);
+    }
+    let mut combination = SIC_BO_COMBINATION;
+    for face in 1..=6 {
+        if counts[face] == 3 {
+            mask |= 1 << (SIC_BO_TRIPLE + face - 1);
+        }
+        if counts[face] >= 2 {
+            mask |= 1 << (SIC_BO_DOUBLE + face - 1);
+        }
+        for other in face + 1..=6 {
+            if counts[face] > 0 && counts[other] > 0 {
+                mask |= 1 << combination;
+            }
+            combination += 1;
+        }
+    }
+    mask
+}
+
+/// Return of a bet on each Sic Bo grid position in basis points, counting its wins over the
+/// 216 equally likely rolls. Positions with no payout return 0.
+pub fn calculate_sic_bo_rtp_bps(payouts: &[u16; SIC_BO_BETS]) -> [u64; SIC_BO_BETS] {
+    let mut wins = [0u64; SIC_BO_BETS];
+    for first in 1..=6 {
+        for second in 1..=6 {
+            for third in 1..=6 {
+                let mask = sic_bo_winning_mask([first, second, third]);
+                for (position, count) in wins.iter_mut().enumerate() {
+                    *count += mask >> position & 1;
+                }
+            }
+        }
+    }
+    let mut rtp_bps = [0u64; SIC_BO_BETS];
+    for (position, rtp) in rtp_bps.iter_mut().enumerate() {
+        if payouts[position] > 0 {
+            *rtp = wins[position] * (payouts[position] as u64 + 1) * 10000 / 216;
+        }
+    }
+    rtp_bps
+}
+
+/// Settles every bet on a roll: each winning position pays x-to-1 plus the stake.
+pub fn calculate_sic_bo_payout(bets: &[u64; SIC_BO_BETS], payouts: &[u16; SIC_BO_BETS], winning_mask: u64) -> u64 {
+    (0..SIC_BO_BETS)
+        .filter(|&position| winning_mask >> position & 1 == 1)
+        .map(|position| bets[position] * (payouts[position] as u64 + 1))
+        .sum()
+}
+
 pub fn calculate_dice_payout(bet_type: crate::state::casino::DiceBetType, param: Option<u8>, roll: u8, bet_amount: u64) -> u64 {
     match bet_type {
         crate::state::casino::DiceBetType::Exact => {
@@ -132,58 +564,48 @@ pub fn calculate_dice_payout(bet_type: crate::state::casino::DiceBetType, param:
     }
 }
 
-pub fn calculate_blackjack_payout(player_hand: &Vec<u8>, dealer_hand: &Vec<u8>, bet_amount: u64) -> u64 {
-    let player_value = calculate_hand_value(player_hand);
-    let dealer_value = calculate_hand_value(dealer_hand);
-    
-    if player_value > BLACKJACK_VALUE {
-        // Player busted
-        0
-    } else if dealer_value > BLACKJACK_VALUE {
-        // Dealer busted, player wins
-        bet_amount
-    } else if player_value > dealer_value {
-        // Player wins
-        bet_amount
-    } else if player_value == dealer_value {
-        // Push
-        bet_amount
-    } else {
-        // Dealer wins
-        0
-    }
+/// Amount returned to the player for a `resolve_game` result code, stake included.
+/// RTP is applied to the winnings only, so a push always returns the full stake.
+pub fn calculate_blackjack_settlement(result_code: u8, bet_amount: u64, rtp_bps: u16) -> u64 {
+    let winnings = match result_code {
+        BLACKJACK_RESULT_PLAYER_BLACKJACK => bet_amount * BLACKJACK_PAYOUT / 100, // 3:2
+        BLACKJACK_RESULT_DEALER_BUST | BLACKJACK_RESULT_PLAYER_WIN => bet_amount, // 1:1
+        BLACKJACK_RESULT_PUSH => 0,
+        BLACKJACK_RESULT_SURRENDER => return bet_amount / 2,
+        _ => return 0,
+    };
+    bet_amount + (winnings * rtp_bps as u64) / 10000
 }
 
-pub fn calculate_hand_value(hand: &Vec<u8>) -> u8 {
-    let mut value = 0u8;
-    let mut aces = 0u8;
-    
-    for card in hand {
-        match card {
-            1 => {
-                aces += 1;
-                value += 11;
-            },
-            2..=10 => value += card,
-            11..=13 => value += 10,
-            _ => {}
-        }
-    }
-    
-    // Adjust for aces
-    while value > BLACKJACK_VALUE && aces > 0 {
-        value -= 10;
-        aces -= 1;
+/// Unpacks the deck revealed by `reveal_deck`: six bits per card, 21 cards per chunk.
+pub fn unpack_blackjack_deck(chunks: [u128; 3]) -> [u8; 52] {
+    let mut deck = [0u8; 52];
+    for (i, card) in deck.iter_mut().enumerate() {
+        *card = ((chunks[i / 21] >> (6 * (i % 21))) & 0x3f) as u8;
     }
-    
-    value
+    deck
+}
+
+/// SHA-256 of the deck ordering, as stored in `BlackjackGame::deck_hash`.
+pub fn blackjack_deck_hash(deck: &[u8; 52]) -> [u8; 32] {
+    hash(deck).to_bytes()
 }
 
-pub fn is_blackjack(hand: &Vec<u8>) -> bool {
-    hand.len() == 2 && calculate_hand_value(hand) == BLACKJACK_VALUE
+/// Commitment to a dealt single-deck hand's encrypted deck, stored in `BlackjackGame::deck_commitment`.
+pub fn blackjack_deck_commitment(deck: &[[u8; 32]; 3], deck_nonce: u128) -> [u8; 32] {
+    hashv(&[&deck[0], &deck[1], &deck[2], &deck_nonce.to_le_bytes()]).to_bytes()
 }
 
-pub fn should_dealer_hit(dealer_hand: &Vec<u8>) -> bool {
-    let value = calculate_hand_value(dealer_hand);
-    value < DEALER_STAND_VALUE
+/// Checks a deck revealed by `reveal_deck`: it must be a permutation of 0..52, and the dealer's
+/// hole card, revealed from the dealer's own hand, must sit at deck[3] where the deal took it.
+/// The deal takes deck[0] and deck[2] for the player and deck[1] and deck[3] for the dealer;
+/// player draws follow from deck[4], then the dealer's, so players can check their own cards.
+pub fn verify_blackjack_deck(deck: &[u8; 52], dealer_hole_card: u8) -> Result<()> {
+    let mut seen = [false; 52];
+    for &card in deck {
+        require!((card as usize) < 52 && !seen[card as usize], CasinoError::InvalidDeckReveal);
+        seen[card as usize] = true;
+    }
+    require!(deck[3] == dealer_hole_card, CasinoError::InvalidDeckReveal);
+    Ok(())
 }
@@ -0,0 +1,19 @@
+use crate::state::casino::*;
+use crate::utils::calculate_aviator_payout;
+
+#[test]
+fn payout_is_stake_times_cashout_when_it_beats_the_crash() {
+    let bet = 1_000_000;
+    assert_eq!(calculate_aviator_payout(AVIATOR_MIN_CASHOUT, 250, bet), 1_010_000);
+    assert_eq!(calculate_aviator_payout(250, 250, bet), 2_500_000);
+    assert_eq!(calculate_aviator_payout(AVIATOR_MAX_CASHOUT, AVIATOR_MAX_CASHOUT, bet), 100_000_000);
+    // No overflow at the largest stakes
+    assert_eq!(calculate_aviator_payout(AVIATOR_MAX_CASHOUT, AVIATOR_MAX_CASHOUT, u64::MAX / 100), u64::MAX / 100 * 100);
+}
+
+#[test]
+fn payout_is_zero_when_the_plane_crashes_first() {
+    let bet = 1_000_000;
+    assert_eq!(calculate_aviator_payout(251, 250, bet), 0);
+    assert_eq!(calculate_aviator_payout(AVIATOR_MIN_CASHOUT, AVIATOR_MULTIPLIER_SCALE, bet), 0);
+}
@@ -0,0 +1,101 @@
+use crate::state::casino::*;
+use crate::utils::{
+    blackjack_deck_commitment, blackjack_deck_hash, calculate_blackjack_settlement, unpack_blackjack_deck,
+    verify_blackjack_deck,
+};
+
+#[test]
+fn settlement_pays_each_result_code() {
+    let bet = 1_000_000;
+    // At 100% RTP: naturals pay 3:2, wins 1:1, pushes return the stake, surrender half of it
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_PLAYER_BLACKJACK, bet, 10000), 2_500_000);
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_PLAYER_WIN, bet, 10000), 2_000_000);
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_DEALER_BUST, bet, 10000), 2_000_000);
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_PUSH, bet, 10000), bet);
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_SURRENDER, bet, 10000), 500_000);
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_PLAYER_BUST, bet, 10000), 0);
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_DEALER_WIN, bet, 10000), 0);
+    assert_eq!(calculate_blackjack_settlement(7, bet, 10000), 0);
+}
+
+#[test]
+fn settlement_applies_rtp_to_winnings_only() {
+    let bet = 1_000_000;
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_PLAYER_WIN, bet, 9950), 1_995_000);
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_PLAYER_BLACKJACK, bet, 9950), 2_492_500);
+    // Stake-only outcomes don't depend on RTP
+    for rtp_bps in [8000, 9950, 10000] {
+        assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_PUSH, bet, rtp_bps), bet);
+        assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_SURRENDER, bet, rtp_bps), bet / 2);
+    }
+    // Odd stakes round the surrender refund down, never paying more than half
+    assert_eq!(calculate_blackjack_settlement(BLACKJACK_RESULT_SURRENDER, 3, 10000), 1);
+}
+
+fn ordered_deck() -> [u8; 52] {
+    core::array::from_fn(|i| i as u8)
+}
+
+/// Packs a deck the way the `Deck` circuit type stores it: six bits per card, 21 per chunk.
+fn pack(deck: &[u8; 52]) -> [u128; 3] {
+    let mut chunks = [0u128; 3];
+    for (i, &card) in deck.iter().enumerate() {
+        chunks[i / 21] |= (card as u128) << (6 * (i % 21));
+    }
+    chunks
+}
+
+#[test]
+fn deck_unpacks_as_packed() {
+    let mut deck = ordered_deck();
+    deck.reverse();
+    deck.swap(3, 40);
+    assert_eq!(unpack_blackjack_deck(pack(&deck)), deck);
+}
+
+#[test]
+fn revealed_deck_must_be_a_permutation() {
+    let deck = ordered_deck();
+    assert!(verify_blackjack_deck(&deck, deck[3]).is_ok());
+
+    let mut duplicate = deck;
+    duplicate[10] = duplicate[11];
+    assert!(verify_blackjack_deck(&duplicate, deck[3]).is_err());
+
+    let mut out_of_range = deck;
+    out_of_range[51] = 52;
+    assert!(verify_blackjack_deck(&out_of_range, deck[3]).is_err());
+}
+
+#[test]
+fn hole_card_must_be_the_fourth_card() {
+    let deck = ordered_deck();
+    // deck[1] is the dealer's up card, not the hole card
+    assert!(verify_blackjack_deck(&deck, deck[1]).is_err());
+    assert!(verify_blackjack_deck(&deck, deck[4]).is_err());
+    assert!(verify_blackjack_deck(&deck, deck[3]).is_ok());
+}
+
+#[test]
+fn deck_hash_depends_on_order() {
+    let deck = ordered_deck();
+    let mut swapped = deck;
+    swapped.swap(0, 1);
+    assert_eq!(blackjack_deck_hash(&deck), blackjack_deck_hash(&ordered_deck()));
+    assert_ne!(blackjack_deck_hash(&deck), blackjack_deck_hash(&swapped));
+}
+
+#[test]
+fn commitment_binds_ciphertexts_and_nonce() {
+    let deck = [[1u8; 32], [2u8; 32], [3u8; 32]];
+    let commitment = blackjack_deck_commitment(&deck, 7);
+    assert_ne!(commitment, [0; 32]);
+    assert_eq!(commitment, blackjack_deck_commitment(&deck, 7));
+    assert_ne!(commitment, blackjack_deck_commitment(&deck, 8));
+
+    let mut tampered = deck;
+    tampered[2][31] ^= 1;
+    assert_ne!(commitment, blackjack_deck_commitment(&tampered, 7));
+    // Chunks can't be reordered without changing the commitment
+    assert_ne!(commitment, blackjack_deck_commitment(&[deck[1], deck[0], deck[2]], 7));
+}
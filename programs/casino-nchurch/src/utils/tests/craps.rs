@@ -0,0 +1,113 @@
+use anchor_lang::prelude::Pubkey;
+use crate::state::casino::*;
+use crate::utils::{craps_lay_odds_win, craps_table_total, craps_take_odds_win, settle_craps_roll};
+
+fn table(point: u8, pass_bet: u64, dont_pass_bet: u64) -> CrapsGame {
+    CrapsGame {
+        game_id: 0,
+        player: Pubkey::default(),
+        point,
+        pass_bet,
+        pass_odds: 0,
+        dont_pass_bet,
+        dont_pass_odds: 0,
+        pending_come: 0,
+        come_bets: [0; 6],
+        come_odds: [0; 6],
+        rolling: false,
+        last_dice: [0; 2],
+        rolls: 0,
+        bump: 0,
+    }
+}
+
+#[test]
+fn come_out_naturals_and_craps() {
+    for total in [7, 11] {
+        let mut game = table(0, 100, 50);
+        assert_eq!(settle_craps_roll(&mut game, total), (200, 100, 50));
+        assert_eq!(craps_table_total(&game), 0);
+    }
+    for total in [2, 3] {
+        let mut game = table(0, 100, 50);
+        assert_eq!(settle_craps_roll(&mut game, total), (100, 50, 100));
+        assert_eq!(craps_table_total(&game), 0);
+    }
+    // Bar 12: pass loses, don't pass stays up
+    let mut game = table(0, 100, 50);
+    assert_eq!(settle_craps_roll(&mut game, 12), (0, 0, 100));
+    assert_eq!((game.pass_bet, game.dont_pass_bet, game.point), (0, 50, 0));
+}
+
+#[test]
+fn come_out_sets_the_point() {
+    for point in CRAPS_POINTS {
+        let mut game = table(0, 100, 50);
+        assert_eq!(settle_craps_roll(&mut game, point), (0, 0, 0));
+        assert_eq!((game.point, game.pass_bet, game.dont_pass_bet), (point, 100, 50));
+    }
+}
+
+#[test]
+fn point_made_or_seven_out() {
+    // Point 4 made: pass and its odds win at 2:1, don't pass and its odds lose
+    let mut game = table(4, 100, 50);
+    game.pass_odds = 300;
+    game.dont_pass_odds = 100;
+    assert_eq!(settle_craps_roll(&mut game, 4), (100 * 2 + 300 + 600, 400, 150));
+    assert_eq!((game.point, craps_table_total(&game)), (0, 0));
+
+    // Seven-out on 6: the laid odds win 5:6
+    let mut game = table(6, 100, 50);
+    game.pass_odds = 300;
+    game.dont_pass_odds = 120;
+    assert_eq!(settle_craps_roll(&mut game, 7), (50 * 2 + 120 + 100, 170, 400));
+    assert_eq!((game.point, craps_table_total(&game)), (0, 0));
+
+    // Any other number leaves the line alone
+    let mut game = table(9, 100, 50);
+    assert_eq!(settle_craps_roll(&mut game, 8), (0, 0, 0));
+    assert_eq!((game.point, game.pass_bet, game.dont_pass_bet), (9, 100, 50));
+}
+
+#[test]
+fn odds_pay_true_odds() {
+    let ways = |number: u64| 6 - (number as i64 - 7).unsigned_abs();
+    for point in CRAPS_POINTS {
+        let amount = 60;
+        let take = craps_take_odds_win(point, amount);
+        let lay = craps_lay_odds_win(point, amount);
+        // Zero expectation: ways to win times profit equals ways to lose times stake
+        assert_eq!(take * ways(point as u64), amount * 6, "{point}");
+        assert_eq!(lay * 6, amount * ways(point as u64), "{point}");
+    }
+}
+
+#[test]
+fn come_bets_travel_and_settle() {
+    // A pending come bet moves to its number and is not settled by that roll
+    let mut game = table(4, 0, 0);
+    game.pending_come = 100;
+    assert_eq!(settle_craps_roll(&mut game, 5), (0, 0, 0));
+    assert_eq!((game.pending_come, game.come_bets[1]), (0, 100));
+
+    // Its number repeats: flat pays 1:1, odds pay 3:2
+    game.come_odds[1] = 200;
+    assert_eq!(settle_craps_roll(&mut game, 5), (200 + 200 + 300, 300, 0));
+    assert_eq!(craps_table_total(&game), 0);
+
+    // A seven takes every come number but wins the new pending come bet
+    let mut game = table(4, 0, 0);
+    game.come_bets = [10, 20, 30, 40, 50, 60];
+    game.come_odds[2] = 50;
+    game.pending_come = 25;
+    assert_eq!(settle_craps_roll(&mut game, 7), (50, 25, 260));
+    assert_eq!(craps_table_total(&game), 0);
+
+    // Craps on the first roll loses the pending come bet only
+    let mut game = table(8, 100, 0);
+    game.pending_come = 25;
+    game.come_bets[0] = 10;
+    assert_eq!(settle_craps_roll(&mut game, 3), (0, 0, 25));
+    assert_eq!(craps_table_total(&game), 110);
+}
@@ -0,0 +1,31 @@
+use crate::state::casino::*;
+use crate::utils::{calculate_aviator_payout, crash_round_multiplier};
+
+#[test]
+fn multiplier_starts_at_one_and_grows_each_slot() {
+    let start = 1_000;
+    assert_eq!(crash_round_multiplier(start, start), AVIATOR_MULTIPLIER_SCALE);
+    assert_eq!(crash_round_multiplier(start, start + 1), AVIATOR_MULTIPLIER_SCALE + CRASH_ROUND_GROWTH_PER_SLOT);
+    assert_eq!(crash_round_multiplier(start, start + 20), 200);
+    // A slot before the round started reads as the start
+    assert_eq!(crash_round_multiplier(start, start - 1), AVIATOR_MULTIPLIER_SCALE);
+}
+
+#[test]
+fn multiplier_caps_at_the_crash_cap() {
+    let cap_slot = (AVIATOR_MAX_CASHOUT - AVIATOR_MULTIPLIER_SCALE) / CRASH_ROUND_GROWTH_PER_SLOT;
+    assert_eq!(crash_round_multiplier(0, cap_slot - 1), AVIATOR_MAX_CASHOUT - CRASH_ROUND_GROWTH_PER_SLOT);
+    assert_eq!(crash_round_multiplier(0, cap_slot), AVIATOR_MAX_CASHOUT);
+    assert_eq!(crash_round_multiplier(0, u64::MAX), AVIATOR_MAX_CASHOUT);
+}
+
+#[test]
+fn settlement_pays_cashouts_below_the_crash() {
+    let (start, bet) = (500, 1_000_000);
+    let crash = 250;
+    // Cashed out at 2.00x and 2.50x: both beat a 2.50x crash
+    assert_eq!(calculate_aviator_payout(crash_round_multiplier(start, start + 20), crash, bet), 2_000_000);
+    assert_eq!(calculate_aviator_payout(crash_round_multiplier(start, start + 30), crash, bet), 2_500_000);
+    // One slot later the plane had already crashed
+    assert_eq!(calculate_aviator_payout(crash_round_multiplier(start, start + 31), crash, bet), 0);
+}
@@ -0,0 +1,50 @@
+use crate::state::casino::HiloGuess;
+use crate::utils::{hilo_rung_value, hilo_winning_cards};
+
+/// Rank counts of a fresh deck after `drawn` cards are taken out.
+fn rank_counts(drawn: &[u8]) -> [u8; 13] {
+    let mut counts = [4u8; 13];
+    for card in drawn {
+        counts[(card % 13) as usize] -= 1;
+    }
+    counts
+}
+
+#[test]
+fn winning_cards_match_a_card_by_card_count() {
+    let drawn = [0u8, 13, 5, 18, 31, 12, 25, 51];
+    let counts = rank_counts(&drawn);
+    for current_rank in 0..13u8 {
+        let unseen = (0..52u8).filter(|card| !drawn.contains(card));
+        let (higher, lower) = unseen.fold((0, 0), |(higher, lower), card| {
+            (higher + (card % 13 > current_rank) as u8, lower + (card % 13 < current_rank) as u8)
+        });
+        assert_eq!(hilo_winning_cards(&counts, current_rank, HiloGuess::Higher), higher, "rank {current_rank}");
+        assert_eq!(hilo_winning_cards(&counts, current_rank, HiloGuess::Lower), lower, "rank {current_rank}");
+    }
+    // Nothing beats a king, nothing is under an ace
+    assert_eq!(hilo_winning_cards(&rank_counts(&[]), 12, HiloGuess::Higher), 0);
+    assert_eq!(hilo_winning_cards(&rank_counts(&[]), 0, HiloGuess::Lower), 0);
+}
+
+#[test]
+fn rungs_pay_the_exact_odds() {
+    // First guess off a fresh deck: 51 cards left, the current card already out
+    let ladder = 1_000_000u64;
+    for current_card in 0..13u8 {
+        let counts = rank_counts(&[current_card]);
+        for guess in [HiloGuess::Higher, HiloGuess::Lower] {
+            let winners = hilo_winning_cards(&counts, current_card, guess);
+            if winners == 0 {
+                continue;
+            }
+            let rung = hilo_rung_value(ladder, 51, winners);
+            // Expected value of the guess is the ladder, less at most a unit of rounding per winner
+            let expected = rung as u128 * winners as u128;
+            assert!(expected <= ladder as u128 * 51, "{current_card} {guess:?}");
+            assert!(expected + winners as u128 > ladder as u128 * 51, "{current_card} {guess:?}");
+        }
+    }
+    assert_eq!(hilo_rung_value(ladder, 51, 48), 1_062_500);
+    assert_eq!(hilo_rung_value(ladder, 51, 3), 17_000_000);
+}
@@ -0,0 +1,16 @@
+use crate::state::casino::*;
+use crate::utils::calculate_memecoin_payout;
+
+#[test]
+fn payout_follows_the_price_and_rtp() {
+    let bet = 1_000_000;
+    assert_eq!(calculate_memecoin_payout(bet, MEMECOIN_START_PRICE, 10000), bet);
+    assert_eq!(calculate_memecoin_payout(bet, MEMECOIN_START_PRICE, 9800), 980_000);
+    assert_eq!(calculate_memecoin_payout(bet, 2 * MEMECOIN_START_PRICE, 9800), 1_960_000);
+    assert_eq!(calculate_memecoin_payout(bet, 475, 10000), 475_000);
+    // Rugged
+    assert_eq!(calculate_memecoin_payout(bet, 0, 9800), 0);
+    // The highest price a path can hold doesn't overflow on large stakes
+    let stake = u64::MAX / 100;
+    assert_eq!(calculate_memecoin_payout(stake, u16::MAX as u64, 10000), (stake as u128 * 65535 / 1000) as u64);
+}
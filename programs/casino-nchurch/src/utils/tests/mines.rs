@@ -0,0 +1,44 @@
+use crate::utils::calculate_mines_payout;
+
+/// Layouts of `mine_count` mines on `tile_count` tiles, and how many leave the first
+/// `safe_reveals` tiles clear.
+fn count_layouts(tile_count: u8, mine_count: u8, safe_reveals: u8) -> (u128, u128) {
+    let revealed = (1u32 << safe_reveals) - 1;
+    let (mut total, mut safe) = (0, 0);
+    for layout in 0u32..1 << tile_count {
+        if layout.count_ones() == mine_count as u32 {
+            total += 1;
+            if layout & revealed == 0 {
+                safe += 1;
+            }
+        }
+    }
+    (total, safe)
+}
+
+#[test]
+fn payout_is_the_inverse_survival_odds() {
+    let bet = 1_000_000u64;
+    for tile_count in [9, 16] {
+        for mine_count in 1..tile_count {
+            for safe_reveals in 0..=tile_count - mine_count {
+                let (total, safe) = count_layouts(tile_count, mine_count, safe_reveals);
+                let fair = (bet as u128 * total * 9900 / (safe * 10000)) as u64;
+                assert_eq!(calculate_mines_payout(bet, tile_count, mine_count, safe_reveals, 9900), fair, "{tile_count} tiles, {mine_count} mines, {safe_reveals} reveals");
+            }
+        }
+    }
+}
+
+#[test]
+fn payout_at_known_points() {
+    let bet = 1_000_000;
+    // No reveals returns the stake less the edge
+    assert_eq!(calculate_mines_payout(bet, 25, 3, 0, 9900), 990_000);
+    // One safe tile out of 25 with one mine: 25/24
+    assert_eq!(calculate_mines_payout(bet, 25, 1, 1, 10000), 1_041_666);
+    // Clearing a 5x5 board with 24 mines: C(25, 1) / C(1, 1)
+    assert_eq!(calculate_mines_payout(bet, 25, 24, 1, 10000), 25_000_000);
+    // More reveals than safe tiles can't happen, so it pays nothing
+    assert_eq!(calculate_mines_payout(bet, 9, 8, 2, 10000), 0);
+}
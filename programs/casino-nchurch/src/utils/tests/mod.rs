@@ -0,0 +1,11 @@
+//! Unit tests for the on-chain settlement and validation helpers, one module per game.
+
+mod aviator;
+mod blackjack;
+mod crash;
+mod craps;
+mod hilo;
+mod memecoin;
+mod mines;
+mod scratch;
+mod sic_bo;
@@ -0,0 +1,58 @@
+use crate::state::casino::*;
+use crate::utils::{
+    binomial, calculate_scratch_rtp_bps, scratch_card_symbols, scratch_winning_symbol,
+    validate_rtp_config,
+};
+
+/// Cards won by each symbol, summed over every split of the nine tiles between the symbols.
+fn winning_cards() -> [u128; SCRATCH_SYMBOLS] {
+    fn split(counts: &mut [u8; SCRATCH_SYMBOLS], symbol: usize, left: u8, cards: &mut [u128; SCRATCH_SYMBOLS]) {
+        if symbol == SCRATCH_SYMBOLS - 1 {
+            counts[symbol] = left;
+            let mut arrangements = 1u128;
+            let mut placed = 0u64;
+            for &count in counts.iter() {
+                placed += count as u64;
+                arrangements *= binomial(placed, count as u64);
+            }
+            if let Some(winner) = (0..SCRATCH_SYMBOLS).rev().find(|&s| counts[s] >= 3) {
+                cards[winner] += arrangements;
+            }
+            return;
+        }
+        for count in 0..=left {
+            counts[symbol] = count;
+            split(counts, symbol + 1, left - count, cards);
+        }
+    }
+    let mut cards = [0u128; SCRATCH_SYMBOLS];
+    split(&mut [0; SCRATCH_SYMBOLS], 0, SCRATCH_TILES, &mut cards);
+    cards
+}
+
+#[test]
+fn rtp_weighs_each_symbol_by_its_winning_cards() {
+    let cards = winning_cards();
+    let total = (SCRATCH_SYMBOLS as u128).pow(SCRATCH_TILES as u32);
+    for symbol in 0..SCRATCH_SYMBOLS {
+        let mut paytable = [0u32; SCRATCH_SYMBOLS];
+        paytable[symbol] = 10_000_000;
+        assert_eq!(calculate_scratch_rtp_bps(&paytable) as u128, cards[symbol] * 10_000_000 / total, "symbol {symbol}");
+    }
+    let paytable = [0, 0, 5_000, 10_000, 12_000, 15_000, 20_000, 40_000];
+    let weighted: u128 = (0..SCRATCH_SYMBOLS).map(|s| cards[s] * paytable[s] as u128).sum();
+    assert_eq!(calculate_scratch_rtp_bps(&paytable) as u128, weighted / total);
+    assert!(validate_rtp_config(calculate_scratch_rtp_bps(&paytable) as u16).is_ok());
+    assert_eq!(calculate_scratch_rtp_bps(&[0; SCRATCH_SYMBOLS]), 0);
+}
+
+#[test]
+fn winning_symbol_is_the_highest_triple() {
+    // Tile `i` in bits 3i..3i+3
+    let card = |symbols: [u32; 9]| symbols.iter().enumerate().fold(0u32, |card, (tile, &s)| card | s << (3 * tile));
+    let symbols = scratch_card_symbols(card([1, 1, 1, 6, 6, 6, 7, 7, 0]));
+    assert_eq!(symbols, [1, 1, 1, 6, 6, 6, 7, 7, 0]);
+    assert_eq!(scratch_winning_symbol(&symbols), 6);
+    assert_eq!(scratch_winning_symbol(&scratch_card_symbols(card([0, 1, 2, 3, 4, 5, 6, 7, 7]))), SCRATCH_NO_WIN);
+    assert_eq!(scratch_winning_symbol(&[0; 9]), 0);
+}
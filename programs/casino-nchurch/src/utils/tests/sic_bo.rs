@@ -0,0 +1,86 @@
+use crate::state::casino::*;
+use crate::utils::{calculate_sic_bo_payout, calculate_sic_bo_rtp_bps, sic_bo_winning_mask};
+
+/// Whether the bet on `position` wins on `dice`, read straight from the grid layout.
+fn wins(position: usize, dice: [u8; 3]) -> bool {
+    let total = dice.iter().sum::<u8>() as usize;
+    let count = |face: u8| dice.iter().filter(|&&die| die == face).count();
+    let triple = dice[0] == dice[1] && dice[1] == dice[2];
+    match position {
+        SIC_BO_SMALL => !triple && (4..=10).contains(&total),
+        SIC_BO_BIG => !triple && (11..=17).contains(&total),
+        p if p < SIC_BO_ANY_TRIPLE => count((p - SIC_BO_TRIPLE + 1) as u8) == 3,
+        SIC_BO_ANY_TRIPLE => triple,
+        p if p < SIC_BO_TOTAL => count((p - SIC_BO_DOUBLE + 1) as u8) >= 2,
+        p if p < SIC_BO_COMBINATION => total == p - SIC_BO_TOTAL + 4,
+        p => {
+            // Combinations run 1-2, 1-3, ..., 1-6, 2-3, ..., 5-6
+            let mut pairs = (1..=6u8).flat_map(|a| (a + 1..=6).map(move |b| (a, b)));
+            let (a, b) = pairs.nth(p - SIC_BO_COMBINATION).unwrap();
+            count(a) > 0 && count(b) > 0
+        }
+    }
+}
+
+fn standard_payouts() -> [u16; SIC_BO_BETS] {
+    let mut payouts = [0u16; SIC_BO_BETS];
+    payouts[SIC_BO_SMALL] = 1;
+    payouts[SIC_BO_BIG] = 1;
+    payouts[SIC_BO_TRIPLE..SIC_BO_ANY_TRIPLE].fill(180);
+    payouts[SIC_BO_ANY_TRIPLE] = 30;
+    payouts[SIC_BO_DOUBLE..SIC_BO_TOTAL].fill(10);
+    payouts[SIC_BO_TOTAL..SIC_BO_COMBINATION].copy_from_slice(&[60, 30, 17, 12, 8, 6, 6, 6, 6, 8, 12, 17, 30, 60]);
+    payouts[SIC_BO_COMBINATION..].fill(6);
+    payouts
+}
+
+#[test]
+fn winning_mask_matches_the_grid() {
+    for first in 1..=6 {
+        for second in 1..=6 {
+            for third in 1..=6 {
+                let dice = [first, second, third];
+                let mask = sic_bo_winning_mask(dice);
+                for position in 0..SIC_BO_BETS {
+                    assert_eq!(mask >> position & 1 == 1, wins(position, dice), "{dice:?} position {position}");
+                }
+                assert_eq!(mask >> SIC_BO_BETS, 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn rtp_counts_every_roll() {
+    let rtp_bps = calculate_sic_bo_rtp_bps(&standard_payouts());
+    // Small and big win on 105 of 216 rolls
+    assert_eq!(rtp_bps[SIC_BO_SMALL], 9722);
+    assert_eq!(rtp_bps[SIC_BO_BIG], 9722);
+    assert!(rtp_bps[SIC_BO_TRIPLE..SIC_BO_ANY_TRIPLE].iter().all(|&rtp| rtp == 181 * 10000 / 216));
+    assert_eq!(rtp_bps[SIC_BO_ANY_TRIPLE], 31 * 6 * 10000 / 216);
+    assert!(rtp_bps[SIC_BO_DOUBLE..SIC_BO_TOTAL].iter().all(|&rtp| rtp == 11 * 16 * 10000 / 216));
+    // Totals 4 and 17 come up 3 ways, 10 and 11 come up 27 ways
+    assert_eq!(rtp_bps[SIC_BO_TOTAL], 61 * 3 * 10000 / 216);
+    assert_eq!(rtp_bps[SIC_BO_TOTAL + 6], 7 * 27 * 10000 / 216);
+    assert!(rtp_bps[SIC_BO_COMBINATION..].iter().all(|&rtp| rtp == 7 * 30 * 10000 / 216));
+
+    // Every positive-payout position carries a house edge; unpaid positions return nothing
+    assert!(rtp_bps.iter().all(|&rtp| rtp > 0 && rtp < 10000));
+    let mut payouts = standard_payouts();
+    payouts[SIC_BO_BIG] = 0;
+    assert_eq!(calculate_sic_bo_rtp_bps(&payouts)[SIC_BO_BIG], 0);
+}
+
+#[test]
+fn payout_settles_every_winning_position() {
+    let payouts = standard_payouts();
+    let mut bets = [0u64; SIC_BO_BETS];
+    bets[SIC_BO_SMALL] = 100;
+    bets[SIC_BO_BIG] = 100;
+    bets[SIC_BO_DOUBLE] = 10;
+    bets[SIC_BO_TOTAL + 5 - 4] = 10;
+    bets[SIC_BO_COMBINATION] = 10;
+    // 1-1-3: small, double ones, total 5 and the 1-3 combination win; 1-2 and big lose
+    let mask = sic_bo_winning_mask([1, 1, 3]);
+    assert_eq!(calculate_sic_bo_payout(&bets, &payouts, mask), 200 + 110 + 310);
+}
@@ -62,7 +62,7 @@ pub fn generate_game_randomness(
             Ok(vec![number])
         },
         crate::state::casino::GameType::Aviator => {
-            // Generate crash multiplier (1.0x - 100.0x)
+            // Generate crash multiplier in hundredths (1.00x - 100.00x)
             let random_bytes: [u8; 4] = [
                 randomness_bytes[0],
                 randomness_bytes[1],
@@ -70,7 +70,7 @@ pub fn generate_game_randomness(
                 randomness_bytes[3],
             ];
             let random_value = u32::from_le_bytes(random_bytes);
-            let multiplier = 1.0 + (random_value % 9900) as f64 / 100.0;
+            let multiplier = 100 + (random_value % 9900) as u64;
             let multiplier_bytes = multiplier.to_le_bytes();
             Ok(multiplier_bytes.to_vec())
         },